@@ -168,7 +168,7 @@ pub const fn convert_str<const N: usize>(case: Case, s: &str) -> [u8; N] {
 
 const CASE_DIFF: u8 = b'a' - b'A';
 
-const fn uppercase_u8(b: u8) -> u8 {
+pub(crate) const fn uppercase_u8(b: u8) -> u8 {
     if let b'a'..=b'z' = b {
         b - CASE_DIFF
     } else {
@@ -176,7 +176,7 @@ const fn uppercase_u8(b: u8) -> u8 {
     }
 }
 
-const fn lowercase_u8(b: u8) -> u8 {
+pub(crate) const fn lowercase_u8(b: u8) -> u8 {
     if let b'A'..=b'Z' = b {
         b + CASE_DIFF
     } else {
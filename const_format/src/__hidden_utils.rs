@@ -5,6 +5,42 @@ pub(crate) const fn max_usize(l: usize, r: usize) -> usize {
         r
     }
 }
+pub const fn max_usize_of(slice: &[usize]) -> usize {
+    let mut max = 0;
+    iter_copy_slice! {x in slice =>
+        max = max_usize(max, x);
+    }
+    max
+}
+
+/// The suffix that makes `word` plural, using simple English rules.
+pub const fn english_plural_suffix(word: &str) -> &'static str {
+    match word.as_bytes() {
+        [.., b's'] | [.., b'x'] | [.., b'z'] | [.., b'c', b'h'] | [.., b's', b'h'] => "es",
+        _ => "s",
+    }
+}
+
+/// The English ordinal suffix of a number that ends in `last_two_digits`,
+/// eg: `"st"` for `1`, `"th"` for `11`.
+///
+/// `last_two_digits` is the number modulo 100, it's negative for negative numbers.
+pub const fn english_ordinal_suffix(last_two_digits: i8) -> &'static str {
+    // can't overflow, because the number is in `-99..=99`
+    let last_two_digits = if last_two_digits < 0 {
+        -last_two_digits
+    } else {
+        last_two_digits
+    };
+    match (last_two_digits / 10, last_two_digits % 10) {
+        (1, _) => "th",
+        (_, 1) => "st",
+        (_, 2) => "nd",
+        (_, 3) => "rd",
+        _ => "th",
+    }
+}
+
 pub(crate) const fn saturating_add(l: usize, r: usize) -> usize {
     let (sum, overflowed) = l.overflowing_add(r);
     if overflowed {
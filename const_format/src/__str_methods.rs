@@ -4,15 +4,230 @@ mod str_replace;
 #[cfg(feature = "const_generics")]
 pub use self::str_replace::{ReplaceInput, ReplaceInputConv};
 
+#[cfg(feature = "const_generics")]
+mod str_splitn;
+
+#[cfg(feature = "const_generics")]
+pub use self::str_splitn::{SplitNInput, SplitNInputConv};
+
+#[cfg(feature = "const_generics")]
+mod escape_debug;
+
+#[cfg(feature = "const_generics")]
+pub use self::escape_debug::{escape_debug_bytes, escape_debug_len};
+
+#[cfg(feature = "const_generics")]
+mod escape_html;
+
+#[cfg(feature = "const_generics")]
+pub use self::escape_html::{escape_html_bytes, escape_html_len};
+
+#[cfg(feature = "const_generics")]
+mod hex_decode;
+
+#[cfg(feature = "const_generics")]
+pub use self::hex_decode::{hex_decode_bytes, HexDecodeArgs, HexDecodeError};
+
+#[cfg(feature = "const_generics")]
+mod hex_dump;
+
+#[cfg(feature = "const_generics")]
+pub use self::hex_dump::{hex_dump_bytes, hex_dump_len};
+
+#[cfg(feature = "const_generics")]
+mod json_escape;
+
+#[cfg(feature = "const_generics")]
+pub use self::json_escape::{json_escape_bytes, json_escaped_len};
+
+#[cfg(feature = "const_generics")]
+mod str_reverse;
+
+#[cfg(feature = "const_generics")]
+pub use self::str_reverse::str_reverse_bytes;
+
+#[cfg(feature = "const_generics")]
+mod slugify;
+
+#[cfg(feature = "const_generics")]
+pub use self::slugify::{slugify_bytes, slugify_len};
+
+#[cfg(feature = "const_generics")]
+mod deaccent;
+
+#[cfg(feature = "const_generics")]
+pub use self::deaccent::{deaccent_bytes, deaccent_len};
+
+#[cfg(feature = "const_generics")]
+mod strip_ansi;
+
+#[cfg(feature = "const_generics")]
+pub use self::strip_ansi::{strip_ansi_bytes, strip_ansi_len};
+
+#[cfg(feature = "const_generics")]
+mod strip_comments;
+
+#[cfg(feature = "const_generics")]
+pub use self::strip_comments::{strip_comments_bytes, strip_comments_len, CommentSyntax};
+
+#[cfg(feature = "const_generics")]
+mod capitalize;
+
+#[cfg(feature = "const_generics")]
+pub use self::capitalize::capitalize_bytes;
+
+#[cfg(feature = "const_generics")]
+mod swap_case;
+
+#[cfg(feature = "const_generics")]
+pub use self::swap_case::swap_case_bytes;
+
+#[cfg(feature = "const_generics")]
+mod dedup_whitespace;
+
+#[cfg(feature = "const_generics")]
+pub use self::dedup_whitespace::{dedup_whitespace_bytes, dedup_whitespace_len};
+
+#[cfg(feature = "const_generics")]
+mod title_from_snake;
+
+#[cfg(feature = "const_generics")]
+pub use self::title_from_snake::{title_from_snake_bytes, title_from_snake_len};
+
+#[cfg(feature = "const_generics")]
+mod ident_case;
+
+#[cfg(feature = "const_generics")]
+pub use self::ident_case::{
+    camel_to_snake_bytes, camel_to_snake_len, snake_to_camel_bytes, snake_to_camel_len,
+};
+
+#[cfg(feature = "const_generics")]
+mod wrap_text;
+
+#[cfg(feature = "const_generics")]
+pub use self::wrap_text::{wrap_text_bytes, wrap_text_len};
+
+#[cfg(feature = "const_generics")]
+mod str_pad;
+
+#[cfg(feature = "const_generics")]
+pub use self::str_pad::{str_pad_bytes, PadSide, StrPadArgs};
+
+#[cfg(feature = "const_generics")]
+mod mask_middle;
+
+#[cfg(feature = "const_generics")]
+pub use self::mask_middle::{mask_middle_bytes, MaskMiddleArgs};
+
+#[cfg(feature = "const_generics")]
+mod binary_padded;
+
+#[cfg(feature = "const_generics")]
+pub use self::binary_padded::{binary_padded_bytes, BinaryPaddedArgs};
+
+#[cfg(feature = "const_generics")]
+mod align_table;
+
+#[cfg(feature = "const_generics")]
+pub use self::align_table::{align_table_bytes, table_columns, AlignTableArgs};
+
+#[cfg(feature = "const_generics")]
+mod grid;
+
+#[cfg(feature = "const_generics")]
+pub use self::grid::{grid_bytes, GridArgs};
+
+#[cfg(feature = "const_generics")]
+mod newlines;
+
+#[cfg(feature = "const_generics")]
+pub use self::newlines::{newlines_bytes, LineEnding, NewlinesArgs};
+
+#[cfg(feature = "const_generics")]
+mod raw_literal;
+
+#[cfg(feature = "const_generics")]
+pub use self::raw_literal::{raw_literal_bytes, RawLiteralArgs};
+
+#[cfg(feature = "const_generics")]
+mod concat_range;
+
+#[cfg(feature = "const_generics")]
+pub use self::concat_range::{concat_range_bytes, ConcatRangeArgs, ConcatRangeArgsConv};
+
+#[cfg(feature = "const_generics")]
+mod zip_join;
+
+#[cfg(feature = "const_generics")]
+pub use self::zip_join::{zip_join_bytes, ZipJoinArgs};
+
+mod common_affix;
+pub use common_affix::{common_prefix_len, CommonAffixArgs};
+
+mod str_lines;
+pub use str_lines::StrLineArgs;
+
+mod word_count;
+pub use word_count::word_count;
+
+mod count_char;
+pub use count_char::{count_leading, count_trailing};
+
+mod truncate_words;
+pub use truncate_words::TruncateWordsArgs;
+
+mod abbreviate_number;
+pub use abbreviate_number::AbbreviateNumberArgs;
+
+mod to_fixed_point;
+pub use to_fixed_point::FixedPointArgs;
+
+mod checksum;
+pub use checksum::{crc32, fnv1a, ChecksumInputConv};
+
+mod str_min_max;
+pub use str_min_max::{str_max, str_min};
+
+mod cstr;
+pub use cstr::CStrArgs;
+
+mod colorize;
+pub use colorize::Color;
+
+mod char_to_str;
+pub use char_to_str::CharToStrArgs;
+
+mod str_match;
+pub use str_match::{assert_unique_patterns, str_eq_any};
+
+mod parse_bool;
+pub use parse_bool::ParseBoolArgs;
+
 mod str_repeat;
 pub use str_repeat::StrRepeatArgs;
 
+mod repeat_to_width;
+pub use repeat_to_width::RepeatToWidthArgs;
+
 mod str_splice;
 pub use str_splice::{DecomposedString, SplicedStr, StrSplceArgsConv, StrSpliceArgs};
 
 mod str_indexing;
 pub use str_indexing::{IndexValidity, StrIndexArgs, StrIndexArgsConv};
 
+mod index_conversion;
+pub use index_conversion::IndexConversion;
+
+mod strip_bom;
+pub use strip_bom::bom_len;
+
+mod clamp_len;
+pub use clamp_len::clamp_len;
+
+mod replace_range;
+pub use replace_range::{ReplaceRangeArgs, ReplaceRangeArgsConv};
+
 #[cfg(feature = "const_generics")]
 mod ascii_byte {
     #[derive(Copy, Clone)]
@@ -0,0 +1,37 @@
+pub struct AbbreviateNumberArgs {
+    /// The integer part of the abbreviated number.
+    pub whole: u64,
+    /// The decimal part of the abbreviated number, `""` if it's zero.
+    pub decimal: &'static str,
+    pub suffix: &'static str,
+}
+
+const DECIMALS: [&str; 10] = ["", ".1", ".2", ".3", ".4", ".5", ".6", ".7", ".8", ".9"];
+
+const SUFFIXES: [(u64, &str); 4] = [
+    (1_000_000_000_000, "T"),
+    (1_000_000_000, "G"),
+    (1_000_000, "M"),
+    (1_000, "K"),
+];
+
+impl AbbreviateNumberArgs {
+    pub const fn new(n: u64) -> Self {
+        __for_range! {i in 0..SUFFIXES.len() =>
+            let (unit, suffix) = SUFFIXES[i];
+            if n >= unit {
+                return Self {
+                    whole: n / unit,
+                    decimal: DECIMALS[(n % unit / (unit / 10)) as usize],
+                    suffix,
+                };
+            }
+        }
+
+        Self {
+            whole: n,
+            decimal: "",
+            suffix: "",
+        }
+    }
+}
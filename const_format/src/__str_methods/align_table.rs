@@ -0,0 +1,96 @@
+use crate::utils::count_chars;
+
+/// Gets the amount of columns of a table, used to name the type of its `AlignTableArgs`.
+pub const fn table_columns<const C: usize>(_table: &[[&str; C]]) -> usize {
+    C
+}
+
+pub struct AlignTableArgs<const C: usize> {
+    pub table: &'static [[&'static str; C]],
+    pub separator: &'static str,
+    /// The width of each column, in `char`s.
+    pub widths: [usize; C],
+    pub out_len: usize,
+}
+
+impl<const C: usize> AlignTableArgs<C> {
+    pub const fn new(table: &'static [[&'static str; C]], separator: &'static str) -> Self {
+        let mut widths = [0usize; C];
+        iter_copy_slice! {row in table =>
+            __for_range! {col in 0..C =>
+                let width = count_chars(row[col]);
+                if width > widths[col] {
+                    widths[col] = width;
+                }
+            }
+        }
+
+        let mut row_len = 0;
+        __for_range! {col in 0..C =>
+            row_len += widths[col];
+        }
+        if C != 0 {
+            row_len += separator.len() * (C - 1);
+        }
+
+        let mut out_len = 0;
+        iter_copy_slice! {row in table =>
+            out_len += row_len;
+            // The padding is one byte per char,
+            // so cells with multi-byte chars are longer than their width.
+            __for_range! {col in 0..C =>
+                out_len += row[col].len() - count_chars(row[col]);
+            }
+        }
+        if !table.is_empty() {
+            out_len += table.len() - 1;
+        }
+
+        Self {
+            table,
+            separator,
+            widths,
+            out_len,
+        }
+    }
+}
+
+/// Writes the aligned `args.table`, `L` must be `args.out_len`.
+pub const fn align_table_bytes<const C: usize, const L: usize>(
+    args: &AlignTableArgs<C>,
+) -> [u8; L] {
+    let mut out = [0u8; L];
+    let mut out_i = 0;
+
+    macro_rules! write_bytes {
+        ($bytes:expr) => {
+            iter_copy_slice! {b in $bytes =>
+                out[out_i] = b;
+                out_i += 1;
+            }
+        };
+    }
+
+    let mut row_i = 0;
+    iter_copy_slice! {row in args.table =>
+        if row_i != 0 {
+            out[out_i] = b'\n';
+            out_i += 1;
+        }
+        row_i += 1;
+
+        __for_range! {col in 0..C =>
+            if col != 0 {
+                write_bytes!(args.separator.as_bytes());
+            }
+            let cell = row[col];
+            __for_range! {_i in count_chars(cell)..args.widths[col] =>
+                out[out_i] = b' ';
+                out_i += 1;
+            }
+            write_bytes!(cell.as_bytes());
+        }
+    }
+
+    out
+}
@@ -0,0 +1,56 @@
+use crate::pargument::Integer;
+
+pub struct BinaryPaddedArgs {
+    /// The two's complement bits of the value, masked to the width of its type.
+    pub value: u128,
+    /// The amount of binary digits that are written.
+    pub bits: usize,
+    /// Whether `value` is representable with `bits` binary digits.
+    pub fits: bool,
+}
+
+/// The largest amount of bits that a value can have, since it's a `u128`.
+const MAX_BITS: usize = 128;
+
+impl BinaryPaddedArgs {
+    pub const fn new(n: Integer, bits: usize) -> Self {
+        let value = if n.is_negative {
+            n.unsigned.wrapping_neg()
+        } else {
+            n.unsigned
+        } & *n.mask;
+
+        let fits = if bits >= MAX_BITS {
+            bits == MAX_BITS
+        } else {
+            value >> bits == 0
+        };
+
+        Self { value, bits, fits }
+    }
+
+    pub const fn assert_valid(&self) {
+        if self.bits > MAX_BITS {
+            [/* the amount of bits can't be larger than 128 */][self.bits]
+        } else if !self.fits {
+            [/* the value doesn't fit in the amount of bits */][self.fits as usize]
+        }
+    }
+}
+
+/// Writes `args.value` as `args.bits` binary digits, `L` must be `args.bits`.
+pub const fn binary_padded_bytes<const L: usize>(args: &BinaryPaddedArgs) -> [u8; L] {
+    let mut out = [b'0'; L];
+
+    if !args.fits {
+        return out;
+    }
+
+    __for_range! {i in 0..L =>
+        if (args.value >> (L - 1 - i)) & 1 == 1 {
+            out[i] = b'1';
+        }
+    }
+
+    out
+}
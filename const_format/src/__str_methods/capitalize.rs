@@ -0,0 +1,14 @@
+use crate::__ascii_case_conv::uppercase_u8;
+
+/// Uppercases the first byte of `s` if it's an ASCII letter, `L` must be `s.len()`.
+pub const fn capitalize_bytes<const L: usize>(s: &str) -> [u8; L] {
+    let mut out = [0u8; L];
+    let mut i = 0;
+
+    iter_copy_slice! {b in s.as_bytes() =>
+        out[i] = if i == 0 { uppercase_u8(b) } else { b };
+        i += 1;
+    }
+
+    out
+}
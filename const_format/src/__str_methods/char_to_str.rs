@@ -0,0 +1,16 @@
+use crate::char_encoding::char_to_display;
+
+pub struct CharToStrArgs {
+    pub encoded: [u8; 6],
+    pub len: usize,
+}
+
+impl CharToStrArgs {
+    pub const fn new(c: char) -> Self {
+        let fmt_char = char_to_display(c);
+        Self {
+            encoded: *fmt_char.encoded(),
+            len: fmt_char.len(),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+/// Converts the input of the checksum macros to a byte slice.
+pub struct ChecksumInputConv<T>(pub T);
+
+impl ChecksumInputConv<&'static str> {
+    pub const fn conv(self) -> &'static [u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl ChecksumInputConv<&'static [u8]> {
+    pub const fn conv(self) -> &'static [u8] {
+        self.0
+    }
+}
+
+#[cfg(feature = "const_generics")]
+impl<const N: usize> ChecksumInputConv<&'static [u8; N]> {
+    pub const fn conv(self) -> &'static [u8] {
+        self.0
+    }
+}
+
+/// The lookup table of the (reflected) CRC-32 polynomial.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Computes the CRC-32 (ISO-HDLC) checksum of `bytes`,
+/// the one used by zip, gzip, and png.
+pub const fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    iter_copy_slice! {b in bytes =>
+        crc = CRC32_TABLE[((crc ^ b as u32) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+/// Computes the 64-bit FNV-1a hash of `bytes`.
+pub const fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash = 0xCBF2_9CE4_8422_2325u64;
+    iter_copy_slice! {b in bytes =>
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01B3);
+    }
+    hash
+}
@@ -0,0 +1,13 @@
+use crate::utils::next_char_boundary;
+
+/// Returns the length in bytes of the first `max_chars` chars of `s`,
+/// `s.len()` if it has fewer chars than that.
+pub const fn clamp_len(s: &str, max_chars: usize) -> usize {
+    let mut index = 0;
+    let mut chars = 0;
+    while chars < max_chars && index < s.len() {
+        index = next_char_boundary(s, index);
+        chars += 1;
+    }
+    index
+}
@@ -0,0 +1,60 @@
+/// A terminal color, for use in the [`colorize`] macro.
+///
+/// The `Bright*` variants are the high intensity versions of the other colors.
+///
+/// [`colorize`]: ./macro.colorize.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+}
+
+impl Color {
+    /// The parameter of the [ANSI] SGR escape sequence that sets this as the
+    /// foreground color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::Color;
+    ///
+    /// assert_eq!(Color::Red.fg_code(), "31");
+    /// assert_eq!(Color::BrightCyan.fg_code(), "96");
+    /// ```
+    ///
+    /// [ANSI]: https://en.wikipedia.org/wiki/ANSI_escape_code#SGR
+    pub const fn fg_code(self) -> &'static str {
+        match self {
+            Color::Black => "30",
+            Color::Red => "31",
+            Color::Green => "32",
+            Color::Yellow => "33",
+            Color::Blue => "34",
+            Color::Magenta => "35",
+            Color::Cyan => "36",
+            Color::White => "37",
+            Color::BrightBlack => "90",
+            Color::BrightRed => "91",
+            Color::BrightGreen => "92",
+            Color::BrightYellow => "93",
+            Color::BrightBlue => "94",
+            Color::BrightMagenta => "95",
+            Color::BrightCyan => "96",
+            Color::BrightWhite => "97",
+        }
+    }
+}
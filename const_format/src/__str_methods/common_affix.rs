@@ -0,0 +1,57 @@
+use crate::utils::is_char_boundary;
+
+/// The location of the common prefix or suffix of two strings, in the first string.
+pub struct CommonAffixArgs {
+    pub str: &'static str,
+    pub start: usize,
+    pub len: usize,
+}
+
+/// The length in bytes of the common prefix of `left` and `right`.
+pub const fn common_prefix_len(left: &str, right: &str) -> usize {
+    let lbytes = left.as_bytes();
+    let rbytes = right.as_bytes();
+
+    let mut len = 0;
+    while len < lbytes.len() && len < rbytes.len() && lbytes[len] == rbytes[len] {
+        len += 1;
+    }
+    // The strings can share the first bytes of a char, without sharing the char
+    while !is_char_boundary(left, len) {
+        len -= 1;
+    }
+    len
+}
+
+impl CommonAffixArgs {
+    pub const fn prefix(left: &'static str, right: &'static str) -> Self {
+        Self {
+            str: left,
+            start: 0,
+            len: common_prefix_len(left, right),
+        }
+    }
+
+    pub const fn suffix(left: &'static str, right: &'static str) -> Self {
+        let lbytes = left.as_bytes();
+        let rbytes = right.as_bytes();
+
+        let mut len = 0;
+        while len < lbytes.len()
+            && len < rbytes.len()
+            && lbytes[lbytes.len() - 1 - len] == rbytes[rbytes.len() - 1 - len]
+        {
+            len += 1;
+        }
+        // The strings can share the last bytes of a char, without sharing the char
+        while !is_char_boundary(left, lbytes.len() - len) {
+            len -= 1;
+        }
+
+        Self {
+            str: left,
+            start: lbytes.len() - len,
+            len,
+        }
+    }
+}
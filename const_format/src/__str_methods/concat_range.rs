@@ -0,0 +1,205 @@
+use core::ops::{Range, RangeInclusive};
+
+pub struct ConcatRangeArgsConv<T> {
+    pub range: T,
+    pub template: &'static str,
+    pub separator: &'static str,
+}
+
+#[allow(non_snake_case)]
+pub const fn ConcatRangeArgsConv<T>(
+    range: T,
+    template: &'static str,
+    separator: &'static str,
+) -> ConcatRangeArgsConv<T> {
+    ConcatRangeArgsConv {
+        range,
+        template,
+        separator,
+    }
+}
+
+impl ConcatRangeArgsConv<Range<usize>> {
+    pub const fn conv(self) -> ConcatRangeArgs {
+        ConcatRangeArgs::new(self.range, self.template, self.separator)
+    }
+}
+
+impl ConcatRangeArgsConv<RangeInclusive<usize>> {
+    pub const fn conv(self) -> ConcatRangeArgs {
+        ConcatRangeArgs::new_inclusive(self.range, self.template, self.separator)
+    }
+}
+
+pub struct ConcatRangeArgs {
+    /// The first index.
+    pub start: usize,
+    /// The amount of indices, the template is written once for each.
+    pub count: usize,
+    pub template: &'static str,
+    pub separator: &'static str,
+    pub out_len: usize,
+    /// Whether the template has a `{` or `}` that isn't escaped or part of a `{}`.
+    pub has_unmatched_brace: bool,
+}
+
+enum Piece {
+    /// A byte that's written unchanged.
+    Byte,
+    /// A `{{` or `}}`, written as a single brace.
+    EscapedBrace,
+    /// A `{}`, replaced with the index.
+    Placeholder,
+    /// A `{` or `}` that isn't part of any of the above, written unchanged.
+    UnmatchedBrace,
+}
+
+/// What `bytes[i..]` starts with.
+const fn piece_at(bytes: &[u8], i: usize) -> Piece {
+    let next = if i + 1 < bytes.len() { bytes[i + 1] } else { 0 };
+    match (bytes[i], next) {
+        (b'{', b'{') | (b'}', b'}') => Piece::EscapedBrace,
+        (b'{', b'}') => Piece::Placeholder,
+        (b'{', _) | (b'}', _) => Piece::UnmatchedBrace,
+        _ => Piece::Byte,
+    }
+}
+
+const fn digit_count(mut n: usize) -> usize {
+    let mut digits = 1;
+    while n >= 10 {
+        n /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+impl ConcatRangeArgs {
+    pub const fn new(range: Range<usize>, template: &'static str, separator: &'static str) -> Self {
+        let count = range.end.saturating_sub(range.start);
+        Self::from_count(range.start, count, template, separator)
+    }
+
+    pub const fn new_inclusive(
+        range: RangeInclusive<usize>,
+        template: &'static str,
+        separator: &'static str,
+    ) -> Self {
+        let (start, end) = (*range.start(), *range.end());
+        // `0..=usize::MAX` has one more index than fits in a usize,
+        // the output can't be that long anyway.
+        let count = if start <= end {
+            (end - start).saturating_add(1)
+        } else {
+            0
+        };
+        Self::from_count(start, count, template, separator)
+    }
+
+    const fn from_count(
+        start: usize,
+        count: usize,
+        template: &'static str,
+        separator: &'static str,
+    ) -> Self {
+        let bytes = template.as_bytes();
+        // The length of the template without its placeholders, after unescaping it.
+        let mut literal_len = 0;
+        let mut placeholders = 0;
+        let mut has_unmatched_brace = false;
+        let mut i = 0;
+        while i < bytes.len() {
+            match piece_at(bytes, i) {
+                Piece::Byte => {
+                    literal_len += 1;
+                    i += 1;
+                }
+                Piece::EscapedBrace => {
+                    literal_len += 1;
+                    i += 2;
+                }
+                Piece::Placeholder => {
+                    placeholders += 1;
+                    i += 2;
+                }
+                Piece::UnmatchedBrace => {
+                    has_unmatched_brace = true;
+                    literal_len += 1;
+                    i += 1;
+                }
+            }
+        }
+
+        let mut out_len = 0;
+        __for_range! {k in 0..count =>
+            if k != 0 {
+                out_len += separator.len();
+            }
+            out_len += literal_len + digit_count(start + k) * placeholders;
+        }
+
+        Self {
+            start,
+            count,
+            template,
+            separator,
+            out_len,
+            has_unmatched_brace,
+        }
+    }
+
+    pub const fn assert_valid(&self) {
+        if self.has_unmatched_brace {
+            [/* the template has an unmatched brace, escape it with `{{` or `}}` */]
+                [self.has_unmatched_brace as usize]
+        }
+    }
+}
+
+/// Writes `args.template` for every index from `args.start`, `args.count` times,
+/// `L` must be `args.out_len`.
+pub const fn concat_range_bytes<const L: usize>(args: &ConcatRangeArgs) -> [u8; L] {
+    let bytes = args.template.as_bytes();
+    let mut out = [0u8; L];
+    let mut o = 0;
+
+    __for_range! {k in 0..args.count =>
+        let n = args.start + k;
+        if k != 0 {
+            iter_copy_slice! {b in args.separator.as_bytes() =>
+                out[o] = b;
+                o += 1;
+            }
+        }
+
+        let mut i = 0;
+        while i < bytes.len() {
+            match piece_at(bytes, i) {
+                Piece::Placeholder => {
+                    let digits = digit_count(n);
+                    let mut rem = n;
+                    let mut d = digits;
+                    while d > 0 {
+                        d -= 1;
+                        out[o + d] = b'0' + (rem % 10) as u8;
+                        rem /= 10;
+                    }
+                    o += digits;
+                    i += 2;
+                }
+                Piece::EscapedBrace => {
+                    out[o] = bytes[i];
+                    o += 1;
+                    i += 2;
+                }
+                Piece::Byte | Piece::UnmatchedBrace => {
+                    out[o] = bytes[i];
+                    o += 1;
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    out
+}
@@ -0,0 +1,41 @@
+use crate::char_encoding::char_to_display;
+
+/// Counts how many times `c` is repeated at the start of `s`.
+pub const fn count_leading(s: &str, c: char) -> usize {
+    let bytes = s.as_bytes();
+    let c = char_to_display(c);
+    let (encoded, len) = (c.encoded(), c.len());
+
+    let mut count = 0;
+    let mut i = 0;
+    while i + len <= bytes.len() && bytes_eq(bytes, i, encoded, len) {
+        count += 1;
+        i += len;
+    }
+    count
+}
+
+/// Counts how many times `c` is repeated at the end of `s`.
+pub const fn count_trailing(s: &str, c: char) -> usize {
+    let bytes = s.as_bytes();
+    let c = char_to_display(c);
+    let (encoded, len) = (c.encoded(), c.len());
+
+    let mut count = 0;
+    let mut end = bytes.len();
+    while end >= len && bytes_eq(bytes, end - len, encoded, len) {
+        count += 1;
+        end -= len;
+    }
+    count
+}
+
+/// Whether `bytes[start..start + len]` is equal to `encoded[..len]`
+const fn bytes_eq(bytes: &[u8], start: usize, encoded: &[u8; 6], len: usize) -> bool {
+    __for_range! {i in 0..len =>
+        if bytes[start + i] != encoded[i] {
+            return false;
+        }
+    }
+    true
+}
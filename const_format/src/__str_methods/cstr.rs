@@ -0,0 +1,32 @@
+pub struct CStrArgs {
+    pub str: &'static str,
+    pub len: usize,
+    /// The position of the first NUL byte in `str`, if there is one.
+    pub nul_pos: Option<usize>,
+}
+
+impl CStrArgs {
+    pub const fn new(str: &'static str) -> Self {
+        let bytes = str.as_bytes();
+        let mut nul_pos = None;
+
+        __for_range! {i in 0..bytes.len() =>
+            if bytes[i] == 0 {
+                nul_pos = Some(i);
+                break;
+            }
+        }
+
+        Self {
+            str,
+            len: bytes.len(),
+            nul_pos,
+        }
+    }
+
+    pub const fn assert_valid(&self) {
+        if let Some(pos) = self.nul_pos {
+            [/* the string contains a NUL byte at this position */][pos]
+        }
+    }
+}
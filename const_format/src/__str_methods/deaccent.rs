@@ -0,0 +1,119 @@
+/// The ASCII replacement of the chars from `U+00C0` to `U+017F`,
+/// `""` for the chars that are left as they are.
+#[rustfmt::skip]
+const FOLDED: [&str; 0xC0] = [
+    // À Á Â Ã Ä Å Æ Ç (U+00C0..=U+00C7)
+    "A",  "A",  "A",  "A",  "A",  "A",  "AE", "C",
+    // È É Ê Ë Ì Í Î Ï (U+00C8..=U+00CF)
+    "E",  "E",  "E",  "E",  "I",  "I",  "I",  "I",
+    // Ð Ñ Ò Ó Ô Õ Ö × (U+00D0..=U+00D7)
+    "D",  "N",  "O",  "O",  "O",  "O",  "O",  "",
+    // Ø Ù Ú Û Ü Ý Þ ß (U+00D8..=U+00DF)
+    "O",  "U",  "U",  "U",  "U",  "Y",  "TH", "ss",
+    // à á â ã ä å æ ç (U+00E0..=U+00E7)
+    "a",  "a",  "a",  "a",  "a",  "a",  "ae", "c",
+    // è é ê ë ì í î ï (U+00E8..=U+00EF)
+    "e",  "e",  "e",  "e",  "i",  "i",  "i",  "i",
+    // ð ñ ò ó ô õ ö ÷ (U+00F0..=U+00F7)
+    "d",  "n",  "o",  "o",  "o",  "o",  "o",  "",
+    // ø ù ú û ü ý þ ÿ (U+00F8..=U+00FF)
+    "o",  "u",  "u",  "u",  "u",  "y",  "th", "y",
+    // Ā ā Ă ă Ą ą Ć ć (U+0100..=U+0107)
+    "A",  "a",  "A",  "a",  "A",  "a",  "C",  "c",
+    // Ĉ ĉ Ċ ċ Č č Ď ď (U+0108..=U+010F)
+    "C",  "c",  "C",  "c",  "C",  "c",  "D",  "d",
+    // Đ đ Ē ē Ĕ ĕ Ė ė (U+0110..=U+0117)
+    "D",  "d",  "E",  "e",  "E",  "e",  "E",  "e",
+    // Ę ę Ě ě Ĝ ĝ Ğ ğ (U+0118..=U+011F)
+    "E",  "e",  "E",  "e",  "G",  "g",  "G",  "g",
+    // Ġ ġ Ģ ģ Ĥ ĥ Ħ ħ (U+0120..=U+0127)
+    "G",  "g",  "G",  "g",  "H",  "h",  "H",  "h",
+    // Ĩ ĩ Ī ī Ĭ ĭ Į į (U+0128..=U+012F)
+    "I",  "i",  "I",  "i",  "I",  "i",  "I",  "i",
+    // İ ı Ĳ ĳ Ĵ ĵ Ķ ķ (U+0130..=U+0137)
+    "I",  "i",  "IJ", "ij", "J",  "j",  "K",  "k",
+    // ĸ Ĺ ĺ Ļ ļ Ľ ľ Ŀ (U+0138..=U+013F)
+    "",   "L",  "l",  "L",  "l",  "L",  "l",  "L",
+    // ŀ Ł ł Ń ń Ņ ņ Ň (U+0140..=U+0147)
+    "l",  "L",  "l",  "N",  "n",  "N",  "n",  "N",
+    // ň ŉ Ŋ ŋ Ō ō Ŏ ŏ (U+0148..=U+014F)
+    "n",  "n",  "N",  "n",  "O",  "o",  "O",  "o",
+    // Ő ő Œ œ Ŕ ŕ Ŗ ŗ (U+0150..=U+0157)
+    "O",  "o",  "OE", "oe", "R",  "r",  "R",  "r",
+    // Ř ř Ś ś Ŝ ŝ Ş ş (U+0158..=U+015F)
+    "R",  "r",  "S",  "s",  "S",  "s",  "S",  "s",
+    // Š š Ţ ţ Ť ť Ŧ ŧ (U+0160..=U+0167)
+    "S",  "s",  "T",  "t",  "T",  "t",  "T",  "t",
+    // Ũ ũ Ū ū Ŭ ŭ Ů ů (U+0168..=U+016F)
+    "U",  "u",  "U",  "u",  "U",  "u",  "U",  "u",
+    // Ű ű Ų ų Ŵ ŵ Ŷ ŷ (U+0170..=U+0177)
+    "U",  "u",  "U",  "u",  "W",  "w",  "Y",  "y",
+    // Ÿ Ź ź Ż ż Ž ž ſ (U+0178..=U+017F)
+    "Y",  "Z",  "z",  "Z",  "z",  "Z",  "z",  "s",
+];
+
+/// Gets the ASCII replacement for the char at `bytes[i..]`,
+/// along with the length in bytes of that char.
+///
+/// All the folded chars are encoded in two bytes, starting with `0xC3`, `0xC4`, or `0xC5`.
+const fn folded_char(bytes: &[u8], i: usize) -> Option<(&'static str, usize)> {
+    if i + 1 < bytes.len() {
+        if let 0xC3..=0xC5 = bytes[i] {
+            let code = ((bytes[i] as usize & 0x1F) << 6) | (bytes[i + 1] as usize & 0x3F);
+            let folded = FOLDED[code - 0xC0];
+            if !folded.is_empty() {
+                return Some((folded, 2));
+            }
+        }
+    }
+    None
+}
+
+pub const fn deaccent_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut len = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match folded_char(bytes, i) {
+            Some((folded, char_len)) => {
+                len += folded.len();
+                i += char_len;
+            }
+            None => {
+                len += 1;
+                i += 1;
+            }
+        }
+    }
+
+    len
+}
+
+/// Replaces the accented latin letters of `s` with ASCII letters,
+/// `L` must be `deaccent_len(s)`.
+pub const fn deaccent_bytes<const L: usize>(s: &str) -> [u8; L] {
+    let bytes = s.as_bytes();
+    let mut out = [0u8; L];
+    let mut out_i = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match folded_char(bytes, i) {
+            Some((folded, char_len)) => {
+                iter_copy_slice! {b in folded.as_bytes() =>
+                    out[out_i] = b;
+                    out_i += 1;
+                }
+                i += char_len;
+            }
+            None => {
+                out[out_i] = bytes[i];
+                out_i += 1;
+                i += 1;
+            }
+        }
+    }
+
+    out
+}
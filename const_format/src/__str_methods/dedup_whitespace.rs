@@ -0,0 +1,55 @@
+use super::word_count::whitespace_len;
+
+pub const fn dedup_whitespace_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut len = 0;
+    let mut words = 0usize;
+    let mut in_word = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let ws_len = whitespace_len(bytes, i);
+        if ws_len != 0 {
+            in_word = false;
+            i += ws_len;
+        } else {
+            if !in_word {
+                words += 1;
+                in_word = true;
+            }
+            len += 1;
+            i += 1;
+        }
+    }
+
+    // the words are separated by a single space
+    len + words.saturating_sub(1)
+}
+
+/// Collapses the whitespace in `s`, `L` must be `dedup_whitespace_len(s)`.
+pub const fn dedup_whitespace_bytes<const L: usize>(s: &str) -> [u8; L] {
+    let bytes = s.as_bytes();
+    let mut out = [0u8; L];
+    let mut out_i = 0;
+    let mut in_word = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let ws_len = whitespace_len(bytes, i);
+        if ws_len != 0 {
+            in_word = false;
+            i += ws_len;
+        } else {
+            if !in_word && out_i != 0 {
+                out[out_i] = b' ';
+                out_i += 1;
+            }
+            in_word = true;
+            out[out_i] = bytes[i];
+            out_i += 1;
+            i += 1;
+        }
+    }
+
+    out
+}
@@ -0,0 +1,99 @@
+/// Returns the escape char of bytes that have a short escape sequence,
+/// eg: `b'\n'` is escaped as `\n`.
+///
+/// Returns `0` for bytes without a short escape.
+const fn short_escape(b: u8) -> u8 {
+    match b {
+        b'\0' => b'0',
+        b'\t' => b't',
+        b'\r' => b'r',
+        b'\n' => b'n',
+        b'\\' => b'\\',
+        b'\'' => b'\'',
+        b'"' => b'"',
+        _ => 0,
+    }
+}
+
+/// Returns the control character that starts at `bytes[i]`,
+/// if there is one that is escaped as `\u{...}`.
+///
+/// The returned tuple is the value of the control character, and its length in bytes.
+const fn unicode_escaped_control(bytes: &[u8], i: usize) -> Option<(u8, usize)> {
+    match bytes[i] {
+        b @ 0x01..=0x1F | b @ 0x7F => Some((b, 1)),
+        // The C1 control characters, from U+0080 to U+009F
+        0xC2 if i + 1 < bytes.len() && 0x80 <= bytes[i + 1] && bytes[i + 1] <= 0x9F => {
+            Some((bytes[i + 1], 2))
+        }
+        _ => None,
+    }
+}
+
+const fn lower_hex_digit(n: u8) -> u8 {
+    if n < 10 {
+        b'0' + n
+    } else {
+        b'a' + n - 10
+    }
+}
+
+pub const fn escape_debug_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut len = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if short_escape(bytes[i]) != 0 {
+            len += 2;
+            i += 1;
+        } else if let Some((control, control_len)) = unicode_escaped_control(bytes, i) {
+            // `\u{X}` or `\u{XX}`
+            len += if control < 0x10 { 5 } else { 6 };
+            i += control_len;
+        } else {
+            len += 1;
+            i += 1;
+        }
+    }
+    len
+}
+
+/// Escapes `s` like `str::escape_debug`, `L` must be `escape_debug_len(s)`.
+pub const fn escape_debug_bytes<const L: usize>(s: &str) -> [u8; L] {
+    let bytes = s.as_bytes();
+    let mut out = [0u8; L];
+    let mut o = 0;
+    let mut i = 0;
+
+    macro_rules! write_byte {
+        ($byte:expr) => {
+            out[o] = $byte;
+            o += 1;
+        };
+    }
+
+    while i < bytes.len() {
+        let escape = short_escape(bytes[i]);
+        if escape != 0 {
+            write_byte!(b'\\');
+            write_byte!(escape);
+            i += 1;
+        } else if let Some((control, control_len)) = unicode_escaped_control(bytes, i) {
+            write_byte!(b'\\');
+            write_byte!(b'u');
+            write_byte!(b'{');
+            if control >= 0x10 {
+                write_byte!(lower_hex_digit(control >> 4));
+            }
+            write_byte!(lower_hex_digit(control & 0xF));
+            write_byte!(b'}');
+            i += control_len;
+        } else {
+            write_byte!(bytes[i]);
+            i += 1;
+        }
+    }
+
+    out
+}
@@ -0,0 +1,106 @@
+use crate::formatting::hex_as_ascii;
+
+/// Returns the entity that `b` is replaced with,
+/// `""` for bytes that are written as they are.
+const fn entity(b: u8) -> &'static str {
+    match b {
+        b'&' => "&amp;",
+        b'<' => "&lt;",
+        b'>' => "&gt;",
+        b'"' => "&quot;",
+        b'\'' => "&#39;",
+        _ => "",
+    }
+}
+
+/// Decodes the non-ASCII char starting at `bytes[i]`,
+/// returning its code point and its length in bytes.
+const fn decode_char(bytes: &[u8], i: usize) -> (u32, usize) {
+    let b = bytes[i] as u32;
+    let (mut code, len) = if b >= 0xF0 {
+        (b & 0x07, 4)
+    } else if b >= 0xE0 {
+        (b & 0x0F, 3)
+    } else {
+        (b & 0x1F, 2)
+    };
+
+    __for_range! {j in 1..len =>
+        code = (code << 6) | (bytes[i + j] as u32 & 0x3F);
+    }
+    (code, len)
+}
+
+/// The amount of hex digits in `code`.
+const fn hex_digits(code: u32) -> usize {
+    let mut digits = 1;
+    while code >> (digits * 4) != 0 {
+        digits += 1;
+    }
+    digits
+}
+
+pub const fn escape_html_len(s: &str, non_ascii: bool) -> usize {
+    let bytes = s.as_bytes();
+    let mut len = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let b = bytes[i];
+        if non_ascii && b >= 0x80 {
+            let (code, char_len) = decode_char(bytes, i);
+            // `&#x` + the digits + `;`
+            len += 4 + hex_digits(code);
+            i += char_len;
+        } else {
+            let entity = entity(b);
+            len += if entity.is_empty() { 1 } else { entity.len() };
+            i += 1;
+        }
+    }
+    len
+}
+
+/// Escapes `s` for HTML, `L` must be `escape_html_len(s, non_ascii)`.
+pub const fn escape_html_bytes<const L: usize>(s: &str, non_ascii: bool) -> [u8; L] {
+    let bytes = s.as_bytes();
+    let mut out = [0u8; L];
+    let mut out_i = 0;
+    let mut i = 0;
+
+    macro_rules! write_byte {
+        ($byte:expr) => {
+            out[out_i] = $byte;
+            out_i += 1;
+        };
+    }
+
+    while i < bytes.len() {
+        let b = bytes[i];
+        if non_ascii && b >= 0x80 {
+            let (code, char_len) = decode_char(bytes, i);
+            write_byte!(b'&');
+            write_byte!(b'#');
+            write_byte!(b'x');
+            let mut digit = hex_digits(code);
+            while digit != 0 {
+                digit -= 1;
+                write_byte!(hex_as_ascii((code >> (digit * 4)) as u8 & 0xF));
+            }
+            write_byte!(b';');
+            i += char_len;
+        } else {
+            let entity = entity(b);
+            if entity.is_empty() {
+                write_byte!(b);
+            } else {
+                iter_copy_slice! {eb in entity.as_bytes() =>
+                    write_byte!(eb);
+                }
+            }
+            i += 1;
+        }
+    }
+
+    out
+}
@@ -0,0 +1,97 @@
+use crate::utils::count_chars;
+
+const CELL_SEPARATOR: &[u8] = b", ";
+const ROW_SEPARATOR: &[u8] = b",\n";
+
+pub struct GridArgs {
+    pub values: &'static [&'static str],
+    pub cols: usize,
+    pub out_len: usize,
+}
+
+impl GridArgs {
+    pub const fn new(values: &'static [&'static str], cols: usize) -> Self {
+        let mut out_len = 0;
+
+        if cols != 0 {
+            __for_range! {i in 0..values.len() =>
+                let value = values[i];
+                // The padding is one byte per char,
+                // so values with multi-byte chars are longer than their width.
+                out_len += column_width(values, cols, i % cols) + value.len() - count_chars(value);
+                if i != 0 {
+                    out_len += if i % cols == 0 {
+                        ROW_SEPARATOR.len()
+                    } else {
+                        CELL_SEPARATOR.len()
+                    };
+                }
+            }
+        }
+
+        Self {
+            values,
+            cols,
+            out_len,
+        }
+    }
+
+    pub const fn assert_valid(&self) {
+        if self.cols == 0 {
+            [/* the amount of columns must be larger than zero */][self.cols]
+        }
+    }
+}
+
+/// The width of the widest value in the `col` column, in `char`s.
+const fn column_width(values: &[&str], cols: usize, col: usize) -> usize {
+    let mut width = 0;
+    let mut i = col;
+    while i < values.len() {
+        let value_width = count_chars(values[i]);
+        if value_width > width {
+            width = value_width;
+        }
+        i += cols;
+    }
+    width
+}
+
+/// Writes `args.values` laid out in rows, `L` must be `args.out_len`.
+pub const fn grid_bytes<const L: usize>(args: &GridArgs) -> [u8; L] {
+    let mut out = [0u8; L];
+    let mut out_i = 0;
+
+    macro_rules! write_bytes {
+        ($bytes:expr) => {
+            iter_copy_slice! {b in $bytes =>
+                out[out_i] = b;
+                out_i += 1;
+            }
+        };
+    }
+
+    if args.cols == 0 {
+        return out;
+    }
+
+    __for_range! {i in 0..args.values.len() =>
+        let col = i % args.cols;
+        if i != 0 {
+            if col == 0 {
+                write_bytes!(ROW_SEPARATOR);
+            } else {
+                write_bytes!(CELL_SEPARATOR);
+            }
+        }
+
+        let value = args.values[i];
+        __for_range! {_i in count_chars(value)..column_width(args.values, args.cols, col) =>
+            out[out_i] = b' ';
+            out_i += 1;
+        }
+        write_bytes!(value.as_bytes());
+    }
+
+    out
+}
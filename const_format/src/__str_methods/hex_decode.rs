@@ -0,0 +1,121 @@
+use crate::utils::next_char_boundary;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum HexDecodeError {
+    /// The string has an odd number of hex digits, contains the amount of digits.
+    OddLength(usize),
+    /// A char that is neither a hex digit nor a separator is at this byte position.
+    InvalidChar(usize),
+}
+
+pub struct HexDecodeArgs {
+    pub str: &'static str,
+    pub separators: &'static str,
+    pub out_len: usize,
+    pub error: Option<HexDecodeError>,
+}
+
+impl HexDecodeArgs {
+    pub const fn new(str: &'static str, separators: &'static str) -> Self {
+        let bytes = str.as_bytes();
+        let mut digits = 0usize;
+        let mut error = None;
+
+        let mut i = 0;
+        while i < bytes.len() {
+            if hex_digit_value(bytes[i]).is_some() {
+                digits += 1;
+                i += 1;
+            } else if let Some(sep_len) = separator_len(str, i, separators) {
+                i += sep_len;
+            } else {
+                error = Some(HexDecodeError::InvalidChar(i));
+                break;
+            }
+        }
+
+        if error.is_none() && digits % 2 == 1 {
+            error = Some(HexDecodeError::OddLength(digits));
+        }
+
+        Self {
+            str,
+            separators,
+            out_len: if error.is_none() { digits / 2 } else { 0 },
+            error,
+        }
+    }
+
+    pub const fn assert_valid(&self) {
+        match self.error {
+            None => {}
+            Some(HexDecodeError::OddLength(digits)) => {
+                [/* the string has an odd number of hex digits */][digits]
+            }
+            Some(HexDecodeError::InvalidChar(pos)) => {
+                [/* the string has a non-hex-digit char at this byte position */][pos]
+            }
+        }
+    }
+}
+
+/// Decodes the hex digits in `args.str`, `L` must be `args.out_len`.
+pub const fn hex_decode_bytes<const L: usize>(args: &HexDecodeArgs) -> [u8; L] {
+    let mut out = [0u8; L];
+    let bytes = args.str.as_bytes();
+    let mut out_i = 0;
+    let mut high: Option<u8> = None;
+
+    let mut i = 0;
+    while out_i < L {
+        match hex_digit_value(bytes[i]) {
+            Some(digit) => {
+                match high {
+                    Some(high_digit) => {
+                        out[out_i] = (high_digit << 4) | digit;
+                        out_i += 1;
+                        high = None;
+                    }
+                    None => high = Some(digit),
+                }
+                i += 1;
+            }
+            None => i = next_char_boundary(args.str, i),
+        }
+    }
+
+    out
+}
+
+const fn hex_digit_value(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Returns the length of the separator char at `str[index..]`,
+/// if that char is in `separators`.
+const fn separator_len(str: &str, index: usize, separators: &str) -> Option<usize> {
+    let bytes = str.as_bytes();
+    let sep_bytes = separators.as_bytes();
+    let char_len = next_char_boundary(str, index) - index;
+
+    let mut sep_i = 0;
+    while sep_i < sep_bytes.len() {
+        let sep_end = next_char_boundary(separators, sep_i);
+        if sep_end - sep_i == char_len {
+            let mut j = 0;
+            while j < char_len && bytes[index + j] == sep_bytes[sep_i + j] {
+                j += 1;
+            }
+            if j == char_len {
+                return Some(char_len);
+            }
+        }
+        sep_i = sep_end;
+    }
+    None
+}
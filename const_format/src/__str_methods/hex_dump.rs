@@ -0,0 +1,63 @@
+const BYTES_PER_LINE: usize = 16;
+
+/// The length of a line before its ascii column,
+/// the offset, and the hex column with the spaces around it.
+const HEX_COLUMNS_LEN: usize = 8 + 2 + BYTES_PER_LINE * 3 + 2;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+pub const fn hex_dump_len(bytes: &[u8]) -> usize {
+    // `usize::div_ceil` is only usable in const fns since Rust 1.73.0
+    #[allow(clippy::manual_div_ceil)]
+    let lines = (bytes.len() + BYTES_PER_LINE - 1) / BYTES_PER_LINE;
+    // every line has its bytes in the ascii column, surrounded by `|`,
+    // and all lines but the last end with a newline.
+    (HEX_COLUMNS_LEN + 2) * lines + bytes.len() + lines.saturating_sub(1)
+}
+
+/// Writes the hex dump of `bytes`, `L` must be `hex_dump_len(bytes)`.
+pub const fn hex_dump_bytes<const L: usize>(bytes: &[u8]) -> [u8; L] {
+    let mut out = [b' '; L];
+    let mut out_i = 0;
+    let mut line_start = 0;
+
+    while line_start < bytes.len() {
+        if line_start != 0 {
+            out[out_i] = b'\n';
+            out_i += 1;
+        }
+
+        __for_range! {i in 0..8 =>
+            out[out_i + i] = HEX_DIGITS[(line_start >> ((7 - i) * 4)) & 0xF];
+        }
+
+        let line_end = if bytes.len() - line_start < BYTES_PER_LINE {
+            bytes.len()
+        } else {
+            line_start + BYTES_PER_LINE
+        };
+
+        __for_range! {i in 0..line_end - line_start =>
+            // the second half of the bytes has an extra space before it
+            let at = out_i + 10 + i * 3 + (i >= BYTES_PER_LINE / 2) as usize;
+            let b = bytes[line_start + i];
+            out[at] = HEX_DIGITS[(b >> 4) as usize];
+            out[at + 1] = HEX_DIGITS[(b & 0xF) as usize];
+        }
+        out_i += HEX_COLUMNS_LEN;
+
+        out[out_i] = b'|';
+        out_i += 1;
+        __for_range! {i in line_start..line_end =>
+            let b = bytes[i];
+            out[out_i] = if b.is_ascii_graphic() || b == b' ' { b } else { b'.' };
+            out_i += 1;
+        }
+        out[out_i] = b'|';
+        out_i += 1;
+
+        line_start = line_end;
+    }
+
+    out
+}
@@ -0,0 +1,103 @@
+use crate::__ascii_case_conv::{lowercase_u8, uppercase_u8};
+
+/// The range of `bytes` without its leading and trailing underscores.
+const fn trim_underscores(bytes: &[u8]) -> (usize, usize) {
+    let mut start = 0;
+    while start < bytes.len() && bytes[start] == b'_' {
+        start += 1;
+    }
+    let mut end = bytes.len();
+    while end > start && bytes[end - 1] == b'_' {
+        end -= 1;
+    }
+    (start, end)
+}
+
+pub const fn snake_to_camel_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let (start, end) = trim_underscores(bytes);
+    let mut len = bytes.len();
+
+    __for_range! {i in start..end =>
+        if bytes[i] == b'_' {
+            len -= 1;
+        }
+    }
+    len
+}
+
+/// Converts `s` from snake case to camel case,
+/// `L` must be `snake_to_camel_len(s)`.
+pub const fn snake_to_camel_bytes<const L: usize>(s: &str, upper: bool) -> [u8; L] {
+    let bytes = s.as_bytes();
+    let (start, end) = trim_underscores(bytes);
+    let mut out = [0u8; L];
+    let mut o = 0;
+    let mut word_start = true;
+
+    __for_range! {i in 0..bytes.len() =>
+        let b = bytes[i];
+        if i < start || i >= end {
+            out[o] = b;
+            o += 1;
+        } else if b == b'_' {
+            word_start = true;
+        } else {
+            out[o] = if !word_start {
+                b
+            } else if upper || o != start {
+                uppercase_u8(b)
+            } else {
+                lowercase_u8(b)
+            };
+            o += 1;
+            word_start = false;
+        }
+    }
+
+    out
+}
+
+/// Whether an underscore goes before `bytes[i]` when converting to snake case.
+const fn is_word_start(bytes: &[u8], i: usize) -> bool {
+    if i == 0 || !bytes[i].is_ascii_uppercase() {
+        return false;
+    }
+    let prev = bytes[i - 1];
+    // non-ascii chars are assumed to be lowercase letters
+    prev.is_ascii_lowercase()
+        || prev.is_ascii_digit()
+        || !prev.is_ascii()
+        || prev.is_ascii_uppercase() && i + 1 < bytes.len() && bytes[i + 1].is_ascii_lowercase()
+}
+
+pub const fn camel_to_snake_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut len = bytes.len();
+
+    __for_range! {i in 0..bytes.len() =>
+        if is_word_start(bytes, i) {
+            len += 1;
+        }
+    }
+    len
+}
+
+/// Converts `s` from camel case to snake case,
+/// `L` must be `camel_to_snake_len(s)`.
+pub const fn camel_to_snake_bytes<const L: usize>(s: &str) -> [u8; L] {
+    let bytes = s.as_bytes();
+    let mut out = [0u8; L];
+    let mut o = 0;
+
+    __for_range! {i in 0..bytes.len() =>
+        if is_word_start(bytes, i) {
+            out[o] = b'_';
+            o += 1;
+        }
+        out[o] = lowercase_u8(bytes[i]);
+        o += 1;
+    }
+
+    out
+}
@@ -0,0 +1,111 @@
+use crate::utils::{is_char_boundary, next_char_boundary};
+
+pub struct IndexConversion {
+    /// The converted index, only meaningful if `validity` is `Valid`.
+    pub index: usize,
+    pub validity: ConversionValidity,
+}
+
+#[derive(Copy, Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum ConversionValidity {
+    Valid,
+    CharIndexOob(usize),
+    ByteIndexOob(usize),
+    ByteIndexInsideChar(usize),
+}
+
+impl IndexConversion {
+    /// Converts the index of a char in `str` into the byte index where that char starts,
+    /// `count_chars(str)` is converted into `str.len()`.
+    pub const fn char_to_byte(str: &str, char_index: usize) -> Self {
+        let mut index = 0;
+        let mut chars = 0;
+        while chars < char_index {
+            if index == str.len() {
+                return Self {
+                    index,
+                    validity: ConversionValidity::CharIndexOob(char_index),
+                };
+            }
+            index = next_char_boundary(str, index);
+            chars += 1;
+        }
+
+        Self {
+            index,
+            validity: ConversionValidity::Valid,
+        }
+    }
+
+    /// Converts a byte index in `str` into the amount of chars before it.
+    pub const fn byte_to_char(str: &str, byte_index: usize) -> Self {
+        let validity = if byte_index > str.len() {
+            ConversionValidity::ByteIndexOob(byte_index)
+        } else if !is_char_boundary(str, byte_index) {
+            ConversionValidity::ByteIndexInsideChar(byte_index)
+        } else {
+            ConversionValidity::Valid
+        };
+
+        let mut chars = 0;
+        if let ConversionValidity::Valid = validity {
+            let mut index = 0;
+            while index < byte_index {
+                index = next_char_boundary(str, index);
+                chars += 1;
+            }
+        }
+
+        Self {
+            index: chars,
+            validity,
+        }
+    }
+
+    pub const fn assert_valid(&self) {
+        match self.validity {
+            ConversionValidity::Valid => (),
+            ConversionValidity::CharIndexOob(index) => {
+                [/* the char index is out of bounds */][index]
+            }
+            ConversionValidity::ByteIndexOob(index) => {
+                [/* the byte index is out of bounds */][index]
+            }
+            ConversionValidity::ByteIndexInsideChar(index) => {
+                [/* the byte index is not on a char boundary */][index]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_validity() {
+        const S: &str = "aé効";
+
+        assert_eq!(
+            IndexConversion::char_to_byte(S, 4).validity,
+            ConversionValidity::CharIndexOob(4)
+        );
+        assert_eq!(
+            IndexConversion::byte_to_char(S, 7).validity,
+            ConversionValidity::ByteIndexOob(7)
+        );
+        for &index in &[2, 4, 5] {
+            assert_eq!(
+                IndexConversion::byte_to_char(S, index).validity,
+                ConversionValidity::ByteIndexInsideChar(index)
+            );
+        }
+        for &index in &[0, 1, 3, 6] {
+            assert_eq!(
+                IndexConversion::byte_to_char(S, index).validity,
+                ConversionValidity::Valid
+            );
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::formatting::hex_as_ascii;
+
+/// Returns the escape char of bytes that have a short escape sequence,
+/// eg: `b'\n'` is escaped as `\n`.
+///
+/// Returns `0` for bytes without a short escape.
+const fn short_escape(b: u8) -> u8 {
+    match b {
+        b'"' => b'"',
+        b'\\' => b'\\',
+        0x08 => b'b',
+        0x0C => b'f',
+        b'\n' => b'n',
+        b'\r' => b'r',
+        b'\t' => b't',
+        _ => 0,
+    }
+}
+
+pub const fn json_escaped_len(s: &str) -> usize {
+    let mut len = 0;
+    iter_copy_slice! {b in s.as_bytes() =>
+        len += if short_escape(b) != 0 {
+            2
+        } else if b < 0x20 {
+            // `\u00XX`
+            6
+        } else {
+            1
+        };
+    }
+    len
+}
+
+pub const fn json_escape_bytes<const L: usize>(s: &str) -> [u8; L] {
+    let mut out = [0u8; L];
+    let mut i = 0;
+
+    macro_rules! write_byte {
+        ($byte:expr) => {
+            out[i] = $byte;
+            i += 1;
+        };
+    }
+
+    iter_copy_slice! {b in s.as_bytes() =>
+        let escape = short_escape(b);
+        if escape != 0 {
+            write_byte!(b'\\');
+            write_byte!(escape);
+        } else if b < 0x20 {
+            write_byte!(b'\\');
+            write_byte!(b'u');
+            write_byte!(b'0');
+            write_byte!(b'0');
+            write_byte!(hex_as_ascii(b >> 4));
+            write_byte!(hex_as_ascii(b & 0xF));
+        } else {
+            write_byte!(b);
+        }
+    }
+
+    out
+}
@@ -0,0 +1,78 @@
+use crate::{
+    char_encoding::{char_to_display, FmtChar},
+    utils::{count_chars, next_char_boundary},
+};
+
+pub struct MaskMiddleArgs {
+    pub str: &'static str,
+    pub mask: FmtChar,
+    /// The byte index where the masked chars start.
+    pub middle_start: usize,
+    /// The byte index where the masked chars end.
+    pub middle_end: usize,
+    /// How many chars are masked.
+    pub masked: usize,
+    pub out_len: usize,
+}
+
+impl MaskMiddleArgs {
+    pub const fn new(str: &'static str, keep_start: usize, keep_end: usize, mask: char) -> Self {
+        let mask = char_to_display(mask);
+        let chars = count_chars(str);
+
+        let (middle_start, middle_end, masked) = match keep_start.checked_add(keep_end) {
+            Some(kept) if kept < chars => {
+                let start = nth_char_index(str, keep_start);
+                let end = nth_char_index(str, chars - keep_end);
+                (start, end, chars - kept)
+            }
+            // strings that don't have chars between the kept ones are returned unchanged
+            _ => (str.len(), str.len(), 0),
+        };
+
+        Self {
+            str,
+            mask,
+            middle_start,
+            middle_end,
+            masked,
+            out_len: str.len() - (middle_end - middle_start) + masked * mask.len(),
+        }
+    }
+}
+
+/// The byte index of the `n`th char of `str`
+const fn nth_char_index(str: &str, n: usize) -> usize {
+    let mut index = 0;
+    __for_range! {_i in 0..n =>
+        index = next_char_boundary(str, index);
+    }
+    index
+}
+
+/// Masks the middle of `args.str`, `L` must be `args.out_len`.
+pub const fn mask_middle_bytes<const L: usize>(args: &MaskMiddleArgs) -> [u8; L] {
+    let mut out = [0u8; L];
+    let bytes = args.str.as_bytes();
+    let mask = args.mask.encoded();
+    let mut out_i = 0;
+
+    __for_range! {i in 0..args.middle_start =>
+        out[out_i] = bytes[i];
+        out_i += 1;
+    }
+
+    __for_range! {_i in 0..args.masked =>
+        __for_range! {j in 0..args.mask.len() =>
+            out[out_i] = mask[j];
+            out_i += 1;
+        }
+    }
+
+    __for_range! {i in args.middle_end..bytes.len() =>
+        out[out_i] = bytes[i];
+        out_i += 1;
+    }
+
+    out
+}
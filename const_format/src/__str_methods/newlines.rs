@@ -0,0 +1,82 @@
+#[derive(Copy, Clone)]
+pub enum LineEnding {
+    Lf,
+    CrLf,
+}
+
+impl LineEnding {
+    const fn as_bytes(self) -> &'static [u8] {
+        match self {
+            LineEnding::Lf => b"\n",
+            LineEnding::CrLf => b"\r\n",
+        }
+    }
+}
+
+pub struct NewlinesArgs {
+    pub str: &'static str,
+    pub ending: LineEnding,
+    pub out_len: usize,
+}
+
+impl NewlinesArgs {
+    pub const fn new(str: &'static str, ending: LineEnding) -> Self {
+        let bytes = str.as_bytes();
+        let ending_len = ending.as_bytes().len();
+
+        let mut out_len = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            let len = line_ending_len(bytes, i);
+            if len == 0 {
+                out_len += 1;
+                i += 1;
+            } else {
+                out_len += ending_len;
+                i += len;
+            }
+        }
+
+        Self {
+            str,
+            ending,
+            out_len,
+        }
+    }
+}
+
+/// Gets the length of the `\r\n`, `\r`, or `\n` line ending at `bytes[i..]`,
+/// returning `0` if there's none.
+const fn line_ending_len(bytes: &[u8], i: usize) -> usize {
+    match bytes[i] {
+        b'\r' if i + 1 < bytes.len() && bytes[i + 1] == b'\n' => 2,
+        b'\r' | b'\n' => 1,
+        _ => 0,
+    }
+}
+
+/// Replaces the line endings of `args.str`, `L` must be `args.out_len`.
+pub const fn newlines_bytes<const L: usize>(args: &NewlinesArgs) -> [u8; L] {
+    let mut out = [0u8; L];
+    let bytes = args.str.as_bytes();
+    let ending = args.ending.as_bytes();
+
+    let mut out_i = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let len = line_ending_len(bytes, i);
+        if len == 0 {
+            out[out_i] = bytes[i];
+            out_i += 1;
+            i += 1;
+        } else {
+            iter_copy_slice! {b in ending =>
+                out[out_i] = b;
+                out_i += 1;
+            }
+            i += len;
+        }
+    }
+
+    out
+}
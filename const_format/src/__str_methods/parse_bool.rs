@@ -0,0 +1,40 @@
+use crate::slice_cmp::str_eq;
+
+pub struct ParseBoolArgs {
+    pub value: bool,
+    /// Whether `"1"`/`"0"` and `"yes"`/`"no"` are accepted.
+    pub lenient: bool,
+    pub is_valid: bool,
+}
+
+impl ParseBoolArgs {
+    pub const fn new(s: &str, lenient: bool) -> Self {
+        let (value, is_valid) = if str_eq(s, "true") {
+            (true, true)
+        } else if str_eq(s, "false") {
+            (false, true)
+        } else if lenient && (str_eq(s, "1") || str_eq(s, "yes")) {
+            (true, true)
+        } else if lenient && (str_eq(s, "0") || str_eq(s, "no")) {
+            (false, true)
+        } else {
+            (false, false)
+        };
+
+        Self {
+            value,
+            lenient,
+            is_valid,
+        }
+    }
+
+    pub const fn assert_valid(&self) {
+        if !self.is_valid {
+            if self.lenient {
+                [/* expected "true", "false", "1", "0", "yes", or "no" */][self.lenient as usize]
+            } else {
+                [/* expected "true" or "false" */][self.is_valid as usize]
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+pub struct RawLiteralArgs {
+    pub str: &'static str,
+    /// How many `#` are written on each side of the raw string literal.
+    pub hashes: usize,
+    pub out_len: usize,
+}
+
+impl RawLiteralArgs {
+    pub const fn new(str: &'static str) -> Self {
+        let bytes = str.as_bytes();
+
+        // The literal needs one more `#` than the longest `"#...` run in the string,
+        // and no `#`s at all if the string has no `"`.
+        let mut hashes = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == b'"' {
+                let mut run = 1;
+                while i + run < bytes.len() && bytes[i + run] == b'#' {
+                    run += 1;
+                }
+                if run > hashes {
+                    hashes = run;
+                }
+                i += run;
+            } else {
+                i += 1;
+            }
+        }
+
+        Self {
+            str,
+            hashes,
+            // `r`, the `#`s, and the `"`s
+            out_len: 3 + hashes * 2 + str.len(),
+        }
+    }
+}
+
+/// Writes `args.str` as a raw string literal, `L` must be `args.out_len`.
+pub const fn raw_literal_bytes<const L: usize>(args: &RawLiteralArgs) -> [u8; L] {
+    let mut out = [0u8; L];
+    let mut i = 0;
+
+    out[i] = b'r';
+    i += 1;
+    __for_range! {_j in 0..args.hashes =>
+        out[i] = b'#';
+        i += 1;
+    }
+    out[i] = b'"';
+    i += 1;
+
+    iter_copy_slice! {b in args.str.as_bytes() =>
+        out[i] = b;
+        i += 1;
+    }
+
+    out[i] = b'"';
+    i += 1;
+    __for_range! {_j in 0..args.hashes =>
+        out[i] = b'#';
+        i += 1;
+    }
+
+    out
+}
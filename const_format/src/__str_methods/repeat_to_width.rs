@@ -0,0 +1,46 @@
+use crate::utils::{count_chars, next_char_boundary};
+
+pub struct RepeatToWidthArgs {
+    pub str: &'static str,
+    /// How many times `str` is repeated before truncating it.
+    pub repeat: usize,
+    /// The length in bytes of the returned string.
+    pub out_len: usize,
+    pub width: usize,
+}
+
+impl RepeatToWidthArgs {
+    pub const fn new(str: &'static str, width: usize) -> Self {
+        let chars = count_chars(str);
+
+        if chars == 0 {
+            return Self {
+                str,
+                repeat: 0,
+                out_len: 0,
+                width,
+            };
+        }
+
+        let full = width / chars;
+        let rem_chars = width % chars;
+
+        let mut rem_len = 0;
+        __for_range! {_i in 0..rem_chars =>
+            rem_len = next_char_boundary(str, rem_len);
+        }
+
+        Self {
+            str,
+            repeat: full + (rem_chars != 0) as usize,
+            out_len: full * str.len() + rem_len,
+            width,
+        }
+    }
+
+    pub const fn assert_valid(&self) {
+        if self.str.is_empty() && self.width != 0 {
+            [/* an empty string can't be repeated to a non-zero width */][self.width]
+        }
+    }
+}
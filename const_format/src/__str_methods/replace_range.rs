@@ -0,0 +1,112 @@
+use super::str_indexing::IndexValidity;
+
+use crate::__hidden_utils::{max_usize, saturating_add};
+
+pub struct ReplaceRangeArgsConv<T> {
+    pub bytes: &'static [u8],
+    pub arg: T,
+    pub insert: &'static [u8],
+}
+
+#[allow(non_snake_case)]
+pub const fn ReplaceRangeArgsConv<T>(
+    bytes: &'static [u8],
+    arg: T,
+    insert: &'static [u8],
+) -> ReplaceRangeArgsConv<T> {
+    ReplaceRangeArgsConv { bytes, arg, insert }
+}
+
+pub struct ReplaceRangeArgs {
+    pub bytes: &'static [u8],
+    pub insert: &'static [u8],
+    pub index_validity: IndexValidity,
+    pub used_rstart: usize,
+    pub used_rlen: usize,
+    pub insert_len: usize,
+    pub suffix_len: usize,
+    pub out_len: usize,
+}
+
+macro_rules! define_conversions {
+    (
+        $( fn($self:ident, $ty:ty) $block:block )*
+    ) => {
+        const _: () = {
+            use core::ops;
+
+            $(
+                impl ReplaceRangeArgsConv<$ty> {
+                    pub const fn conv($self) -> ReplaceRangeArgs {
+                        let range = $block;
+
+                        let len = $self.bytes.len();
+
+                        let mut used_rstart = 0;
+                        let mut used_rend = len;
+
+                        let mut index_validity = IndexValidity::Valid;
+
+                        if range.end > len {
+                            index_validity = IndexValidity::EndOob(range.end);
+                        } else {
+                            used_rend = range.end;
+                        }
+
+                        if range.start > len {
+                            index_validity = IndexValidity::StartOob(range.start);
+                        } else {
+                            used_rstart = range.start;
+                        }
+
+                        let used_rlen = used_rend - used_rstart;
+                        let insert_len = $self.insert.len();
+
+                        ReplaceRangeArgs {
+                            bytes: $self.bytes,
+                            insert: $self.insert,
+                            index_validity,
+                            used_rstart,
+                            used_rlen,
+                            insert_len,
+                            suffix_len: len - used_rend,
+                            out_len: len - used_rlen + insert_len,
+                        }
+                    }
+                }
+            )*
+        };
+    };
+}
+
+define_conversions! {
+    fn(self, usize) {
+        self.arg .. saturating_add(self.arg, 1)
+    }
+
+    fn(self, ops::Range<usize>) {
+        let ops::Range{start, end} = self.arg;
+        start .. max_usize(start, end)
+    }
+
+    fn(self, ops::RangeTo<usize>) {
+        0..self.arg.end
+    }
+
+    fn(self, ops::RangeFrom<usize>) {
+        self.arg.start..self.bytes.len()
+    }
+
+    fn(self, ops::RangeInclusive<usize>) {
+        let start = *self.arg.start();
+        start .. max_usize(saturating_add(*self.arg.end(), 1), start)
+    }
+
+    fn(self, ops::RangeToInclusive<usize>) {
+        0 .. saturating_add(self.arg.end, 1)
+    }
+
+    fn(self, ops::RangeFull) {
+        0 .. self.bytes.len()
+    }
+}
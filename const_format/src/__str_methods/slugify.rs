@@ -0,0 +1,47 @@
+use crate::__ascii_case_conv::lowercase_u8;
+
+pub const fn slugify_len(s: &str) -> usize {
+    let mut len = 0;
+    let mut words = 0usize;
+    let mut in_word = false;
+
+    iter_copy_slice! {b in s.as_bytes() =>
+        if b.is_ascii_alphanumeric() {
+            if !in_word {
+                in_word = true;
+                words += 1;
+            }
+            len += 1;
+        } else {
+            in_word = false;
+        }
+    }
+
+    // the dashes between the words
+    len + words.saturating_sub(1)
+}
+
+/// Converts `s` into a slug, `L` must be `slugify_len(s)`.
+pub const fn slugify_bytes<const L: usize>(s: &str) -> [u8; L] {
+    let mut out = [0u8; L];
+    let mut i = 0;
+    let mut in_word = false;
+
+    iter_copy_slice! {b in s.as_bytes() =>
+        if b.is_ascii_alphanumeric() {
+            if !in_word {
+                if i != 0 {
+                    out[i] = b'-';
+                    i += 1;
+                }
+                in_word = true;
+            }
+            out[i] = lowercase_u8(b);
+            i += 1;
+        } else {
+            in_word = false;
+        }
+    }
+
+    out
+}
@@ -0,0 +1,59 @@
+/// The location of the first or last line of a string.
+pub struct StrLineArgs {
+    pub str: &'static str,
+    pub start: usize,
+    pub len: usize,
+}
+
+impl StrLineArgs {
+    pub const fn first(str: &'static str) -> Self {
+        let (end, _) = line_at(str.as_bytes(), 0);
+
+        Self {
+            str,
+            start: 0,
+            len: end,
+        }
+    }
+
+    pub const fn last(str: &'static str) -> Self {
+        let bytes = str.as_bytes();
+
+        let mut start = 0;
+        loop {
+            let (end, next) = line_at(bytes, start);
+            // The line ending of the last line is optional
+            if next >= bytes.len() {
+                return Self {
+                    str,
+                    start,
+                    len: end - start,
+                };
+            }
+            start = next;
+        }
+    }
+}
+
+/// Splits the line that starts at `bytes[start]`, the same way that `str::lines` does it.
+///
+/// Returns the end of the line without its line ending, and the start of the next line.
+/// Lines end with a `\n`, and a single `\r` right before that `\n` is part of the line ending,
+/// so a `\r` with no `\n` after it (eg: at the end of the string) is kept.
+///
+/// Both `first` and `last` are implemented with this function,
+/// so that they can't disagree on where lines end.
+const fn line_at(bytes: &[u8], start: usize) -> (usize, usize) {
+    let mut end = start;
+    while end < bytes.len() && bytes[end] != b'\n' {
+        end += 1;
+    }
+
+    if end == bytes.len() {
+        (end, end)
+    } else if end > start && bytes[end - 1] == b'\r' {
+        (end - 1, end + 1)
+    } else {
+        (end, end + 1)
+    }
+}
@@ -0,0 +1,21 @@
+use crate::slice_cmp::str_eq;
+
+/// Whether `string` is equal to any of the `patterns`.
+pub const fn str_eq_any(string: &str, patterns: &[&str]) -> bool {
+    iter_copy_slice! {pattern in patterns =>
+        if str_eq(string, pattern) {
+            return true;
+        }
+    }
+    false
+}
+
+pub const fn assert_unique_patterns(patterns: &[&str]) {
+    __for_range! {i in 0..patterns.len() =>
+        __for_range! {j in i + 1..patterns.len() =>
+            if str_eq(patterns[i], patterns[j]) {
+                let _: () = [/* the str_match macro has duplicate patterns */][j];
+            }
+        }
+    }
+}
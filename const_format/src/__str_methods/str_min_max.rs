@@ -0,0 +1,32 @@
+use crate::utils::compare_str;
+
+use core::cmp::Ordering;
+
+/// Gets the lexicographically smallest string in `strs`,
+/// causing a compile-time error if `strs` is empty.
+pub const fn str_min(strs: &[&'static str]) -> &'static str {
+    fold(strs, Ordering::Less)
+}
+
+/// Gets the lexicographically largest string in `strs`,
+/// causing a compile-time error if `strs` is empty.
+pub const fn str_max(strs: &[&'static str]) -> &'static str {
+    fold(strs, Ordering::Greater)
+}
+
+/// Gets the first string in `strs` which compares as `replace_if`
+/// against all the strings before it.
+const fn fold(strs: &[&'static str], replace_if: Ordering) -> &'static str {
+    let mut out = match strs {
+        [first, ..] => *first,
+        [] => [/* str_min and str_max require a non-empty array */][strs.len()],
+    };
+
+    iter_copy_slice! {s in strs =>
+        if compare_str(s, out) as i8 == replace_if as i8 {
+            out = s;
+        }
+    }
+
+    out
+}
@@ -0,0 +1,127 @@
+use crate::{
+    char_encoding::{char_to_display, FmtChar},
+    utils::count_chars,
+};
+
+#[derive(Copy, Clone)]
+pub enum PadSide {
+    Start,
+    End,
+    /// Like `Start`, but when the string starts with a `-` sign,
+    /// the padding goes between the sign and the rest of the string.
+    StartAfterSign,
+    /// Half of the padding goes before the string and the other half after it,
+    /// with the extra fill char going after it when the padding is odd.
+    Center,
+}
+
+impl PadSide {
+    /// How padding is placed when formatting integers with `fill`,
+    /// zeroes go after the sign (`-0042`), other fill chars before it (`  -42`).
+    pub const fn for_int(fill: char) -> Self {
+        if fill == '0' {
+            PadSide::StartAfterSign
+        } else {
+            PadSide::Start
+        }
+    }
+}
+
+pub struct StrPadArgs {
+    pub str: &'static str,
+    pub fill: FmtChar,
+    pub side: PadSide,
+    /// How many times `fill` is written.
+    pub padding: usize,
+    pub out_len: usize,
+    /// Whether the padded string would be longer than `MAX_LEN`.
+    pub too_large: bool,
+}
+
+/// The largest length that the padded string can have,
+/// since arrays can't be larger than `isize::MAX` bytes.
+const MAX_LEN: usize = isize::MAX as usize;
+
+impl StrPadArgs {
+    pub const fn new(str: &'static str, width: usize, fill: char, side: PadSide) -> Self {
+        let fill = char_to_display(fill);
+        let padding = width.saturating_sub(count_chars(str));
+
+        let out_len = match padding.checked_mul(fill.len()) {
+            Some(padding_len) => padding_len.checked_add(str.len()),
+            None => None,
+        };
+
+        match out_len {
+            Some(out_len) if out_len <= MAX_LEN => Self {
+                str,
+                fill,
+                side,
+                padding,
+                out_len,
+                too_large: false,
+            },
+            // Not padding the string, so that the error comes from `assert_valid`.
+            _ => Self {
+                str,
+                fill,
+                side,
+                padding: 0,
+                out_len: str.len(),
+                too_large: true,
+            },
+        }
+    }
+
+    pub const fn assert_valid(&self) {
+        if self.too_large {
+            [/* the width is too large, the padded string would be longer than isize::MAX bytes */]
+                [self.too_large as usize]
+        }
+    }
+}
+
+/// Pads the `args.str` string, `L` must be `args.out_len`.
+pub const fn str_pad_bytes<const L: usize>(args: &StrPadArgs) -> [u8; L] {
+    let mut out = [0u8; L];
+    let fill = args.fill.encoded();
+    let fill_len = args.fill.len();
+
+    let mut str = args.str.as_bytes();
+    let mut at = 0;
+
+    // How many times `fill` is written before the string.
+    let start_padding = match (args.side, str) {
+        (PadSide::StartAfterSign, [b'-', rem @ ..]) => {
+            out[0] = b'-';
+            str = rem;
+            at = 1;
+            args.padding
+        }
+        (PadSide::Start, _) => args.padding,
+        (PadSide::StartAfterSign, _) => args.padding,
+        (PadSide::End, _) => 0,
+        (PadSide::Center, _) => args.padding / 2,
+    };
+
+    __for_range! {_i in 0..start_padding =>
+        __for_range! {j in 0..fill_len =>
+            out[at] = fill[j];
+            at += 1;
+        }
+    }
+
+    iter_copy_slice! {b in str =>
+        out[at] = b;
+        at += 1;
+    }
+
+    __for_range! {_i in start_padding..args.padding =>
+        __for_range! {j in 0..fill_len =>
+            out[at] = fill[j];
+            at += 1;
+        }
+    }
+
+    out
+}
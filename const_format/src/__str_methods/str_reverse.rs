@@ -0,0 +1,22 @@
+use crate::utils::next_char_boundary;
+
+/// Reverses `s` by chars, `L` must be `s.len()`.
+pub const fn str_reverse_bytes<const L: usize>(s: &str) -> [u8; L] {
+    let bytes = s.as_bytes();
+    let mut out = [0u8; L];
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let char_end = next_char_boundary(s, i);
+
+        // the char at `i..char_end` is written at `L - char_end..L - i`
+        let out_start = L - char_end;
+        __for_range! {j in i..char_end =>
+            out[out_start + j - i] = bytes[j];
+        }
+
+        i = char_end;
+    }
+
+    out
+}
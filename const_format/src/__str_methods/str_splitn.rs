@@ -0,0 +1,161 @@
+use super::{bytes_find, AsciiByte};
+
+use crate::utils::next_char_boundary;
+
+pub struct SplitNInputConv<T>(pub &'static str, pub usize, pub T);
+
+impl SplitNInputConv<u8> {
+    pub const fn conv(self) -> SplitNInput {
+        SplitNInput {
+            str: self.0,
+            limit: self.1,
+            pattern: SplitPattern::AsciiByte(AsciiByte::new(self.2)),
+        }
+    }
+}
+
+impl SplitNInputConv<&'static str> {
+    pub const fn conv(self) -> SplitNInput {
+        SplitNInput {
+            str: self.0,
+            limit: self.1,
+            pattern: SplitPattern::Str(self.2),
+        }
+    }
+}
+
+pub struct SplitNInput {
+    str: &'static str,
+    limit: usize,
+    pattern: SplitPattern,
+}
+
+#[derive(Copy, Clone)]
+pub enum SplitPattern {
+    AsciiByte(AsciiByte),
+    Str(&'static str),
+}
+
+impl SplitNInput {
+    pub const fn length(&self) -> usize {
+        let mut iter = SplitN::new(self);
+        let mut len = 0;
+        while let Some((_, next)) = iter.next() {
+            iter = next;
+            len += 1;
+        }
+        len
+    }
+
+    pub const fn split<const LEN: usize>(&self) -> [&'static str; LEN] {
+        let mut out = [""; LEN];
+        let mut iter = SplitN::new(self);
+        let mut i = 0;
+        while let Some((part, next)) = iter.next() {
+            iter = next;
+            out[i] = part;
+            i += 1;
+        }
+        out
+    }
+}
+
+/// The state of splitting a string, used as an iterator.
+#[derive(Copy, Clone)]
+struct SplitN {
+    str: &'static str,
+    pattern: SplitPattern,
+    /// How many parts can still be yielded.
+    remaining: usize,
+    /// Where the next part starts.
+    start: usize,
+    /// Where to search for the next match of the pattern.
+    search_from: usize,
+}
+
+impl SplitN {
+    const fn new(input: &SplitNInput) -> Self {
+        Self {
+            str: input.str,
+            pattern: input.pattern,
+            remaining: input.limit,
+            start: 0,
+            search_from: 0,
+        }
+    }
+
+    const fn next(mut self) -> Option<(&'static str, Self)> {
+        let bytes = self.str.as_bytes();
+        match self.remaining {
+            0 => return None,
+            1 => {
+                self.remaining = 0;
+                let part = subslice_to_str(bytes, self.start, bytes.len());
+                return Some((part, self));
+            }
+            _ => {}
+        }
+
+        // Returns the start and end of the match
+        let found = match self.pattern {
+            SplitPattern::AsciiByte(byte) => {
+                match bytes_find(bytes, &[byte.get()], self.search_from) {
+                    Some(pos) => Some((pos, pos + 1)),
+                    None => None,
+                }
+            }
+            SplitPattern::Str(str) if str.is_empty() => {
+                if self.search_from <= bytes.len() {
+                    Some((self.search_from, self.search_from))
+                } else {
+                    None
+                }
+            }
+            SplitPattern::Str(str) => match bytes_find(bytes, str.as_bytes(), self.search_from) {
+                Some(pos) => Some((pos, pos + str.len())),
+                None => None,
+            },
+        };
+
+        match found {
+            Some((match_start, match_end)) => {
+                let part = subslice_to_str(bytes, self.start, match_start);
+                self.remaining -= 1;
+                self.start = match_end;
+                self.search_from = if match_start != match_end {
+                    match_end
+                } else if match_end == bytes.len() {
+                    // empty patterns match at the end of the string once,
+                    // so the search goes past it
+                    bytes.len() + 1
+                } else {
+                    // empty patterns match at every char boundary, once
+                    next_char_boundary(self.str, match_end)
+                };
+                Some((part, self))
+            }
+            None => {
+                let part = subslice_to_str(bytes, self.start, bytes.len());
+                self.remaining = 0;
+                Some((part, self))
+            }
+        }
+    }
+}
+
+/// Gets `bytes[start..end]` as a `&str`,
+/// requires that the range is in bounds and on char boundaries.
+const fn subslice_to_str(mut bytes: &'static [u8], start: usize, end: usize) -> &'static str {
+    __for_range! {_i in end..bytes.len() =>
+        if let [rem @ .., _] = bytes {
+            bytes = rem;
+        }
+    }
+    __for_range! {_i in 0..start =>
+        if let [_, rem @ ..] = bytes {
+            bytes = rem;
+        }
+    }
+
+    unsafe { __priv_transmute_bytes_to_str!(bytes) }
+}
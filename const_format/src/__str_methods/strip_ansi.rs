@@ -0,0 +1,60 @@
+/// Returns the length of the CSI escape sequence
+/// (`ESC [`, parameter bytes, intermediate bytes, final byte) starting at `bytes[start]`.
+///
+/// Returns `0` if there's no complete escape sequence starting at `start`.
+const fn csi_len(bytes: &[u8], start: usize) -> usize {
+    if start + 1 >= bytes.len() || bytes[start] != 0x1B || bytes[start + 1] != b'[' {
+        return 0;
+    }
+
+    let mut i = start + 2;
+    // parameter bytes
+    while i < bytes.len() && 0x30 <= bytes[i] && bytes[i] <= 0x3F {
+        i += 1;
+    }
+    // intermediate bytes
+    while i < bytes.len() && 0x20 <= bytes[i] && bytes[i] <= 0x2F {
+        i += 1;
+    }
+    // final byte
+    if i < bytes.len() && 0x40 <= bytes[i] && bytes[i] <= 0x7E {
+        i + 1 - start
+    } else {
+        0
+    }
+}
+
+pub const fn strip_ansi_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut len = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match csi_len(bytes, i) {
+            0 => {
+                len += 1;
+                i += 1;
+            }
+            seq_len => i += seq_len,
+        }
+    }
+    len
+}
+
+/// Removes the CSI escape sequences in `s`, `L` must be `strip_ansi_len(s)`.
+pub const fn strip_ansi_bytes<const L: usize>(s: &str) -> [u8; L] {
+    let bytes = s.as_bytes();
+    let mut out = [0u8; L];
+    let mut out_i = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match csi_len(bytes, i) {
+            0 => {
+                out[out_i] = bytes[i];
+                out_i += 1;
+                i += 1;
+            }
+            seq_len => i += seq_len,
+        }
+    }
+    out
+}
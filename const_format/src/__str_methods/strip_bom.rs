@@ -0,0 +1,8 @@
+/// Returns the length of the byte order mark at the start of `s`, `0` if there's none.
+pub const fn bom_len(s: &str) -> usize {
+    match s.as_bytes() {
+        // The UTF-8 encoding of '\u{FEFF}'
+        [0xEF, 0xBB, 0xBF, ..] => 3,
+        _ => 0,
+    }
+}
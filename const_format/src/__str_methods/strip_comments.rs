@@ -0,0 +1,109 @@
+/// The syntax of the comments that are removed by `strip_comments_bytes`.
+#[derive(Copy, Clone)]
+pub struct CommentSyntax {
+    /// Starts a comment that continues up to the end of the line.
+    pub line: &'static str,
+    /// Starts a block comment, block comments aren't removed if this is empty.
+    pub block_start: &'static str,
+    /// Ends a block comment.
+    pub block_end: &'static str,
+}
+
+impl CommentSyntax {
+    /// C-style comments, `//` line comments and `/* */` block comments.
+    pub const C: Self = Self {
+        line: "//",
+        block_start: "/*",
+        block_end: "*/",
+    };
+
+    /// Line comments started with `line`, without block comments.
+    pub const fn line(line: &'static str) -> Self {
+        Self {
+            line,
+            block_start: "",
+            block_end: "",
+        }
+    }
+
+    pub const fn assert_valid(&self) {
+        if self.line.is_empty() {
+            [/* the comment marker can't be an empty string */][self.line.len()]
+        }
+    }
+}
+
+const fn starts_with_at(bytes: &[u8], start: usize, pattern: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    if pattern.is_empty() || bytes.len() - start < pattern.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < pattern.len() {
+        if bytes[start + i] != pattern[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns the length of the comment starting at `bytes[start]`,
+/// `0` if there's no comment starting there.
+///
+/// Line comments don't include the line ending, unclosed block comments
+/// continue up to the end of `bytes`.
+const fn comment_len(bytes: &[u8], start: usize, syntax: CommentSyntax) -> usize {
+    let mut i = start;
+    if starts_with_at(bytes, i, syntax.line) {
+        i += syntax.line.len();
+        while i < bytes.len() && bytes[i] != b'\n' && !starts_with_at(bytes, i, "\r\n") {
+            i += 1;
+        }
+    } else if starts_with_at(bytes, i, syntax.block_start) {
+        i += syntax.block_start.len();
+        while i < bytes.len() && !starts_with_at(bytes, i, syntax.block_end) {
+            i += 1;
+        }
+        if i < bytes.len() {
+            i += syntax.block_end.len();
+        }
+    }
+    i - start
+}
+
+pub const fn strip_comments_len(s: &str, syntax: CommentSyntax) -> usize {
+    let bytes = s.as_bytes();
+    let mut len = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match comment_len(bytes, i, syntax) {
+            0 => {
+                len += 1;
+                i += 1;
+            }
+            comment_len => i += comment_len,
+        }
+    }
+    len
+}
+
+/// Removes the comments in `s`, `L` must be `strip_comments_len(s, syntax)`.
+pub const fn strip_comments_bytes<const L: usize>(s: &str, syntax: CommentSyntax) -> [u8; L] {
+    let bytes = s.as_bytes();
+    let mut out = [0u8; L];
+    let mut out_i = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match comment_len(bytes, i, syntax) {
+            0 => {
+                out[out_i] = bytes[i];
+                out_i += 1;
+                i += 1;
+            }
+            comment_len => i += comment_len,
+        }
+    }
+    out
+}
@@ -0,0 +1,18 @@
+use crate::__ascii_case_conv::{lowercase_u8, uppercase_u8};
+
+/// Swaps the case of the ASCII letters in `s`, `L` must be `s.len()`.
+pub const fn swap_case_bytes<const L: usize>(s: &str) -> [u8; L] {
+    let mut out = [0u8; L];
+    let mut i = 0;
+
+    iter_copy_slice! {b in s.as_bytes() =>
+        out[i] = if b.is_ascii_lowercase() {
+            uppercase_u8(b)
+        } else {
+            lowercase_u8(b)
+        };
+        i += 1;
+    }
+
+    out
+}
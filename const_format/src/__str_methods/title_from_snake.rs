@@ -0,0 +1,48 @@
+use crate::__ascii_case_conv::uppercase_u8;
+
+pub const fn title_from_snake_len(s: &str) -> usize {
+    let mut len = 0;
+    let mut in_word = false;
+    let mut words = 0usize;
+
+    iter_copy_slice! {b in s.as_bytes() =>
+        if b == b'_' {
+            in_word = false;
+        } else {
+            if !in_word {
+                in_word = true;
+                words += 1;
+            }
+            len += 1;
+        }
+    }
+
+    // the spaces between the words
+    len + words.saturating_sub(1)
+}
+
+/// Converts `s` from snake case to title case, `L` must be `title_from_snake_len(s)`.
+pub const fn title_from_snake_bytes<const L: usize>(s: &str) -> [u8; L] {
+    let mut out = [0u8; L];
+    let mut i = 0;
+    let mut in_word = false;
+
+    iter_copy_slice! {b in s.as_bytes() =>
+        if b == b'_' {
+            in_word = false;
+        } else if in_word {
+            out[i] = b;
+            i += 1;
+        } else {
+            if i != 0 {
+                out[i] = b' ';
+                i += 1;
+            }
+            in_word = true;
+            out[i] = uppercase_u8(b);
+            i += 1;
+        }
+    }
+
+    out
+}
@@ -0,0 +1,66 @@
+use crate::pargument::Integer;
+
+pub struct FixedPointArgs {
+    /// `"-"` if the number is negative, `""` otherwise.
+    pub sign: &'static str,
+    /// The integer part of the absolute value of the number.
+    pub whole: u128,
+    /// `1` if the number has a fractional part, `0` if `scale` is zero.
+    pub has_fraction: usize,
+    /// The amount of leading zeroes in the fractional part.
+    pub fraction_zeros: usize,
+    /// The fractional part of the absolute value of the number.
+    pub fraction: u128,
+    pub scale: u32,
+}
+
+/// The largest scale for which `10.pow(scale)` fits in a `u128`.
+const MAX_SCALE: u32 = 38;
+
+const fn digit_count(mut n: u128) -> usize {
+    let mut digits = 1;
+    while n >= 10 {
+        n /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+impl FixedPointArgs {
+    pub const fn new(n: Integer, scale: u32) -> Self {
+        let magnitude = n.unsigned;
+        let sign = if n.is_negative { "-" } else { "" };
+
+        if scale == 0 || scale > MAX_SCALE {
+            return Self {
+                sign,
+                whole: magnitude,
+                has_fraction: 0,
+                fraction_zeros: 0,
+                fraction: 0,
+                scale,
+            };
+        }
+
+        let mut unit = 1u128;
+        __for_range! {_i in 0..scale =>
+            unit *= 10;
+        }
+        let fraction = magnitude % unit;
+
+        Self {
+            sign,
+            whole: magnitude / unit,
+            has_fraction: 1,
+            fraction_zeros: scale as usize - digit_count(fraction),
+            fraction,
+            scale,
+        }
+    }
+
+    pub const fn assert_valid(&self) {
+        if self.scale > MAX_SCALE {
+            [/* the scale can't be larger than 38 */][self.scale as usize]
+        }
+    }
+}
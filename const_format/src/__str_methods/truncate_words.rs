@@ -0,0 +1,63 @@
+use super::word_count::whitespace_len;
+
+pub struct TruncateWordsArgs {
+    pub str: &'static str,
+    /// The length in bytes of the kept prefix of `str`.
+    pub len: usize,
+    /// Whether any words were removed.
+    pub truncated: bool,
+}
+
+impl TruncateWordsArgs {
+    pub const fn new(str: &'static str, words: usize) -> Self {
+        let bytes = str.as_bytes();
+        let mut count = 0;
+        let mut in_word = false;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let ws_len = whitespace_len(bytes, i);
+            if ws_len != 0 {
+                if in_word && count == words {
+                    // the end of the last kept word,
+                    // truncating only if another word comes after it.
+                    let mut j = i + ws_len;
+                    while j < bytes.len() {
+                        let next_ws_len = whitespace_len(bytes, j);
+                        if next_ws_len == 0 {
+                            return Self {
+                                str,
+                                len: i,
+                                truncated: true,
+                            };
+                        }
+                        j += next_ws_len;
+                    }
+                    break;
+                }
+                in_word = false;
+                i += ws_len;
+            } else {
+                if !in_word {
+                    if count == words {
+                        // only reachable when `words == 0`
+                        return Self {
+                            str,
+                            len: 0,
+                            truncated: true,
+                        };
+                    }
+                    count += 1;
+                    in_word = true;
+                }
+                i += 1;
+            }
+        }
+
+        Self {
+            str,
+            len: str.len(),
+            truncated: false,
+        }
+    }
+}
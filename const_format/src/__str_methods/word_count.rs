@@ -0,0 +1,59 @@
+/// Counts the whitespace-separated words in `s`,
+/// like `s.split_whitespace().count()`.
+pub const fn word_count(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut count = 0;
+    let mut in_word = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let ws_len = whitespace_len(bytes, i);
+        if ws_len != 0 {
+            in_word = false;
+            i += ws_len;
+        } else {
+            if !in_word {
+                count += 1;
+                in_word = true;
+            }
+            i += 1;
+        }
+    }
+
+    count
+}
+
+/// Gets the length in bytes of the `char` at `bytes[i..]`
+/// if it has the `White_Space` unicode property, otherwise returns `0`.
+pub(super) const fn whitespace_len(bytes: &[u8], i: usize) -> usize {
+    match (
+        byte_at(bytes, i),
+        byte_at(bytes, i + 1),
+        byte_at(bytes, i + 2),
+    ) {
+        (b'\t'..=b'\r', _, _) | (b' ', _, _) => 1,
+        // U+0085 and U+00A0
+        (0xC2, 0x85, _) | (0xC2, 0xA0, _) => 2,
+        // U+1680
+        (0xE1, 0x9A, 0x80) => 3,
+        // U+2000 to U+200A, U+2028, U+2029, and U+202F
+        (0xE2, 0x80, 0x80..=0x8A)
+        | (0xE2, 0x80, 0xA8)
+        | (0xE2, 0x80, 0xA9)
+        | (0xE2, 0x80, 0xAF) => 3,
+        // U+205F
+        (0xE2, 0x81, 0x9F) => 3,
+        // U+3000
+        (0xE3, 0x80, 0x80) => 3,
+        _ => 0,
+    }
+}
+
+/// Gets `bytes[i]`, returning `0` if `i` is out of bounds.
+const fn byte_at(bytes: &[u8], i: usize) -> u8 {
+    if i < bytes.len() {
+        bytes[i]
+    } else {
+        0
+    }
+}
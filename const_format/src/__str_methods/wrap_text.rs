@@ -0,0 +1,164 @@
+use crate::utils::next_char_boundary;
+
+#[derive(Copy, Clone)]
+enum Token {
+    /// A word at `start..end` in the string, that's `chars` chars long.
+    Word {
+        start: usize,
+        end: usize,
+        chars: usize,
+    },
+    /// A `'\n'` in the string.
+    Newline,
+}
+
+impl Token {
+    const fn byte_len(self) -> usize {
+        match self {
+            Token::Word { start, end, .. } => end - start,
+            Token::Newline => 1,
+        }
+    }
+}
+
+/// Iterates over the words and newlines of a string, skipping the spaces.
+#[derive(Copy, Clone)]
+struct Tokens<'a> {
+    str: &'a str,
+    pos: usize,
+}
+
+impl<'a> Tokens<'a> {
+    const fn new(str: &'a str) -> Self {
+        Self { str, pos: 0 }
+    }
+
+    const fn next(mut self) -> Option<(Token, Self)> {
+        let bytes = self.str.as_bytes();
+        while self.pos < bytes.len() && bytes[self.pos] == b' ' {
+            self.pos += 1;
+        }
+
+        if self.pos == bytes.len() {
+            return None;
+        } else if bytes[self.pos] == b'\n' {
+            self.pos += 1;
+            return Some((Token::Newline, self));
+        }
+
+        let start = self.pos;
+        let mut chars = 0;
+        while self.pos < bytes.len() && bytes[self.pos] != b' ' && bytes[self.pos] != b'\n' {
+            self.pos = next_char_boundary(self.str, self.pos);
+            chars += 1;
+        }
+
+        let word = Token::Word {
+            start,
+            end: self.pos,
+            chars,
+        };
+        Some((word, self))
+    }
+}
+
+/// Tracks the column that the output is at, to decide where lines are broken.
+#[derive(Copy, Clone)]
+struct Layout {
+    width: usize,
+    column: usize,
+    at_line_start: bool,
+}
+
+impl Layout {
+    const fn new(width: usize) -> Self {
+        Self {
+            width,
+            column: 0,
+            at_line_start: true,
+        }
+    }
+
+    /// Places `token` in the output,
+    /// returning the byte that must be written before it (`0` if none).
+    const fn place(mut self, token: Token) -> (Self, u8) {
+        match token {
+            Token::Newline => {
+                self.column = 0;
+                self.at_line_start = true;
+                (self, 0)
+            }
+            Token::Word { chars, .. } => {
+                let separator = if self.at_line_start {
+                    0
+                } else if self.column + 1 + chars > self.width {
+                    self.column = 0;
+                    b'\n'
+                } else {
+                    self.column += 1;
+                    b' '
+                };
+
+                self.column += chars;
+                self.at_line_start = false;
+                (self, separator)
+            }
+        }
+    }
+}
+
+pub const fn wrap_text_len(s: &str, width: usize) -> usize {
+    let mut len = 0;
+    let mut tokens = Tokens::new(s);
+    let mut layout = Layout::new(width);
+
+    while let Some((token, next)) = tokens.next() {
+        tokens = next;
+
+        let (next_layout, separator) = layout.place(token);
+        layout = next_layout;
+
+        if separator != 0 {
+            len += 1;
+        }
+        len += token.byte_len();
+    }
+
+    len
+}
+
+/// Wraps `s` to `width` chars, `L` must be `wrap_text_len(s, width)`.
+pub const fn wrap_text_bytes<const L: usize>(s: &str, width: usize) -> [u8; L] {
+    let bytes = s.as_bytes();
+    let mut out = [0u8; L];
+    let mut i = 0;
+    let mut tokens = Tokens::new(s);
+    let mut layout = Layout::new(width);
+
+    while let Some((token, next)) = tokens.next() {
+        tokens = next;
+
+        let (next_layout, separator) = layout.place(token);
+        layout = next_layout;
+
+        if separator != 0 {
+            out[i] = separator;
+            i += 1;
+        }
+
+        match token {
+            Token::Word { start, end, .. } => {
+                __for_range! {j in start..end =>
+                    out[i] = bytes[j];
+                    i += 1;
+                }
+            }
+            Token::Newline => {
+                out[i] = b'\n';
+                i += 1;
+            }
+        }
+    }
+
+    out
+}
@@ -0,0 +1,73 @@
+pub struct ZipJoinArgs {
+    pub left: &'static [&'static str],
+    pub right: &'static [&'static str],
+    pub pair_sep: &'static str,
+    pub item_sep: &'static str,
+    /// The amount of pairs, the length of the shorter array.
+    pub pairs: usize,
+    pub out_len: usize,
+}
+
+impl ZipJoinArgs {
+    pub const fn new(
+        left: &'static [&'static str],
+        right: &'static [&'static str],
+        pair_sep: &'static str,
+        item_sep: &'static str,
+    ) -> Self {
+        let pairs = if left.len() < right.len() {
+            left.len()
+        } else {
+            right.len()
+        };
+
+        let mut out_len = 0;
+        __for_range! {i in 0..pairs =>
+            out_len += left[i].len() + pair_sep.len() + right[i].len();
+        }
+        if pairs > 1 {
+            out_len += (pairs - 1) * item_sep.len();
+        }
+
+        Self {
+            left,
+            right,
+            pair_sep,
+            item_sep,
+            pairs,
+            out_len,
+        }
+    }
+
+    pub const fn assert_valid(&self) {
+        if self.left.len() != self.right.len() {
+            [/* the arrays have different lengths */][self.right.len()]
+        }
+    }
+}
+
+/// Joins the pairs of strings in `args`, `L` must be `args.out_len`.
+pub const fn zip_join_bytes<const L: usize>(args: &ZipJoinArgs) -> [u8; L] {
+    let mut out = [0u8; L];
+    let mut o = 0;
+
+    macro_rules! write_str {
+        ($str:expr) => {
+            iter_copy_slice! {b in $str.as_bytes() =>
+                out[o] = b;
+                o += 1;
+            }
+        };
+    }
+
+    __for_range! {i in 0..args.pairs =>
+        if i != 0 {
+            write_str!(args.item_sep);
+        }
+        write_str!(args.left[i]);
+        write_str!(args.pair_sep);
+        write_str!(args.right[i]);
+    }
+
+    out
+}
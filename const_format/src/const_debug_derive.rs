@@ -78,12 +78,125 @@
 /// cfmt = {version = "0.*", package = "const_format"}
 /// ```
 ///
+/// ### `#[cdeb(as_int)]`
+///
+/// Prints the enum as the integer value of its discriminant,
+/// instead of the name of the variant.
+///
+/// This can only be used on enums whose variants have no fields.
+/// The integer has the type of the `#[repr(...)]` attribute if there is one,
+/// otherwise it's printed as an `isize`.
+///
+/// Example:
+///
+/// ```rust
+/// # #![feature(const_mut_refs)]
+/// use const_format::{ConstDebug, formatc};
+///
+/// #[derive(ConstDebug)]
+/// #[cdeb(as_int)]
+/// #[repr(u8)]
+/// enum Level {
+///     Low = 1,
+///     Medium = 5,
+///     High = 10,
+/// }
+///
+/// const TEXT: &str = formatc!("{:?} {:?} {:x?}", Level::Low, Level::Medium, Level::High);
+///
+/// assert_eq!(TEXT, "1 5 A");
+/// ```
+///
+/// ### `#[cdeb(transparent)]`
+///
+/// Prints the struct exactly like its only field,
+/// without the name of the struct.
+///
+/// This can only be used on structs with exactly one field that isn't
+/// ignored with `#[cdeb(ignore)]`.
+///
+/// Example:
+///
+/// ```rust
+/// # #![feature(const_mut_refs)]
+/// use const_format::{ConstDebug, formatc};
+///
+/// #[derive(ConstDebug)]
+/// #[cdeb(transparent)]
+/// struct Meters(u32);
+///
+/// #[derive(ConstDebug)]
+/// #[cdeb(transparent)]
+/// struct Name {
+///     name: &'static str,
+///     #[cdeb(ignore)]
+///     _len: usize,
+/// }
+///
+/// const TEXT: &str = formatc!("{:?} {:?}", Meters(100), Name { name: "joe", _len: 3 });
+///
+/// assert_eq!(TEXT, r#"100 "joe""#);
+/// ```
+///
+/// Using it on a struct with multiple fields is an error:
+///
+/// ```compile_fail
+/// # #![feature(const_mut_refs)]
+/// #[derive(const_format::ConstDebug)]
+/// #[cdeb(transparent)]
+/// struct Point(u32, u32);
+/// ```
+///
+/// ### `#[cdeb(named_fields)]`
+///
+/// Prints the fields of tuple structs and tuple variants with their index as their name,
+/// like the fields of braced structs are printed,
+/// eg: `Point { 0: 3, 1: 5 }` instead of `Point(3, 5)`.
+///
+/// This also allows using the `#[cdeb(rename = "...")]` attribute on tuple fields,
+/// to print them with a different name.
+///
+/// Example:
+///
+/// ```rust
+/// # #![feature(const_mut_refs)]
+/// use const_format::{ConstDebug, formatc};
+///
+/// #[derive(ConstDebug)]
+/// #[cdeb(named_fields)]
+/// struct Point(u32, u32);
+///
+/// #[derive(ConstDebug)]
+/// #[cdeb(named_fields)]
+/// struct Rgb(#[cdeb(rename = "r")] u8, #[cdeb(rename = "g")] u8, #[cdeb(rename = "b")] u8);
+///
+/// const TEXT: &str = formatc!("{:?} {:?}", Point(3, 5), Rgb(255, 128, 0));
+///
+/// assert_eq!(TEXT, "Point { 0: 3, 1: 5 } Rgb { r: 255, g: 128, b: 0 }");
+/// ```
+///
+/// # Variant attributes
+///
+/// ### `#[cdeb(rename = "NewName")]`
+///
+/// Prints the variant with the passed-in name,
+/// instead of the name of the variant.
+///
 /// # Field attributes
 ///
 /// ### `#[cdeb(ignore)]`
 ///
 /// Ignores the field, pretending that it doesn't exist.
 ///
+/// ### `#[cdeb(rename = "new_name")]`
+///
+/// Prints the field with the passed-in name,
+/// instead of the name of the field.
+///
+/// This can't be used on the fields of tuple structs or tuple variants,
+/// since those are printed without their name,
+/// unless the type has the `#[cdeb(named_fields)]` attribute.
+///
 /// ### `#[cdeb(with = "module::function")]`
 ///
 /// Uses the function at the passed-in path to format the field.
@@ -325,6 +438,34 @@
 ///
 ///
 /// 
+/// ### Renaming
+///
+/// This example demonstrates the `#[cdeb(rename = "...")]` attribute
+/// on both variants and fields.
+///
+/// ```rust
+/// #![feature(const_mut_refs)]
+///
+/// use const_format::{ConstDebug, formatc};
+///
+/// const E_FOO: &str = formatc!("{:?}", Enum::Foo{ field: 3 });
+/// const E_BAR: &str = formatc!("{:?}", Enum::Bar(5));
+///
+/// assert_eq!(E_FOO, "foo { renamed: 3 }");
+/// assert_eq!(E_BAR, "Bar(5)");
+///
+/// #[derive(ConstDebug)]
+/// enum Enum {
+///     #[cdeb(rename = "foo")]
+///     Foo {
+///         #[cdeb(rename = "renamed")]
+///         field: u32,
+///     },
+///     Bar(u32),
+/// }
+///
+/// ```
+///
 /// ### Renamed import
 /// 
 /// This example demonstrates that you can use all the macros when the `const_format`
@@ -16,6 +16,8 @@ pub const fn __priv_concatenate<const LEN: usize>(input: &[PArgument]) -> LenAnd
             PVariant::Str(s) => crate::__write_pvariant!(str, current, s => out),
             PVariant::Int(int) => crate::__write_pvariant!(int, current, int => out),
             PVariant::Char(c) => crate::__write_pvariant!(char, current, c => out),
+            PVariant::Ip(ip) => crate::__write_pvariant!(char, current, ip => out),
+            PVariant::Duration(d) => crate::__write_pvariant!(char, current, d => out),
         }
     }
 
@@ -226,5 +226,95 @@ pub struct AssertCP;
 /// const_format::assertcp_ne!(0u8, 0u8, "foo");
 /// ```
 ///
+/// # Formatting hint
+///
+/// ```rust
+/// const_format::assertcp_eq!(0u8, 0u8, fmt = "#x", "foo");
+/// ```
+///
+/// ```compile_fail
+/// const_format::assertcp_eq!(0u8, 10u8, fmt = "#x", "foo");
+/// ```
+///
+/// ```compile_fail
+/// const_format::assertcp_ne!(0u8, 0u8, fmt = "b");
+/// ```
+///
+/// ```compile_fail
+/// const_format::assertcp_eq!(0u8, 0u8, fmt = "y");
+/// ```
+///
+/// ```compile_fail
+/// const_format::assertcp_eq!(0u8, 0u8, fmt = 16);
+/// ```
+///
 #[cfg(feature = "assertcp")]
 pub struct AssertCPCmp;
+
+/// ```rust
+/// assert_eq!(const_format::pad_start!("ab", 4, '-'), "--ab");
+/// ```
+///
+/// ```compile_fail
+/// const _: &str = const_format::pad_start!("ab", usize::MAX, '-');
+/// ```
+///
+/// ```compile_fail
+/// const _: &str = const_format::center!("ab", usize::MAX / 2, 'ñ');
+/// ```
+///
+/// ```compile_fail
+/// const _: &str = const_format::format_fixed_width_int!(-42i8, usize::MAX, '0');
+/// ```
+///
+#[cfg(feature = "const_generics")]
+pub struct StrPadTooLarge;
+
+/// ```rust
+/// const _: &str = const_format::to_binary_padded!(u128::MAX, 128);
+/// ```
+///
+/// ```compile_fail
+/// const _: &str = const_format::to_binary_padded!(256u16, 8);
+/// ```
+///
+/// ```compile_fail
+/// const _: &str = const_format::to_binary_padded!(0u8, 129);
+/// ```
+///
+#[cfg(feature = "const_generics")]
+pub struct BinaryPaddedDoesntFit;
+
+/// ```rust
+/// const _: &str = const_format::grid!([1u8, 2u8, 3u8], cols = 1);
+/// ```
+///
+/// ```compile_fail
+/// const _: &str = const_format::grid!([1u8, 2u8, 3u8], cols = 0);
+/// ```
+///
+/// ```compile_fail
+/// const _: &str = const_format::grid!([], cols = 0);
+/// ```
+///
+#[cfg(feature = "const_generics")]
+pub struct GridZeroCols;
+
+/// ```rust
+/// const _: bool = const_format::is_valid_ident!("foo");
+/// ```
+///
+/// ```compile_fail
+/// const NAME: &str = "foo";
+/// const _: bool = const_format::is_valid_ident!(NAME);
+/// ```
+///
+/// ```compile_fail
+/// const _: bool = const_format::is_valid_ident!(b"foo");
+/// ```
+///
+/// ```compile_fail
+/// const _: bool = const_format::is_valid_ident!(10);
+/// ```
+///
+pub struct IsValidIdentNotLiteral;
@@ -0,0 +1,95 @@
+use core::time::Duration;
+
+/// The longest Duration, when debug formatted, is `u64::MAX` seconds,
+/// which is 20 digits, followed by a `.`, 9 fractional digits, and the `s` suffix.
+const MAX_DURATION_LEN: usize = 31;
+
+#[derive(Copy, Clone)]
+pub struct FmtDuration {
+    encoded: [u8; MAX_DURATION_LEN],
+    len: u8,
+}
+
+impl FmtDuration {
+    /// Array which contains the debug-formatted Duration,
+    /// only `&self.encoded[][..self.len()]` should be copied.
+    pub const fn encoded(&self) -> &[u8; MAX_DURATION_LEN] {
+        &self.encoded
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    #[cfg(feature = "fmt")]
+    pub(crate) const fn as_str(&self) -> &str {
+        let (bytes, _) = self.encoded.split_at(self.len());
+        // Safety: only complete UTF-8 strings are written into `self.encoded`
+        unsafe { core::str::from_utf8_unchecked(bytes) }
+    }
+
+    const fn push(mut self, byte: u8) -> Self {
+        self.encoded[self.len as usize] = byte;
+        self.len += 1;
+        self
+    }
+
+    const fn push_str(mut self, string: &str) -> Self {
+        let bytes = string.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            self = self.push(bytes[i]);
+            i += 1;
+        }
+        self
+    }
+
+    const fn push_decimal(mut self, n: u64) -> Self {
+        let mut divisor = 1;
+        while n / divisor >= 10 {
+            divisor *= 10;
+        }
+        loop {
+            self = self.push(b'0' + (n / divisor % 10) as u8);
+            if divisor == 1 {
+                break self;
+            }
+            divisor /= 10;
+        }
+    }
+}
+
+const EMPTY: FmtDuration = FmtDuration {
+    encoded: [0; MAX_DURATION_LEN],
+    len: 0,
+};
+
+/// Formats the Duration like its `Debug` impl in the standard library does,
+/// eg: `5s`, `1.5ms`, `100ns`.
+pub(crate) const fn duration_to_debug(duration: Duration) -> FmtDuration {
+    let secs = duration.as_secs();
+    let nanos = duration.subsec_nanos();
+
+    let (integer_part, mut fractional_part, mut divisor, suffix) = if secs > 0 {
+        (secs, nanos, 100_000_000, "s")
+    } else if nanos >= 1_000_000 {
+        ((nanos / 1_000_000) as u64, nanos % 1_000_000, 100_000, "ms")
+    } else if nanos >= 1_000 {
+        ((nanos / 1_000) as u64, nanos % 1_000, 100, "µs")
+    } else {
+        (nanos as u64, 0, 1, "ns")
+    };
+
+    let mut out = EMPTY.push_decimal(integer_part);
+
+    if fractional_part > 0 {
+        out = out.push(b'.');
+        while fractional_part > 0 {
+            out = out.push(b'0' + (fractional_part / divisor) as u8);
+            fractional_part %= divisor;
+            divisor /= 10;
+        }
+    }
+
+    out.push_str(suffix)
+}
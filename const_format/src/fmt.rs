@@ -66,6 +66,8 @@
 //! except that it does not escape unicode characters.
 //!
 //! - Display formatting (eg: `formatc!("{}", 0u8)`, `formatc!("{:}", 0u8)` )
+//! Arrays and slices of `&str`s and `char`s are Display formatted by concatenating
+//! their elements, without separators (eg: `formatc!("{}", ["foo", "bar"])` writes `foobar`).
 //!
 //! - Hexadecimal formatting (eg: `formatc!("{:x}", 0u8)`):
 //! Writes numbers in capialized hexadecimal.
@@ -74,6 +76,15 @@
 //! - Binary formatting (eg: `formatc!("{:b}", 0u8)`):
 //! This can be combined with debug formatting, with the `"{:b?}"` formatter.
 //!
+//! - Formatting from a constant (eg: `formatc!("{:@FLAGS}", 0u8)`):
+//! Uses the `FLAGS` [`FormattingFlags`] constant from the enclosing scope
+//! as the flags of the argument, with Display formatting.
+//! This can be combined with debug formatting, with the `"{:@FLAGS?}"` formatter.
+//! It's a compile-time error for `FLAGS` to be of any other type.
+//!
+//! - Custom labels for `bool`s (eg: `formatc!("{:bool(on,off)}", true)`):
+//! Writes `on` for `true`, and `off` for `false`.
+//!
 //! ### Alternate flag
 //!
 //! The alternate flag allows types to format themselves in an alternate way,
@@ -294,6 +305,7 @@
 //!
 //! [`writec`]: ../macro.writec.html
 //! [`Formatter`]: ./struct.Formatter.html
+//! [`FormattingFlags`]: ./struct.FormattingFlags.html
 //! [`FormatMarker`]: ../marker_traits/trait.FormatMarker.html
 //! [`ConstDebug`]: ../derive.ConstDebug.html
 //!
@@ -302,17 +314,31 @@
 //!
 //!
 
+mod const_int;
 mod error;
 mod formatter;
+#[cfg(feature = "heapless")]
+mod heapless_string;
+#[cfg(feature = "std")]
+mod std_string;
 mod std_type_impls;
 mod str_writer;
 mod str_writer_mut;
 
-pub use crate::formatting::{FormattingFlags, NumberFormatting};
+pub use crate::formatting::{Alignment, FormattingFlags, NumberFormatting};
 
 pub use self::{
+    const_int::ConstInt,
     error::{Error, Result, ToResult},
-    formatter::{ComputeStrLength, DebugList, DebugSet, DebugStruct, DebugTuple, Formatter},
+    formatter::{
+        ComputeStrLength, DebugList, DebugMap, DebugSet, DebugStruct, DebugTuple, Formatter,
+    },
     str_writer::StrWriter,
     str_writer_mut::{NoEncoding, StrWriterMut, Utf8Encoding},
 };
+
+#[cfg(feature = "heapless")]
+pub use self::heapless_string::HeaplessStringWriter;
+
+#[cfg(feature = "std")]
+pub use self::std_string::StdStringWriter;
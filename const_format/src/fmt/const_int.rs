@@ -0,0 +1,59 @@
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The integer primitive types,
+/// which can be written with the [`Formatter::write_int`] method.
+///
+/// This trait is sealed, so it can't be implemented outside of `const_format`.
+///
+/// [`Formatter::write_int`]: ./struct.Formatter.html#method.write_int
+pub trait ConstInt: sealed::Sealed + Copy {
+    #[doc(hidden)]
+    const __KIND: IntKind;
+}
+
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub enum IntKind {
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+}
+
+macro_rules! impl_const_int {
+    ($( ($ty:ty, $kind:ident) )*) => (
+        $(
+            impl sealed::Sealed for $ty {}
+
+            impl ConstInt for $ty {
+                #[doc(hidden)]
+                const __KIND: IntKind = IntKind::$kind;
+            }
+        )*
+    )
+}
+
+impl_const_int! {
+    (u8, U8)
+    (u16, U16)
+    (u32, U32)
+    (u64, U64)
+    (u128, U128)
+    (usize, Usize)
+    (i8, I8)
+    (i16, I16)
+    (i32, I32)
+    (i64, I64)
+    (i128, I128)
+    (isize, Isize)
+}
@@ -19,9 +19,9 @@ pub enum Error {
 impl Display for Error {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         match self {
-            Self::NotEnoughSpace => {
-                fmt.write_str("The was not enough space to write the formatted output")
-            }
+            Self::NotEnoughSpace => fmt.write_str(
+                "There was not enough space in the buffer to write the formatted output",
+            ),
             Self::NotAscii => fmt.write_str("Attempted to write non-ascii text"),
             Self::NotOnCharBoundary => {
                 fmt.write_str("Attempted to index a byte that's not on a char boundary.")
@@ -30,6 +30,10 @@ impl Display for Error {
     }
 }
 
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "core_error")))]
+#[cfg(feature = "core_error")]
+impl core::error::Error for Error {}
+
 macro_rules! index_vars{
     ($self:ident, $index:ident; $($variant:ident),* $(,)? ) => (
         enum Index{
@@ -54,7 +58,9 @@ impl Error {
         };
 
         match self {
-            Error::NotEnoughSpace => ["The was not enough space to write the formatted output"][i],
+            Error::NotEnoughSpace => {
+                ["There was not enough space in the buffer to write the formatted output"][i]
+            }
             Error::NotAscii => ["Attempted to write non-ascii text"][i],
             Error::NotOnCharBoundary => {
                 ["Attempted to index a byte that's not on a char boundary."][i]
@@ -1,5 +1,8 @@
 use crate::{
-    fmt::{Error, FormattingFlags, NoEncoding, StrWriter, StrWriterMut},
+    fmt::{
+        const_int::IntKind, Alignment, ConstInt, Error, FormattingFlags, NoEncoding, StrWriter,
+        StrWriterMut,
+    },
     utils::saturate_range,
     wrapper_types::{AsciiStr, PWrapper},
 };
@@ -44,7 +47,38 @@ use core::ops::Range;
 ///
 /// ```
 ///
+/// ### Using `writec`
+///
+/// A `ComputeStrLength` can also be passed to the [`writec`] macro,
+/// which adds the length of the formatted output instead of writing it,
+/// for when the code that does the formatting is a single `writec` invocation.
+///
+/// ```rust
+/// #![feature(const_mut_refs)]
+///
+/// use const_format::fmt::{ComputeStrLength, StrWriter};
+/// use const_format::{unwrap, writec};
+///
+/// const NAME: &str = "Bob";
+/// const AGE: u32 = 37;
+///
+/// const LEN: usize = {
+///     let mut computer = ComputeStrLength::new();
+///     unwrap!(writec!(computer, "{NAME:?} is {AGE} years old"));
+///     computer.len()
+/// };
+///
+/// let writer: &mut StrWriter = &mut StrWriter::new([0; LEN]);
+///
+/// writec!(writer, "{NAME:?} is {AGE} years old").unwrap();
+///
+/// assert_eq!(writer.as_str(), r#""Bob" is 37 years old"#);
+/// assert_eq!(writer.capacity(), LEN);
+///
+/// ```
+///
 /// [`formatc`]: ../macro.formatc.html
+/// [`writec`]: ../macro.writec.html
 ///
 ///
 pub struct ComputeStrLength {
@@ -62,6 +96,7 @@ impl ComputeStrLength {
     pub const fn make_formatter(&mut self, flags: FormattingFlags) -> Formatter<'_> {
         Formatter {
             margin: 0,
+            max_depth: flags.max_depth(),
             flags,
             writer: WriterBackend::Length(self),
         }
@@ -225,11 +260,24 @@ enum WriterBackend<'w> {
 pub struct Formatter<'w> {
     margin: u16,
     flags: FormattingFlags,
+    /// The `max_depth` that this formatter was constructed with,
+    /// kept separate from `flags` so that it's inherited by the formatters
+    /// constructed with `make_formatter`.
+    max_depth: Option<usize>,
     writer: WriterBackend<'w>,
 }
 
 const MARGIN_STEP: u16 = 4;
 
+/// The lower of two max depths, where `None` means that there's no limit.
+const fn min_max_depth(l: Option<usize>, r: Option<usize>) -> Option<usize> {
+    match (l, r) {
+        (Some(l), Some(r)) => Some(crate::utils::min_usize(l, r)),
+        (Some(x), None) | (None, Some(x)) => Some(x),
+        (None, None) => None,
+    }
+}
+
 impl<'w> Formatter<'w> {
     /// Constructs a `Formatter`.
     ///
@@ -260,6 +308,7 @@ impl<'w> Formatter<'w> {
     pub const fn from_sw(writer: &'w mut StrWriter, flags: FormattingFlags) -> Self {
         Self {
             margin: 0,
+            max_depth: flags.max_depth(),
             flags,
             // safety:
             // Formatter only writes valid utf8, which is valid for both
@@ -303,6 +352,7 @@ impl<'w> Formatter<'w> {
     ) -> Self {
         Self {
             margin: 0,
+            max_depth: flags.max_depth(),
             flags,
             // safety:
             // Formatter only writes valid utf8, which is valid for both
@@ -362,6 +412,7 @@ impl<'w> Formatter<'w> {
     ) -> Self {
         Self {
             margin: 0,
+            max_depth: flags.max_depth(),
             flags,
             writer: WriterBackend::Str(StrWriterMut::from_custom(buffer, length)),
         }
@@ -383,6 +434,7 @@ impl<'w> Formatter<'w> {
         *length = 0;
         Self {
             margin: 0,
+            max_depth: flags.max_depth(),
             flags,
             writer: WriterBackend::Str(StrWriterMut::from_custom(buffer, length)),
         }
@@ -394,6 +446,110 @@ impl<'w> Formatter<'w> {
         self.flags
     }
 
+    /// Sets the formatting flags of this `Formatter`, returning the previous flags.
+    ///
+    /// This is for temporarily overriding the flags of a `Formatter` around a few writes,
+    /// restoring them afterwards by passing the returned flags to this method.
+    ///
+    /// This doesn't change the max depth of this `Formatter`,
+    /// which comes from the flags that it was constructed with.
+    ///
+    /// For writing a single value with different flags,
+    /// you can also pass a formatter from [`make_formatter`](#method.make_formatter).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(const_mut_refs)]
+    ///
+    /// use const_format::{Error, Formatter, PWrapper};
+    /// use const_format::{formatc, impl_fmt, try_};
+    ///
+    /// struct Register {
+    ///     address: u32,
+    ///     value: u8,
+    ///     accesses: u32,
+    /// }
+    ///
+    /// impl_fmt!{
+    ///     impl Register;
+    ///
+    ///     const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+    ///         let mut f = f.debug_struct("Register");
+    ///
+    ///         // Writing a single field with different flags
+    ///         let fmt_address = f.field("address");
+    ///         let flags = fmt_address.flags().set_hexadecimal();
+    ///         try_!(PWrapper(self.address).const_debug_fmt(&mut fmt_address.make_formatter(flags)));
+    ///
+    ///         // Overriding the flags until they're restored
+    ///         let fmt_value = f.field("value");
+    ///         let prev = fmt_value.set_flags(fmt_value.flags().set_binary());
+    ///         try_!(PWrapper(self.value).const_debug_fmt(fmt_value));
+    ///         let _ = fmt_value.set_flags(prev);
+    ///
+    ///         try_!(PWrapper(self.accesses).const_debug_fmt(f.field("accesses")));
+    ///         f.finish()
+    ///     }
+    /// }
+    ///
+    /// const REG: Register = Register { address: 0xF00D, value: 5, accesses: 10 };
+    ///
+    /// assert_eq!(
+    ///     formatc!("{:?}", REG),
+    ///     "Register { address: F00D, value: 101, accesses: 10 }",
+    /// );
+    /// assert_eq!(
+    ///     formatc!("{:#?}", REG),
+    ///     "Register {\n    address: 0xF00D,\n    value: 0b101,\n    accesses: 10,\n}",
+    /// );
+    ///
+    /// ```
+    #[inline(always)]
+    #[must_use = "the previous flags are usually restored with another `set_flags` call"]
+    pub const fn set_flags(&mut self, flags: FormattingFlags) -> FormattingFlags {
+        let prev = self.flags;
+        self.flags = flags;
+        prev
+    }
+
+    /// Whether the alternate flag is enabled in this `Formatter`,
+    /// the alternate flag is written as `#` in format strings (eg: `"{:#?}"`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(const_mut_refs)]
+    ///
+    /// use const_format::{Error, Formatter, StrWriter};
+    /// use const_format::{formatc, impl_fmt, try_};
+    ///
+    /// struct Point(u32, u32);
+    ///
+    /// impl_fmt!{
+    ///     impl Point;
+    ///     
+    ///     const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+    ///         if f.alternate() {
+    ///             try_!(f.write_str("Point at "));
+    ///         }
+    ///         try_!(f.write_u32_display(self.0));
+    ///         try_!(f.write_str(","));
+    ///         f.write_u32_display(self.1)
+    ///     }
+    /// }
+    ///
+    /// const POINT: Point = Point(3, 5);
+    ///
+    /// assert_eq!(formatc!("{POINT:?}"), "3,5");
+    /// assert_eq!(formatc!("{POINT:#?}"), "Point at 3,5");
+    ///
+    /// ```
+    #[inline(always)]
+    pub const fn alternate(&self) -> bool {
+        self.flags.is_alternate()
+    }
+
     /// Gets how much indentation a data structure is printed with.
     pub const fn margin(&self) -> usize {
         self.margin as usize
@@ -409,6 +565,26 @@ impl<'w> Formatter<'w> {
     const fn decrement_margin(&mut self) {
         self.margin -= 4;
     }
+
+    /// Whether data structures written at this depth are formatted as `...`,
+    /// because of the `max_depth` of the formatting flags.
+    #[inline(always)]
+    const fn is_at_max_depth(&self) -> bool {
+        match self.max_depth {
+            Some(max_depth) => (self.margin / MARGIN_STEP) as usize >= max_depth,
+            None => false,
+        }
+    }
+
+    /// Whether this is inside a data structure that was formatted as `...`,
+    /// in which case everything written into this formatter is discarded.
+    #[inline(always)]
+    const fn is_past_max_depth(&self) -> bool {
+        match self.max_depth {
+            Some(max_depth) => (self.margin / MARGIN_STEP) as usize > max_depth,
+            None => false,
+        }
+    }
 }
 
 impl<'w> Formatter<'w> {
@@ -422,6 +598,11 @@ impl<'w> Formatter<'w> {
     ///
     /// The return value inherits the margin from this Formatter.
     ///
+    /// The return value also inherits the max depth of this Formatter,
+    /// using the max depth of `flags` instead when that is lower,
+    /// so that a reborrow can't write data structures that are nested more deeply
+    /// than this Formatter can.
+    ///
     /// This method exists because the [`writec`] macro gets a formatter from any writer
     /// by calling a `make_formatter` method.
     ///
@@ -482,6 +663,7 @@ impl<'w> Formatter<'w> {
     pub const fn make_formatter(&mut self, flags: FormattingFlags) -> Formatter<'_> {
         Formatter {
             margin: self.margin,
+            max_depth: min_max_depth(self.max_depth, flags.max_depth()),
             flags,
             writer: match &mut self.writer {
                 WriterBackend::Str(x) => WriterBackend::Str(x.reborrow()),
@@ -501,7 +683,7 @@ impl<'w> Formatter<'w> {
     ///
     #[inline]
     pub const fn debug_struct(&mut self, name: &str) -> DebugStruct<'_, 'w> {
-        let err = self.write_str(name);
+        let err = self.write_str(if self.is_at_max_depth() { "..." } else { name });
         DebugStruct {
             fmt: self.increment_margin(),
             wrote_field: false,
@@ -519,7 +701,7 @@ impl<'w> Formatter<'w> {
     ///
     #[inline]
     pub const fn debug_tuple(&mut self, name: &str) -> DebugTuple<'_, 'w> {
-        let err = self.write_str(name);
+        let err = self.write_str(if self.is_at_max_depth() { "..." } else { name });
         DebugTuple {
             fmt: self.increment_margin(),
             wrote_field: false,
@@ -537,10 +719,15 @@ impl<'w> Formatter<'w> {
     ///
     #[inline]
     pub const fn debug_list(&mut self) -> DebugList<'_, 'w> {
+        let err = if self.is_at_max_depth() {
+            self.write_str("...")
+        } else {
+            Ok(())
+        };
         DebugList {
             fmt: self.increment_margin(),
             wrote_field: false,
-            err: Ok(()),
+            err,
         }
     }
 
@@ -554,11 +741,246 @@ impl<'w> Formatter<'w> {
     ///
     #[inline]
     pub const fn debug_set(&mut self) -> DebugSet<'_, 'w> {
+        let err = if self.is_at_max_depth() {
+            self.write_str("...")
+        } else {
+            Ok(())
+        };
         DebugSet {
             fmt: self.increment_margin(),
             wrote_field: false,
-            err: Ok(()),
+            err,
+        }
+    }
+
+    /// For debug writing a map.
+    ///
+    /// # Examples
+    ///
+    /// For examples of using this method, you can look at the docs for [`DebugMap`]
+    ///
+    /// [`DebugMap`]: ./struct.DebugMap.html
+    ///
+    #[inline]
+    pub const fn debug_map(&mut self) -> DebugMap<'_, 'w> {
+        let err = if self.is_at_max_depth() {
+            self.write_str("...")
+        } else {
+            Ok(())
+        };
+        DebugMap {
+            fmt: self.increment_margin(),
+            wrote_field: false,
+            err,
+        }
+    }
+
+    /// Writes the `value` integer, using `flags` to determine how it's formatted.
+    ///
+    /// This dispatches to the `write_*_debug` method for the type of `value`,
+    /// making it possible to write integers in code that's generic over
+    /// the integer type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(const_mut_refs)]
+    ///
+    /// use const_format::{Formatter, FormattingFlags, StrWriter};
+    /// use const_format::{fmt::ConstInt, try_};
+    ///
+    /// const fn write_pair<L: ConstInt, R: ConstInt>(
+    ///     f: &mut Formatter<'_>,
+    ///     left: L,
+    ///     right: R,
+    /// ) -> const_format::Result {
+    ///     let flags = f.flags();
+    ///     try_!(f.write_int(left, flags));
+    ///     try_!(f.write_str(", "));
+    ///     f.write_int(right, flags)
+    /// }
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+    ///
+    /// let _ = write_pair(&mut writer.make_formatter(FormattingFlags::NEW), 3u8, -5i64);
+    /// assert_eq!(writer.as_str(), "3, -5");
+    ///
+    /// writer.clear();
+    /// let flags = FormattingFlags::NEW.set_hexadecimal();
+    /// let _ = write_pair(&mut writer.make_formatter(flags), 255u16, -1i8);
+    /// assert_eq!(writer.as_str(), "FF, FF");
+    ///
+    /// ```
+    pub const fn write_int<I: ConstInt>(
+        &mut self,
+        value: I,
+        flags: FormattingFlags,
+    ) -> Result<(), Error> {
+        let mut f = self.make_formatter(flags);
+        let ptr = &value as *const I;
+
+        // safety: `I::__KIND` is the variant for the type of `value`,
+        // so `ptr` is cast to a pointer to the same type.
+        unsafe {
+            match I::__KIND {
+                IntKind::U8 => f.write_u8_debug(*(ptr as *const u8)),
+                IntKind::U16 => f.write_u16_debug(*(ptr as *const u16)),
+                IntKind::U32 => f.write_u32_debug(*(ptr as *const u32)),
+                IntKind::U64 => f.write_u64_debug(*(ptr as *const u64)),
+                IntKind::U128 => f.write_u128_debug(*(ptr as *const u128)),
+                IntKind::Usize => f.write_usize_debug(*(ptr as *const usize)),
+                IntKind::I8 => f.write_i8_debug(*(ptr as *const i8)),
+                IntKind::I16 => f.write_i16_debug(*(ptr as *const i16)),
+                IntKind::I32 => f.write_i32_debug(*(ptr as *const i32)),
+                IntKind::I64 => f.write_i64_debug(*(ptr as *const i64)),
+                IntKind::I128 => f.write_i128_debug(*(ptr as *const i128)),
+                IntKind::Isize => f.write_isize_debug(*(ptr as *const isize)),
+            }
+        }
+    }
+
+    /// Writes `values` with the `flags` formatting flags, separated by `sep`.
+    ///
+    /// This writes each integer like [`write_int`](#method.write_int) does,
+    /// so the `flags` passed to this method override the ones of the Formatter.
+    ///
+    /// Like the other `write_*` methods, this doesn't write anything
+    /// when it returns `Error::NotEnoughSpace`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(const_mut_refs)]
+    ///
+    /// use const_format::{Formatter, FormattingFlags, StrWriter};
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+    /// let mut fmt = writer.make_formatter(FormattingFlags::NEW);
+    ///
+    /// let _ = fmt.write_separated_ints(&[3u8, 5, 8], ", ", FormattingFlags::NEW);
+    /// let _ = fmt.write_str(" | ");
+    /// let _ = fmt.write_separated_ints(&[-1i16, 255], ":", FormattingFlags::NEW.set_hexadecimal());
+    ///
+    /// assert_eq!(writer.as_str(), "3, 5, 8 | FFFF:FF");
+    ///
+    /// ```
+    pub const fn write_separated_ints<I: ConstInt>(
+        &mut self,
+        values: &[I],
+        sep: &str,
+        flags: FormattingFlags,
+    ) -> Result<(), Error> {
+        // Checking the space up front, so that nothing is written
+        // when there's no space for all the integers.
+        if let WriterBackend::Str(writer) = &self.writer {
+            let mut len = ComputeStrLength::new();
+            let _ = len
+                .make_formatter(self.flags)
+                .write_separated_ints(values, sep, flags);
+            if len.len() > writer.remaining_capacity() {
+                return Err(Error::NotEnoughSpace);
+            }
+        }
+
+        __for_range! {i in 0..values.len() =>
+            if i != 0 {
+                try_!(self.write_str(sep));
+            }
+            try_!(self.write_int(values[i], flags));
+        }
+        Ok(())
+    }
+
+    /// Writes `string` padded with `fill` up to `width` chars,
+    /// placing `string` inside the padding as determined by `align`.
+    ///
+    /// The width is measured in `char`s, not bytes.
+    /// If `string` is already `width` chars long or longer,
+    /// it's written without padding, and without truncating it.
+    ///
+    /// Like the other `write_*` methods, this doesn't write anything
+    /// when it returns `Error::NotEnoughSpace`,
+    /// neither the padding nor the string.
+    ///
+    /// This returns `Error::NotEnoughSpace` when computing the length of the
+    /// padded string overflows a `usize`, even when computing the length
+    /// of the formatted output with a [`ComputeStrLength`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(const_mut_refs)]
+    ///
+    /// use const_format::{Formatter, FormattingFlags, StrWriter};
+    /// use const_format::fmt::Alignment;
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+    /// let mut fmt = writer.make_formatter(FormattingFlags::NEW);
+    ///
+    /// let _ = fmt.write_str_aligned("foo", 7, Alignment::Left, '-');
+    /// let _ = fmt.write_str("|");
+    /// let _ = fmt.write_str_aligned("foo", 7, Alignment::Center, '-');
+    /// let _ = fmt.write_str("|");
+    /// let _ = fmt.write_str_aligned("foo", 7, Alignment::Right, '-');
+    /// let _ = fmt.write_str("|");
+    /// let _ = fmt.write_str_aligned("鉄錆", 4, Alignment::Right, '·');
+    /// let _ = fmt.write_str("|");
+    /// let _ = fmt.write_str_aligned("too long", 4, Alignment::Center, ' ');
+    ///
+    /// assert_eq!(writer.as_str(), "foo----|--foo--|----foo|··鉄錆|too long");
+    ///
+    /// ```
+    pub const fn write_str_aligned(
+        &mut self,
+        string: &str,
+        width: usize,
+        align: Alignment,
+        fill: char,
+    ) -> Result<(), Error> {
+        let padding = width.saturating_sub(crate::utils::count_chars(string));
+        let (before, after) = match align {
+            Alignment::Left => (0, padding),
+            Alignment::Center => (padding / 2, padding - padding / 2),
+            Alignment::Right => (padding, 0),
+        };
+
+        if self.is_past_max_depth() {
+            return Ok(());
+        }
+
+        let fill_len = crate::char_encoding::char_display_len(fill);
+        let len = match padding.checked_mul(fill_len) {
+            Some(padding_len) => padding_len.checked_add(string.len()),
+            None => None,
+        };
+        // A padded string whose length overflows can't fit in any buffer.
+        let len = match len {
+            Some(len) => len,
+            None => return Err(Error::NotEnoughSpace),
+        };
+
+        match &mut self.writer {
+            WriterBackend::Length(fmt_len) => {
+                fmt_len.add_len(len);
+                return Ok(());
+            }
+            // Checking the space up front, so that the padding isn't written
+            // when there's no space for the string.
+            WriterBackend::Str(writer) => {
+                if len > writer.remaining_capacity() {
+                    return Err(Error::NotEnoughSpace);
+                }
+            }
+        }
+
+        __for_range! {_i in 0..before =>
+            try_!(self.write_char(fill));
+        }
+        try_!(self.write_str(string));
+        __for_range! {_i in 0..after =>
+            try_!(self.write_char(fill));
         }
+        Ok(())
     }
 }
 
@@ -582,7 +1004,10 @@ macro_rules! field_method_impl {
         len(|$fmt_len:ident| $($write_name_len:tt)*)
         fmt(|$writer:ident| $($write_name_fmt:tt)*)
     ) => ({
+        let is_past_max_depth = $self.fmt.is_past_max_depth();
         match &mut $self.fmt.writer {
+            // The data structure was formatted as `...`
+            _ if is_past_max_depth => {}
             WriterBackend::Length($fmt_len)=>{
                 let $fmt_len = &mut **$fmt_len;
 
@@ -627,6 +1052,12 @@ macro_rules! finish_method_impl {
             return result;
         }
 
+        // The data structure was formatted as `...`
+        if $self.fmt.is_past_max_depth() {
+            $self.fmt.decrement_margin();
+            return Ok(());
+        }
+
         $self.fmt.decrement_margin();
         if $self.wrote_field {
             match &mut $self.fmt.writer {
@@ -849,6 +1280,12 @@ macro_rules! finish_listset_method_impl {
             return result;
         }
 
+        // The data structure was formatted as `...`
+        if $self.fmt.is_past_max_depth() {
+            $self.fmt.margin -= MARGIN_STEP;
+            return Ok(());
+        }
+
         match &mut $self.fmt.writer {
             WriterBackend::Length(fmt_len) => {
                 let fmt_len = &mut **fmt_len;
@@ -1005,6 +1442,86 @@ impl<'f, 'w> DebugSet<'f, 'w> {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// For debug formatting a map.
+///
+/// Every entry is written by calling [`key`](#method.key) and writing the key,
+/// then calling [`value`](#method.value) and writing the value.
+///
+/// # Example
+///
+/// This example demonstrates how you can debug format a custom type as a map.
+///
+/// ```rust
+/// #![feature(const_mut_refs)]
+///
+/// use const_format::{Error, Formatter};
+/// use const_format::{formatc, impl_fmt, try_};
+///
+/// fn main() {
+///     const MAP: &str = formatc!("{:?}", SmallMap(&[("foo", 3), ("bar", 5)]));
+///     
+///     assert_eq!(MAP, r#"{"foo": 3, "bar": 5}"#);
+///
+///     const ALT: &str = formatc!("{:#?}", SmallMap(&[("foo", 3), ("bar", 5)]));
+///
+///     assert_eq!(ALT, "{\n    \"foo\": 3,\n    \"bar\": 5,\n}");
+/// }
+///
+/// struct SmallMap(&'static [(&'static str, u32)]);
+///
+/// impl_fmt!{
+///     impl SmallMap;
+///     
+///     const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+///         let mut f = f.debug_map();
+///         let mut i = 0;
+///         while i < self.0.len() {
+///             let (key, value) = self.0[i];
+///             try_!(f.key().write_str_debug(key));
+///             try_!(f.value().write_u32_debug(value));
+///             i+=1;
+///         }
+///         f.finish()
+///     }
+/// }
+///
+/// ```
+///
+pub struct DebugMap<'f, 'w> {
+    fmt: &'f mut Formatter<'w>,
+    wrote_field: bool,
+    err: Result<(), Error>,
+}
+
+impl<'f, 'w> DebugMap<'f, 'w> {
+    /// Adds the key of an entry to the formatted output,
+    /// which must be followed by a call to [`value`](#method.value).
+    pub const fn key(&mut self) -> &mut Formatter<'w> {
+        field_method_impl!(self, "{", "{\n"; len(|fmt_len|) fmt(|writer|) )
+    }
+
+    /// Adds the value of an entry to the formatted output,
+    /// after the key written with [`key`](#method.key).
+    pub const fn value(&mut self) -> &mut Formatter<'w> {
+        let is_past_max_depth = self.fmt.is_past_max_depth();
+        match &mut self.fmt.writer {
+            // The data structure was formatted as `...`
+            _ if is_past_max_depth => {}
+            WriterBackend::Length(fmt_len) => fmt_len.add_len(COLON_SPACE_LEN),
+            WriterBackend::Str(writer) => trys!(writer.write_str(": "), self),
+        }
+        self.fmt
+    }
+
+    /// Finishes writing the map,
+    /// and if anything went wrong in the `key` or `value` methods,returns an error.
+    pub const fn finish(self) -> Result<(), Error> {
+        finish_listset_method_impl!(self, "}", "{}")
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 macro_rules! delegate_write_methods {
     (
         shared_attrs $shared_attrs:tt
@@ -1038,6 +1555,10 @@ macro_rules! delegate_write_methods {
         $( #[$shared_attrs] )*
         $(#[$attrs])*
         pub const fn $method(&mut self, $($arg: $arg_ty ),*  ) -> Result<(), Error> {
+            if self.is_past_max_depth() {
+                return Ok(());
+            }
+
             match &mut self.writer {
                 WriterBackend::Length(fmt_len)=>{
                     fmt_len.add_len($len);
@@ -1396,6 +1917,10 @@ macro_rules! delegate_integer_debug_methods {
         $( #[$shared_attrs] )*
         $(#[$attrs])*
         pub const fn $method(&mut self, $($arg: $arg_ty ),*  ) -> Result<(), Error> {
+            if self.is_past_max_depth() {
+                return Ok(());
+            }
+
             let $flags = self.flags;
 
             match &mut self.writer {
@@ -0,0 +1,79 @@
+use super::{Formatter, FormattingFlags};
+
+use heapless::{String, Vec};
+
+/// For writing a formatted string into a [`heapless::String`].
+///
+/// # Construction
+///
+/// This type is constructed with the [`new`](#method.new) constructor,
+/// it's also what mutable references to `heapless::String`s are converted into
+/// when they're passed to the [`writec`] macro.
+///
+/// # Capacity
+///
+/// The string is written into until it reaches its `N` capacity,
+/// after which the `write_*` methods return `Err(Error::NotEnoughSpace)`.
+///
+/// # Features
+///
+/// This type is only exported with the "heapless" feature.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(const_mut_refs)]
+/// # extern crate heapless_crate as heapless;
+///
+/// use const_format::{writec, Error};
+///
+/// use heapless::String;
+///
+/// let mut string = String::<16>::new();
+///
+/// writec!(string, "{}-{:?}", 3u8, "foo")?;
+/// assert_eq!(string, r#"3-"foo""#);
+///
+/// writec!(&mut string, "{:x}", 255u8)?;
+/// assert_eq!(string, r#"3-"foo"FF"#);
+///
+/// // The string doesn't have enough capacity for this argument,
+/// // so nothing is written.
+/// assert_eq!(writec!(string, "{}", u64::MAX), Err(Error::NotEnoughSpace));
+/// assert_eq!(string, r#"3-"foo"FF"#);
+///
+/// # Ok::<(), const_format::Error>(())
+/// ```
+///
+/// [`heapless::String`]: https://docs.rs/heapless/0.7/heapless/struct.String.html
+/// [`writec`]: ../macro.writec.html
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "heapless")))]
+pub struct HeaplessStringWriter<'w, const N: usize> {
+    vec: &'w mut Vec<u8, N>,
+    len: usize,
+}
+
+impl<'w, const N: usize> HeaplessStringWriter<'w, N> {
+    /// Constructs a `HeaplessStringWriter` that appends to `string`.
+    pub fn new(string: &'w mut String<N>) -> Self {
+        // safety: only complete UTF-8 strings are written into the vector,
+        // and `drop` truncates it to the length of the written string.
+        let vec = unsafe { string.as_mut_vec() };
+        let len = vec.len();
+        Self { vec, len }
+    }
+
+    /// Constructs a `Formatter` that writes into the string.
+    pub fn make_formatter(&mut self, flags: FormattingFlags) -> Formatter<'_> {
+        // Zero initializing the unused capacity of the string,
+        // so that it can be written into as a `&mut [u8]`.
+        let _ = self.vec.resize(N, 0);
+        Formatter::from_custom(&mut self.vec[..], &mut self.len, flags)
+    }
+}
+
+impl<const N: usize> Drop for HeaplessStringWriter<'_, N> {
+    fn drop(&mut self) {
+        self.vec.truncate(self.len);
+    }
+}
@@ -0,0 +1,87 @@
+use super::{Formatter, FormattingFlags};
+
+use std::{string::String, vec::Vec};
+
+/// For writing a formatted string into a [`String`].
+///
+/// # Construction
+///
+/// This type is constructed with the [`new`](#method.new) constructor,
+/// it's also what mutable references to `String`s are converted into
+/// when they're passed to the [`writec`] macro.
+///
+/// # Capacity
+///
+/// The [`writec`] macro computes the length of the formatted string before writing it,
+/// reserving enough capacity in the `String` for it,
+/// so writing into a `String` never returns `Err(Error::NotEnoughSpace)`.
+///
+/// Formatters returned by [`make_formatter`](#method.make_formatter) can only write
+/// up to the capacity of the `String`,
+/// which can be increased beforehand with the [`reserve`](#method.reserve) method.
+///
+/// # Features
+///
+/// This type is only exported with the "std" feature.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(const_mut_refs)]
+///
+/// use const_format::{writec, Error};
+///
+/// let mut string = String::new();
+///
+/// writec!(string, "{}-{:?}", 3u8, "foo")?;
+/// assert_eq!(string, r#"3-"foo""#);
+///
+/// writec!(&mut string, "{:x}", 255u8)?;
+/// assert_eq!(string, r#"3-"foo"FF"#);
+///
+/// // The string grows as needed
+/// writec!(string, "{:?}", [u64::MAX; 10])?;
+/// assert!(string.ends_with("18446744073709551615, 18446744073709551615]"));
+///
+/// # Ok::<(), const_format::Error>(())
+/// ```
+///
+/// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+/// [`writec`]: ../macro.writec.html
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "std")))]
+pub struct StdStringWriter<'w> {
+    vec: &'w mut Vec<u8>,
+    len: usize,
+}
+
+impl<'w> StdStringWriter<'w> {
+    /// Constructs a `StdStringWriter` that appends to `string`.
+    pub fn new(string: &'w mut String) -> Self {
+        // safety: only complete UTF-8 strings are written into the vector,
+        // and `drop` truncates it to the length of the written string.
+        let vec = unsafe { string.as_mut_vec() };
+        let len = vec.len();
+        Self { vec, len }
+    }
+
+    /// Reserves capacity for at least `additional` more bytes to be written.
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec.truncate(self.len);
+        self.vec.reserve(additional);
+    }
+
+    /// Constructs a `Formatter` that writes into the string.
+    pub fn make_formatter(&mut self, flags: FormattingFlags) -> Formatter<'_> {
+        // Zero initializing the unused capacity of the string,
+        // so that it can be written into as a `&mut [u8]`.
+        let capacity = self.vec.capacity();
+        self.vec.resize(capacity, 0);
+        Formatter::from_custom(&mut self.vec[..], &mut self.len, flags)
+    }
+}
+
+impl Drop for StdStringWriter<'_> {
+    fn drop(&mut self) {
+        self.vec.truncate(self.len);
+    }
+}
@@ -69,6 +69,26 @@ slice_of_std_impl! {
     usize, isize,
 }
 
+// Display formatting of slices of strings and chars concatenates the elements,
+// there's no Display impl for slices in std to imitate.
+impl PWrapper<&[&str]> {
+    pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        __for_range! {i in 0..self.0.len() =>
+            try_!(f.write_str(self.0[i]));
+        }
+        Ok(())
+    }
+}
+
+impl PWrapper<&[char]> {
+    pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        __for_range! {i in 0..self.0.len() =>
+            try_!(f.write_char(self.0[i]));
+        }
+        Ok(())
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 use core::{
@@ -150,6 +170,79 @@ non_zero_impls! {
     NonZeroU128, NonZeroI128, NonZeroUsize, NonZeroIsize,
 }
 
+use core::num::{Saturating, Wrapping};
+
+// `Wrapping` and `Saturating` are formatted as the integer they wrap,
+// like their `Debug` and `Display` impls in the standard library do.
+macro_rules! wrapper_int_impls {
+    ($($ty:ident,)*) => (
+        $(
+            std_kind_impl!{ impl[] Wrapping<$ty> }
+
+            impl PWrapper<Wrapping<$ty>> {
+                #[inline(always)]
+                pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                    PWrapper(self.0 .0).const_debug_fmt(f)
+                }
+
+                #[inline(always)]
+                pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                    PWrapper(self.0 .0).const_display_fmt(f)
+                }
+            }
+
+            std_kind_impl!{ impl[] Saturating<$ty> }
+
+            impl PWrapper<Saturating<$ty>> {
+                #[inline(always)]
+                pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                    PWrapper(self.0 .0).const_debug_fmt(f)
+                }
+
+                #[inline(always)]
+                pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                    PWrapper(self.0 .0).const_display_fmt(f)
+                }
+            }
+        )*
+    )
+}
+
+wrapper_int_impls! {
+    u8, i8, u16, i16,
+    u32, i32, u64, i64,
+    u128, i128, usize, isize,
+}
+
+use core::cmp::Reverse;
+
+// Unlike `Wrapping`, `Reverse` is Debug formatted as a tuple struct in the standard library.
+impl_fmt! {
+    is_std_type;
+
+    impl[] Reverse<u8>;
+    impl[] Reverse<i8>;
+    impl[] Reverse<u16>;
+    impl[] Reverse<i16>;
+    impl[] Reverse<u32>;
+    impl[] Reverse<i32>;
+    impl[] Reverse<u64>;
+    impl[] Reverse<i64>;
+    impl[] Reverse<u128>;
+    impl[] Reverse<i128>;
+    impl[] Reverse<usize>;
+    impl[] Reverse<isize>;
+    impl[] Reverse<bool>;
+    impl[] Reverse<char>;
+    impl['a,] Reverse<&'a str>;
+
+    pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut f = f.debug_tuple("Reverse");
+        try_!(PWrapper(self.0 .0).const_debug_fmt(f.field()));
+        f.finish()
+    }
+}
+
 std_kind_impl! { impl[T,] *mut T }
 // Unfortunately, can't print pointer addresses at compile-time.
 impl<T> PWrapper<*mut T> {
@@ -236,3 +329,85 @@ impl_fmt! {
         }
     }
 }
+
+use core::{fmt::Alignment, num::FpCategory};
+
+impl_fmt! {
+    is_std_type;
+
+    impl FpCategory;
+
+    pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self.0 {
+            FpCategory::Nan => f.write_str("Nan"),
+            FpCategory::Infinite => f.write_str("Infinite"),
+            FpCategory::Zero => f.write_str("Zero"),
+            FpCategory::Subnormal => f.write_str("Subnormal"),
+            FpCategory::Normal => f.write_str("Normal"),
+        }
+    }
+}
+
+impl_fmt! {
+    is_std_type;
+
+    impl Alignment;
+
+    pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self.0 {
+            Alignment::Left => f.write_str("Left"),
+            Alignment::Right => f.write_str("Right"),
+            Alignment::Center => f.write_str("Center"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+use core::time::Duration;
+
+impl_fmt! {
+    is_std_type;
+
+    impl Duration;
+
+    /// Formats the Duration like the `Debug` impl in the standard library does,
+    /// eg: `5s`, `1.5ms`, `100ns`.
+    pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str(crate::duration_encoding::duration_to_debug(self.0).as_str())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "net")]
+macro_rules! ip_addr_impls {
+    ( $( ($Ip:ty, $to_display:ident) )* ) => (
+        $(
+            impl_fmt! {
+                is_std_type;
+
+                impl $Ip;
+
+                /// Formats the address like its `Display` impl in the standard library does.
+                pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                    f.write_str(crate::ip_encoding::$to_display(self.0).as_str())
+                }
+
+                /// Formats the address like its `Debug` impl in the standard library does,
+                /// which is the same as its `Display` impl.
+                #[inline(always)]
+                pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                    self.const_display_fmt(f)
+                }
+            }
+        )*
+    );
+}
+
+#[cfg(feature = "net")]
+ip_addr_impls! {
+    (core::net::Ipv4Addr, ipv4_to_display)
+    (core::net::Ipv6Addr, ipv6_to_display)
+    (core::net::IpAddr, ip_to_display)
+}
@@ -1,5 +1,5 @@
 use crate::{
-    fmt::{Error, Formatter},
+    fmt::{ConstInt, Error, Formatter},
     marker_traits::{FormatMarker, IsAFormatMarker, IsStdKind},
     wrapper_types::PWrapper,
 };
@@ -8,14 +8,14 @@ use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInc
 
 ////////////////////////////////////////////////////////////////////////////////
 
-impl FormatMarker for Range<usize> {
+impl<T: ConstInt> FormatMarker for Range<T> {
     type Kind = IsStdKind;
     type This = Self;
 }
 
-impl<T> IsAFormatMarker<IsStdKind, Range<usize>, T> {
+impl<T: ConstInt, U> IsAFormatMarker<IsStdKind, Range<T>, U> {
     #[inline(always)]
-    pub const fn coerce(self, range: &Range<usize>) -> PWrapper<Range<usize>> {
+    pub const fn coerce(self, range: &Range<T>) -> PWrapper<Range<T>> {
         PWrapper(Range {
             start: range.start,
             end: range.end,
@@ -23,14 +23,15 @@ impl<T> IsAFormatMarker<IsStdKind, Range<usize>, T> {
     }
 }
 
-impl PWrapper<Range<usize>> {
+impl<T: ConstInt> PWrapper<Range<T>> {
     const RANGE: &'static str = "..";
 
     #[inline(always)]
     pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        try_!(PWrapper(self.0.start).const_debug_fmt(f));
+        let flags = f.flags();
+        try_!(f.write_int(self.0.start, flags));
         try_!(PWrapper(Self::RANGE).const_display_fmt(f));
-        try_!(PWrapper(self.0.end).const_debug_fmt(f));
+        try_!(f.write_int(self.0.end, flags));
         Ok(())
     }
 }
@@ -115,26 +116,27 @@ impl PWrapper<RangeToInclusive<usize>> {
 
 ///////////////////////////////////////////////////////////////////////////////
 
-impl FormatMarker for RangeInclusive<usize> {
+impl<T: ConstInt> FormatMarker for RangeInclusive<T> {
     type Kind = IsStdKind;
     type This = Self;
 }
 
-impl<T> IsAFormatMarker<IsStdKind, RangeInclusive<usize>, T> {
+impl<T: ConstInt, U> IsAFormatMarker<IsStdKind, RangeInclusive<T>, U> {
     #[inline(always)]
-    pub const fn coerce(self, range: &RangeInclusive<usize>) -> PWrapper<RangeInclusive<usize>> {
+    pub const fn coerce(self, range: &RangeInclusive<T>) -> PWrapper<RangeInclusive<T>> {
         PWrapper(RangeInclusive::new(*range.start(), *range.end()))
     }
 }
 
-impl PWrapper<RangeInclusive<usize>> {
+impl<T: ConstInt> PWrapper<RangeInclusive<T>> {
     const RANGE: &'static str = "..=";
 
     #[inline(always)]
     pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        try_!(PWrapper(*self.0.start()).const_debug_fmt(f));
+        let flags = f.flags();
+        try_!(f.write_int(*self.0.start(), flags));
         try_!(PWrapper(Self::RANGE).const_display_fmt(f));
-        try_!(PWrapper(*self.0.end()).const_debug_fmt(f));
+        try_!(f.write_int(*self.0.end(), flags));
         Ok(())
     }
 }
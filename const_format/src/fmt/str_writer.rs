@@ -404,6 +404,7 @@ impl StrWriter {
         pub fn as_str(&self) -> &str {
             // All the methods that modify the buffer must ensure utf8 validity,
             // only methods from this module need to ensure this.
+            debug_assert!(core::str::from_utf8(self.as_bytes()).is_ok());
             unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
         }
 
@@ -548,6 +548,7 @@ impl<'w> StrWriterMut<'w, Utf8Encoding> {
         pub fn as_str(&self) -> &str {
             // All the methods that modify the buffer must ensure utf8 validity,
             // only methods from this module need to ensure this.
+            debug_assert!(core::str::from_utf8(self.as_bytes()).is_ok());
             unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
         }
     }
@@ -998,6 +999,10 @@ impl<'w, E> StrWriterMut<'w, E> {
     ///
     /// Out of bounds range bounds are treated as being at `s.len()`.
     ///
+    /// Unlike [`write_str_range`](Self::write_str_range),
+    /// this doesn't check that the range is on `char` boundaries,
+    /// since every byte of an [`AsciiStr`] is a `char`.
+    ///
     /// This is a workaround for being unable to do `&foo[start..end]` at compile time.
     ///
     /// # Example
@@ -1434,3 +1439,41 @@ write_integer_fn! {
     (write_i128_display, write_i128_debug, signed, i128, u128)
     (write_isize_display, write_isize_debug, signed, isize, usize)
 }
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Allows using the standard [`write`] macro with a `StrWriterMut`,
+/// for when formatting happens at runtime.
+///
+/// A string that doesn't fit in the remaining capacity returns a [`core::fmt::Error`],
+/// without writing any of it.
+/// Since `write!` writes its output in pieces,
+/// the pieces before the one that didn't fit are still written.
+///
+/// # Example
+///
+/// ```rust
+///
+/// use const_format::StrWriterMut;
+///
+/// use core::fmt::Write;
+///
+/// let mut len = 0;
+/// let mut buffer = [0; 16];
+/// let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+///
+/// write!(writer, "{}-{:?}", 10, "foo").unwrap();
+/// assert_eq!(writer.as_str(), r#"10-"foo""#);
+///
+/// // There's no space for the 10 bytes of the string
+/// assert!(write!(writer, "{}", "abcdefghij").is_err());
+/// assert_eq!(writer.as_str(), r#"10-"foo""#);
+///
+/// ```
+///
+/// [`write`]: https://doc.rust-lang.org/core/macro.write.html
+impl<E> core::fmt::Write for StrWriterMut<'_, E> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        StrWriterMut::write_str(self, s).map_err(|_| core::fmt::Error)
+    }
+}
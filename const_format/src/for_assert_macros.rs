@@ -9,6 +9,11 @@ pub const fn assert_(cond: bool, message: &'static str) {
     }
 }
 
+#[track_caller]
+pub const fn panic_(message: &'static str) -> ! {
+    panic!(message)
+}
+
 // The `T` type parameter is there just so that the PARGUMENTS associated constant
 // is evaluated lazily.
 pub trait ConcatArgsIf<T, const COND: bool> {
@@ -30,6 +30,12 @@ pub enum NumberFormatting {
 }
 
 impl NumberFormatting {
+    /// Whether `self` is the same variant as `other`,
+    /// this is a const equivalent of `==`.
+    pub const fn const_eq(&self, other: &Self) -> bool {
+        *self as u8 == *other as u8
+    }
+
     #[cfg(test)]
     #[cfg(feature = "fmt")]
     pub(crate) const ALL: &'static [Self; 3] = &[
@@ -41,6 +47,108 @@ impl NumberFormatting {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// Where a string is placed when it's padded to a width.
+///
+/// This is used by [`Formatter::write_str_aligned`].
+///
+/// [`Formatter::write_str_aligned`]: ./struct.Formatter.html#method.write_str_aligned
+#[cfg(feature = "fmt")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Alignment {
+    /// Places the string at the start, with the padding after it (eg: `"foo   "`).
+    Left,
+    /// Places the string in the middle, with the padding on both sides (eg: `" foo  "`).
+    ///
+    /// If the padding can't be evenly split,
+    /// the extra fill character goes after the string.
+    Center,
+    /// Places the string at the end, with the padding before it (eg: `"   foo"`).
+    Right,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// How integers are written in scientific notation (eg: `1.2345e4`).
+///
+/// This is used by [`FormattingFlags::set_scientific`],
+/// and is set by the `{:e}`, `{:E}`, and `{:.2e}` formatting arguments of [`formatcp`].
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{formatcp, FormattingFlags, ScientificNotation};
+///
+/// const SCI: FormattingFlags = FormattingFlags::NEW
+///     .set_scientific(Some(ScientificNotation::UPPER.set_precision(Some(1))));
+///
+/// assert_eq!(formatcp!("{:@SCI}", 1250u32), "1.2E3");
+/// assert_eq!(formatcp!("{:@SCI}", -7i8), "-7.0E0");
+///
+/// ```
+///
+/// [`FormattingFlags::set_scientific`]: ./struct.FormattingFlags.html#method.set_scientific
+///
+/// [`formatcp`]: ./macro.formatcp.html
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ScientificNotation {
+    is_uppercase: bool,
+    precision: Option<usize>,
+}
+
+impl ScientificNotation {
+    /// Writes an `e` before the exponent (eg: `1.2345e4`),
+    /// and every significant digit of the mantissa.
+    pub const LOWER: Self = Self {
+        is_uppercase: false,
+        precision: None,
+    };
+
+    /// Writes an `E` before the exponent (eg: `1.2345E4`),
+    /// and every significant digit of the mantissa.
+    pub const UPPER: Self = Self {
+        is_uppercase: true,
+        precision: None,
+    };
+
+    /// Sets how many digits are written after the decimal point of the mantissa,
+    /// with `None` meaning that all of its significant digits are written.
+    ///
+    /// The mantissa is padded with zeros, or rounded to the nearest digit
+    /// (ties round to even, like the standard library does).
+    #[inline]
+    pub const fn set_precision(mut self, precision: Option<usize>) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Whether the exponent is written after an `E`, instead of an `e`.
+    #[inline]
+    pub const fn is_uppercase(self) -> bool {
+        self.is_uppercase
+    }
+
+    /// Gets how many digits are written after the decimal point of the mantissa,
+    /// `None` means that all of its significant digits are written.
+    #[inline]
+    pub const fn precision(self) -> Option<usize> {
+        self.precision
+    }
+
+    /// Whether `self` is the same as `other`,
+    /// this is a const equivalent of `==`.
+    pub const fn const_eq(&self, other: &Self) -> bool {
+        self.is_uppercase == other.is_uppercase
+            && match (self.precision, other.precision) {
+                (Some(l), Some(r)) => l == r,
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 /// This type bundles configuration for how to format data into strings, including.
 ///
 /// # Number formatting
@@ -79,15 +187,74 @@ impl NumberFormatting {
 /// - The binary formater (eg: `formatc!("{:#b}", FOO)`):
 /// prefixes numbers with `0b`.`
 ///
+/// # Max depth
+///
+/// How deeply nested structs, tuples, lists, and sets are debug formatted,
+/// it can be accessed with the `max_depth` method, and set with the `set_max_depth` method.
+///
+/// When set to `Some(depth)`, the data structures nested more than `depth` levels deep
+/// are formatted as `...`, which bounds the compile-time cost of formatting large constants.
+/// The default is `None`, which formats data structures at any depth.
+///
+/// # Digit grouping
+///
+/// A separator written between each group of three digits of decimal integers,
+/// it can be accessed with the `digit_grouping` method,
+/// and set with the `set_digit_grouping` method.
+///
+/// When set to `Some(separator)`, `1234567` is formatted as `1,234,567` (with a `','` separator).
+/// The default is `None`, which formats integers without separators.
+///
+/// This is set by the `{:,}` and `{:group(_)}` formatting arguments of [`formatcp`],
+/// and is only honored by [`formatcp`] and the other macros that evaluate to
+/// a `&'static str`, the [`Formatter`] type ignores it.
+///
+/// # Scientific notation
+///
+/// How decimal integers are written in scientific notation,
+/// it can be accessed with the `scientific` method,
+/// and set with the `set_scientific` method.
+///
+/// When set to `Some(notation)`, `1234567` is formatted as `1.234567e6`
+/// (with a [`ScientificNotation::LOWER`] notation).
+/// The default is `None`, which doesn't use scientific notation.
+/// Digit grouping is ignored when scientific notation is used.
+///
+/// This is set by the `{:e}`, `{:E}`, and `{:.2e}` formatting arguments of [`formatcp`],
+/// and like digit grouping,
+/// it's only honored by [`formatcp`] and the other macros that evaluate to
+/// a `&'static str`.
+///
+/// # Merging
+///
+/// Flags can be layered with the [`merge`](#method.merge) method,
+/// which takes each field from an override where it was set,
+/// and from a base otherwise.
+///
 /// [`Formatter`]: ./struct.Formatter.html
 ///
+/// [`formatcp`]: ./macro.formatcp.html
+///
+/// [`ScientificNotation::LOWER`]: ./struct.ScientificNotation.html#associatedconstant.LOWER
+///
 #[must_use]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
 pub struct FormattingFlags {
     num_fmt: NumberFormatting,
     is_alternate: bool,
+    max_depth: Option<usize>,
+    digit_grouping: Option<char>,
+    scientific: Option<ScientificNotation>,
+    // Which fields were explicitly set, a combination of the `*_SET` constants.
+    set_fields: u8,
 }
 
+const NUM_FMT_SET: u8 = 1;
+const ALTERNATE_SET: u8 = 2;
+const MAX_DEPTH_SET: u8 = 4;
+const DIGIT_GROUPING_SET: u8 = 8;
+const SCIENTIFIC_SET: u8 = 16;
+
 #[doc(hidden)]
 impl FormattingFlags {
     pub const __REG: Self = Self::NEW.set_alternate(false).set_decimal();
@@ -103,6 +270,10 @@ impl FormattingFlags {
     pub const DEFAULT: Self = Self {
         num_fmt: NumberFormatting::Decimal,
         is_alternate: false,
+        max_depth: None,
+        digit_grouping: None,
+        scientific: None,
+        set_fields: 0,
     };
 
     /// Constructs a `FormattingFlags` with these values:
@@ -111,9 +282,19 @@ impl FormattingFlags {
     ///
     /// - is alternate: false
     ///
+    /// - max depth: None
+    ///
+    /// - digit grouping: None
+    ///
+    /// - scientific notation: None
+    ///
     pub const NEW: Self = Self {
         num_fmt: NumberFormatting::Decimal,
         is_alternate: false,
+        max_depth: None,
+        digit_grouping: None,
+        scientific: None,
+        set_fields: 0,
     };
 
     /// Constructs a `FormattingFlags` with these values:
@@ -122,6 +303,12 @@ impl FormattingFlags {
     ///
     /// - is alternate: false
     ///
+    /// - max depth: None
+    ///
+    /// - digit grouping: None
+    ///
+    /// - scientific notation: None
+    ///
     #[inline]
     pub const fn new() -> Self {
         Self::NEW
@@ -133,6 +320,7 @@ impl FormattingFlags {
     #[inline]
     pub const fn set_num_fmt(mut self, num_fmt: NumberFormatting) -> Self {
         self.num_fmt = num_fmt;
+        self.set_fields |= NUM_FMT_SET;
         self
     }
 
@@ -142,6 +330,7 @@ impl FormattingFlags {
     #[inline]
     pub const fn set_decimal(mut self) -> Self {
         self.num_fmt = NumberFormatting::Decimal;
+        self.set_fields |= NUM_FMT_SET;
         self
     }
 
@@ -151,6 +340,7 @@ impl FormattingFlags {
     #[inline]
     pub const fn set_hexadecimal(mut self) -> Self {
         self.num_fmt = NumberFormatting::Hexadecimal;
+        self.set_fields |= NUM_FMT_SET;
         self
     }
 
@@ -160,6 +350,7 @@ impl FormattingFlags {
     #[inline]
     pub const fn set_binary(mut self) -> Self {
         self.num_fmt = NumberFormatting::Binary;
+        self.set_fields |= NUM_FMT_SET;
         self
     }
 
@@ -167,6 +358,88 @@ impl FormattingFlags {
     #[inline]
     pub const fn set_alternate(mut self, is_alternate: bool) -> Self {
         self.is_alternate = is_alternate;
+        self.set_fields |= ALTERNATE_SET;
+        self
+    }
+
+    /// Sets how many levels of nested data structures are debug formatted,
+    /// with `None` meaning that there's no limit.
+    ///
+    /// The max depth is taken from the flags that a [`Formatter`] is constructed with,
+    /// and it's inherited by the formatters constructed from it with `make_formatter`,
+    /// even when they're passed flags without a max depth.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "fmt", doc = "```rust")]
+    #[cfg_attr(not(feature = "fmt"), doc = "```ignore")]
+    /// #![feature(const_mut_refs)]
+    ///
+    /// use const_format::{coerce_to_fmt, FormattingFlags, StrWriter};
+    ///
+    /// const FLAGS: FormattingFlags = FormattingFlags::NEW.set_max_depth(Some(0));
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+    ///
+    /// let _ = coerce_to_fmt!(Some(3u8)).const_debug_fmt(&mut writer.make_formatter(FLAGS));
+    /// assert_eq!(writer.as_str(), "...");
+    ///
+    /// writer.clear();
+    ///
+    /// let flags = FLAGS.set_max_depth(Some(1));
+    /// let _ = coerce_to_fmt!(Some(3u8)).const_debug_fmt(&mut writer.make_formatter(flags));
+    /// assert_eq!(writer.as_str(), "Some(3)");
+    ///
+    /// ```
+    ///
+    /// [`Formatter`]: ./struct.Formatter.html
+    #[inline]
+    pub const fn set_max_depth(mut self, max_depth: Option<usize>) -> Self {
+        self.max_depth = max_depth;
+        self.set_fields |= MAX_DEPTH_SET;
+        self
+    }
+
+    /// Sets the separator written between each group of three digits of decimal integers,
+    /// with `None` meaning that integers are written without separators.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{formatcp, FormattingFlags};
+    ///
+    /// const UNDERSCORES: FormattingFlags = FormattingFlags::NEW.set_digit_grouping(Some('_'));
+    ///
+    /// assert_eq!(formatcp!("{:@UNDERSCORES}", 1234567u32), "1_234_567");
+    /// assert_eq!(formatcp!("{:@UNDERSCORES?}", -1000i16), "-1_000");
+    ///
+    /// ```
+    #[inline]
+    pub const fn set_digit_grouping(mut self, digit_grouping: Option<char>) -> Self {
+        self.digit_grouping = digit_grouping;
+        self.set_fields |= DIGIT_GROUPING_SET;
+        self
+    }
+
+    /// Sets how decimal integers are written in scientific notation,
+    /// with `None` meaning that they're written without it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{formatcp, FormattingFlags, ScientificNotation};
+    ///
+    /// const SCI: FormattingFlags = FormattingFlags::NEW
+    ///     .set_scientific(Some(ScientificNotation::LOWER.set_precision(Some(2))));
+    ///
+    /// assert_eq!(formatcp!("{:@SCI}", 12345u32), "1.23e4");
+    /// assert_eq!(formatcp!("{:@SCI?}", -5i8), "-5.00e0");
+    ///
+    /// ```
+    #[inline]
+    pub const fn set_scientific(mut self, scientific: Option<ScientificNotation>) -> Self {
+        self.scientific = scientific;
+        self.set_fields |= SCIENTIFIC_SET;
         self
     }
 
@@ -181,6 +454,198 @@ impl FormattingFlags {
     pub const fn is_alternate(self) -> bool {
         self.is_alternate
     }
+
+    /// Gets how many levels of nested data structures are debug formatted,
+    /// `None` means that there's no limit.
+    #[inline]
+    pub const fn max_depth(self) -> Option<usize> {
+        self.max_depth
+    }
+
+    /// Gets the separator written between each group of three digits of decimal integers,
+    /// `None` means that integers are written without separators.
+    #[inline]
+    pub const fn digit_grouping(self) -> Option<char> {
+        self.digit_grouping
+    }
+
+    /// Gets how decimal integers are written in scientific notation,
+    /// `None` means that they're written without it.
+    #[inline]
+    pub const fn scientific(self) -> Option<ScientificNotation> {
+        self.scientific
+    }
+
+    /// Combines `base` and `over`,
+    /// taking each field from `over` if it was set in it, and from `base` otherwise.
+    ///
+    /// A field is set by calling its setter method
+    /// (eg: `set_alternate` sets whether the alternate flag is enabled),
+    /// the fields of [`FormattingFlags::NEW`] are all unset.
+    /// The fields that were set in either argument are also set in the returned flags.
+    ///
+    /// Whether a field was set doesn't affect equality.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::FormattingFlags;
+    ///
+    /// const BASE: FormattingFlags = FormattingFlags::NEW
+    ///     .set_max_depth(Some(2))
+    ///     .set_alternate(true);
+    ///
+    /// const OVER: FormattingFlags = FormattingFlags::NEW.set_hexadecimal();
+    ///
+    /// const MERGED: FormattingFlags = FormattingFlags::merge(BASE, OVER);
+    ///
+    /// const EXPECTED: FormattingFlags = FormattingFlags::NEW
+    ///     .set_hexadecimal()
+    ///     .set_max_depth(Some(2))
+    ///     .set_alternate(true);
+    ///
+    /// assert!(MERGED.const_eq(&EXPECTED));
+    ///
+    /// ```
+    ///
+    /// [`FormattingFlags::NEW`]: #associatedconstant.NEW
+    pub const fn merge(base: Self, over: Self) -> Self {
+        const fn is_set(flags: &FormattingFlags, field: u8) -> bool {
+            flags.set_fields & field != 0
+        }
+
+        Self {
+            num_fmt: if is_set(&over, NUM_FMT_SET) {
+                over.num_fmt
+            } else {
+                base.num_fmt
+            },
+            is_alternate: if is_set(&over, ALTERNATE_SET) {
+                over.is_alternate
+            } else {
+                base.is_alternate
+            },
+            max_depth: if is_set(&over, MAX_DEPTH_SET) {
+                over.max_depth
+            } else {
+                base.max_depth
+            },
+            digit_grouping: if is_set(&over, DIGIT_GROUPING_SET) {
+                over.digit_grouping
+            } else {
+                base.digit_grouping
+            },
+            scientific: if is_set(&over, SCIENTIFIC_SET) {
+                over.scientific
+            } else {
+                base.scientific
+            },
+            set_fields: base.set_fields | over.set_fields,
+        }
+    }
+
+    /// Whether `self` and `other` have the same flags,
+    /// this is a const equivalent of `==`.
+    ///
+    /// This allows comparing `FormattingFlags` in the [`assertc_eq`] and
+    /// [`assertc_ne`] macros.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::FormattingFlags;
+    ///
+    /// const FLAGS: FormattingFlags = FormattingFlags::NEW.set_hexadecimal();
+    ///
+    /// const EQ: bool = FLAGS.const_eq(&FormattingFlags::NEW.set_hexadecimal());
+    /// const NE: bool = FLAGS.const_eq(&FLAGS.set_alternate(true));
+    ///
+    /// assert!(EQ);
+    /// assert!(!NE);
+    /// ```
+    ///
+    /// [`assertc_eq`]: ./macro.assertc_eq.html
+    /// [`assertc_ne`]: ./macro.assertc_ne.html
+    pub const fn const_eq(&self, other: &Self) -> bool {
+        self.num_fmt.const_eq(&other.num_fmt)
+            && self.is_alternate == other.is_alternate
+            && match (self.max_depth, other.max_depth) {
+                (Some(l), Some(r)) => l == r,
+                (None, None) => true,
+                _ => false,
+            }
+            && match (self.digit_grouping, other.digit_grouping) {
+                (Some(l), Some(r)) => l == r,
+                (None, None) => true,
+                _ => false,
+            }
+            && match (&self.scientific, &other.scientific) {
+                (Some(l), Some(r)) => l.const_eq(r),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+impl PartialEq for FormattingFlags {
+    fn eq(&self, other: &Self) -> bool {
+        self.const_eq(other)
+    }
+}
+
+impl core::fmt::Debug for FormattingFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FormattingFlags")
+            .field("num_fmt", &self.num_fmt)
+            .field("is_alternate", &self.is_alternate)
+            .field("max_depth", &self.max_depth)
+            .field("digit_grouping", &self.digit_grouping)
+            .field("scientific", &self.scientific)
+            .finish()
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl_fmt! {
+    impl NumberFormatting;
+
+    /// Const debug formats this `NumberFormatting`, the same as its `Debug` impl.
+    pub const fn const_debug_fmt(&self, f: &mut crate::fmt::Formatter<'_>) -> crate::Result {
+        f.write_str(match self {
+            NumberFormatting::Decimal => "Decimal",
+            NumberFormatting::Hexadecimal => "Hexadecimal",
+            NumberFormatting::Binary => "Binary",
+        })
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl_fmt! {
+    impl ScientificNotation;
+
+    /// Const debug formats this `ScientificNotation`, the same as its `Debug` impl.
+    pub const fn const_debug_fmt(&self, f: &mut crate::fmt::Formatter<'_>) -> crate::Result {
+        let mut f = f.debug_struct("ScientificNotation");
+        try_!(crate::PWrapper(self.is_uppercase).const_debug_fmt(f.field("is_uppercase")));
+        try_!(crate::PWrapper(self.precision).const_debug_fmt(f.field("precision")));
+        f.finish()
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl_fmt! {
+    impl FormattingFlags;
+
+    /// Const debug formats this `FormattingFlags`, the same as its `Debug` impl.
+    pub const fn const_debug_fmt(&self, f: &mut crate::fmt::Formatter<'_>) -> crate::Result {
+        let mut f = f.debug_struct("FormattingFlags");
+        try_!(self.num_fmt.const_debug_fmt(f.field("num_fmt")));
+        try_!(crate::PWrapper(self.is_alternate).const_debug_fmt(f.field("is_alternate")));
+        try_!(crate::PWrapper(self.max_depth).const_debug_fmt(f.field("max_depth")));
+        try_!(crate::PWrapper(self.digit_grouping).const_debug_fmt(f.field("digit_grouping")));
+        crate::call_debug_fmt!(Option, self.scientific, f.field("scientific"));
+        f.finish()
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
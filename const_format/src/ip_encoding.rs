@@ -0,0 +1,165 @@
+#[cfg(feature = "net")]
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// The longest IPv6 address, when displayed, is 8 groups of 4 hex digits,
+/// separated by 7 colons.
+const MAX_IP_LEN: usize = 39;
+
+#[derive(Copy, Clone)]
+pub struct FmtIpAddr {
+    encoded: [u8; MAX_IP_LEN],
+    len: u8,
+}
+
+impl FmtIpAddr {
+    /// Array which contains the display-formatted IP address,
+    /// only `&self.encoded[][..self.len()]` should be copied.
+    pub const fn encoded(&self) -> &[u8; MAX_IP_LEN] {
+        &self.encoded
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    #[cfg(all(feature = "net", feature = "fmt"))]
+    pub(crate) const fn as_str(&self) -> &str {
+        let (bytes, _) = self.encoded.split_at(self.len());
+        // Safety: only ASCII bytes are written into `self.encoded`
+        unsafe { core::str::from_utf8_unchecked(bytes) }
+    }
+
+    #[cfg(feature = "net")]
+    const fn push(mut self, byte: u8) -> Self {
+        self.encoded[self.len as usize] = byte;
+        self.len += 1;
+        self
+    }
+
+    #[cfg(feature = "net")]
+    const fn push_decimal(mut self, n: u8) -> Self {
+        if n >= 100 {
+            self = self.push(b'0' + n / 100);
+        }
+        if n >= 10 {
+            self = self.push(b'0' + (n / 10) % 10);
+        }
+        self.push(b'0' + n % 10)
+    }
+
+    /// Writes `n` as lowercase hexadecimal, without leading zeroes.
+    #[cfg(feature = "net")]
+    const fn push_hex(mut self, n: u16) -> Self {
+        let mut shift = 12;
+        while shift > 0 && (n >> shift) == 0 {
+            shift -= 4;
+        }
+        loop {
+            let digit = ((n >> shift) & 0xF) as u8;
+            self = self.push(if digit < 10 {
+                b'0' + digit
+            } else {
+                b'a' + digit - 10
+            });
+            if shift == 0 {
+                break self;
+            }
+            shift -= 4;
+        }
+    }
+
+    #[cfg(feature = "net")]
+    const fn push_ipv4(mut self, [a, b, c, d]: [u8; 4]) -> Self {
+        self = self.push_decimal(a).push(b'.');
+        self = self.push_decimal(b).push(b'.');
+        self = self.push_decimal(c).push(b'.');
+        self.push_decimal(d)
+    }
+
+    /// Writes `segments[start..end]` separated by colons.
+    #[cfg(feature = "net")]
+    const fn push_segments(mut self, segments: &[u16; 8], start: usize, end: usize) -> Self {
+        let mut i = start;
+        while i < end {
+            if i != start {
+                self = self.push(b':');
+            }
+            self = self.push_hex(segments[i]);
+            i += 1;
+        }
+        self
+    }
+}
+
+#[cfg(feature = "net")]
+const EMPTY: FmtIpAddr = FmtIpAddr {
+    encoded: [0; MAX_IP_LEN],
+    len: 0,
+};
+
+/// Formats the address like its `Display` impl in the standard library does,
+/// eg: `127.0.0.1`.
+#[cfg(feature = "net")]
+pub(crate) const fn ipv4_to_display(addr: Ipv4Addr) -> FmtIpAddr {
+    EMPTY.push_ipv4(addr.octets())
+}
+
+/// Formats the address like its `Display` impl in the standard library does,
+/// compressing the longest run of zero segments into `::`,
+/// eg: `2001:db8::1`, `::ffff:192.0.2.1`.
+#[cfg(feature = "net")]
+pub(crate) const fn ipv6_to_display(addr: Ipv6Addr) -> FmtIpAddr {
+    let segments = addr.segments();
+
+    if let [0, 0, 0, 0, 0, 0xFFFF, ab, cd] = segments {
+        let [a, b] = ab.to_be_bytes();
+        let [c, d] = cd.to_be_bytes();
+        return EMPTY
+            .push(b':')
+            .push(b':')
+            .push_hex(0xFFFF)
+            .push(b':')
+            .push_ipv4([a, b, c, d]);
+    }
+
+    // The leftmost longest run of zero segments
+    let mut longest_start = 0;
+    let mut longest_len = 0;
+    let mut current_start = 0;
+    let mut current_len = 0;
+
+    let mut i = 0;
+    while i < segments.len() {
+        if segments[i] == 0 {
+            if current_len == 0 {
+                current_start = i;
+            }
+            current_len += 1;
+            if current_len > longest_len {
+                longest_start = current_start;
+                longest_len = current_len;
+            }
+        } else {
+            current_len = 0;
+        }
+        i += 1;
+    }
+
+    if longest_len > 1 {
+        EMPTY
+            .push_segments(&segments, 0, longest_start)
+            .push(b':')
+            .push(b':')
+            .push_segments(&segments, longest_start + longest_len, segments.len())
+    } else {
+        EMPTY.push_segments(&segments, 0, segments.len())
+    }
+}
+
+#[cfg(feature = "net")]
+pub(crate) const fn ip_to_display(addr: IpAddr) -> FmtIpAddr {
+    match addr {
+        IpAddr::V4(addr) => ipv4_to_display(addr),
+        IpAddr::V6(addr) => ipv6_to_display(addr),
+    }
+}
@@ -20,23 +20,147 @@
 //! [`format`]-like formatting which takes `integers`, `bool`, `char`, and `&str` constants,
 //! and emits a `&'static str` constant.
 //!
+//! - [`format_arg_count`]:
+//! Counts the distinct arguments that a [`formatcp`]-style format string uses.
+//!
+//! - [`interp_env`]:
+//! Substitutes `${NAME}`s in a string literal with the compile-time value of environment variables.
+//!
+//! - [`is_valid_ident`]:
+//! Checks whether a string literal is a valid Rust identifier.
+//!
+//! - [`col_width`]:
+//! Computes the length of the longest of the passed constants when they're formatted.
+//!
+//! - [`wrap_in`]:
+//! Wraps a constant between a prefix and a suffix, eg: `"[config]"`.
+//!
+//! - [`pluralize`]:
+//! Concatenates an integer constant with the singular or plural form of a noun.
+//!
+//! - [`ordinal`]:
+//! Concatenates an integer constant with its English ordinal suffix, eg: `21st`.
+//!
+//! - [`abbreviate_number`]:
+//! Formats a `u64` constant with a `K`/`M`/`G`/`T` suffix, eg: `1.5M`.
+//!
+//! - [`to_fixed_point`]:
+//! Formats an integer constant as a decimal number with a fixed amount of decimal places.
+//!
+//! - [`char_to_str`]:
+//! Converts a `char` constant into a `&'static str`.
+//!
+//! - [`common_prefix`]/[`common_suffix`]:
+//! Gets the longest common prefix/suffix of two `&'static str` constants.
+//!
+//! - [`str_prefix_len`]:
+//! Gets the length of the longest common prefix of two `&'static str` constants.
+//!
+//! - [`first_line`]/[`last_line`]:
+//! Gets the first/last line of a `&'static str` constant.
+//!
+//! - [`word_count`]:
+//! Counts the whitespace-separated words in a `&'static str` constant.
+//!
+//! - [`parse_bool`]:
+//! Parses a `&'static str` constant into a `bool` constant, erroring on invalid strings.
+//!
+//! - [`count_leading`]/[`count_trailing`]:
+//! Counts how many times a `char` is repeated at the start/end of a `&'static str` constant.
+//!
+//! - [`truncate_words`]:
+//! Keeps the first words of a `&'static str` constant,
+//! appending a suffix if any were removed.
+//!
+//! - [`str_min`]/[`str_max`]:
+//! Gets the lexicographically smallest/largest of an array of `&'static str` constants.
+//!
+//! - [`crc32`]/[`fnv1a`]:
+//! Computes the CRC-32 checksum/FNV-1a hash of a `&'static str` or `&'static [u8]` constant.
+//!
+//! - [`cstr`]:
+//! Converts a `&'static str` constant into a NUL-terminated `&'static [u8]`, for FFI.
+//!
+//! - [`colorize`]:
+//! Wraps a `&'static str` constant in the ANSI escape sequences to print it with a [`Color`].
+//!
 //! - [`str_get`]:
 //! Indexes a `&'static str` constant, returning `None` when the index is out of bounds.
 //!
 //! - [`str_index`]:
 //! Indexes a `&'static str` constant.
 //!
+//! - [`strip_bom`]:
+//! Removes the byte order mark at the start of a `&'static str` constant, if there's one.
+//!
+//! - [`clamp_len`]:
+//! Truncates a `&'static str` constant to at most a number of chars.
+//!
+//! - [`char_to_byte_index`]/[`byte_to_char_index`]:
+//! Converts between char indices and byte indices of a `&'static str` constant.
+//!
+//! - [`str_match`]:
+//! Compares a `&str` against string literal patterns, evaluating to the value of the matching arm.
+//!
 //! - [`str_repeat`]:
 //! Creates a `&'static str` by repeating a `&'static str` constant `times` times.
 //!
+//! - [`repeat_to_width`]:
+//! Repeats a `&'static str` constant until it's a number of chars long.
+//!
 //! - [`str_splice`]:
 //! Replaces a substring in a `&'static str` constant.
 //!
+//! - [`replace_range`]:
+//! Replaces a range of bytes in a `&'static [u8]` constant.
+//!
 //!
 //! ### Rust 1.51.0
 //!
 //! By enabling the "const_generics" feature, you can use these macros:
 //!
+//! - [`escape_debug`]:
+//! Escapes a `&'static str` constant like [`str::escape_debug`], without surrounding quotes.
+//!
+//! - [`escape_html`]:
+//! Escapes the characters of a `&'static str` constant that are special in HTML.
+//!
+//! - [`hex_decode`]:
+//! Decodes a `&'static str` constant of hex digits into a `&'static [u8]`.
+//!
+//! - [`hex_dump`]:
+//! Formats a `&'static [u8]` constant as a table of offsets, hex bytes, and ascii text.
+//!
+//! - [`json_escape`]:
+//! Escapes a `&'static str` constant so that it can be embedded in a JSON string.
+//!
+//! - [`pad_start`]/[`pad_end`]:
+//! Pads the start/end of a `&'static str` constant with a `char`, up to a minimum width.
+//!
+//! - [`center`]:
+//! Centers a `&'static str` constant by padding both sides with a `char`, up to a minimum width.
+//!
+//! - [`mask_middle`]:
+//! Replaces the middle of a `&'static str` constant with a mask `char`, for redacting it.
+//!
+//! - [`format_fixed_width_int`]:
+//! Formats an integer constant right-aligned to a minimum width, padded with a `char`.
+//!
+//! - [`to_binary_padded`]:
+//! Formats an unsigned integer constant as exactly `bits` binary digits, eg: `"00000101"`.
+//!
+//! - [`normalize_newlines`]/[`to_crlf`]:
+//! Converts the line endings of a `&'static str` constant to `\n`/`\r\n`.
+//!
+//! - [`as_raw_literal`]:
+//! Wraps a `&'static str` constant in a raw string literal with the fewest `#`s, as source code.
+//!
+//! - [`align_table`]:
+//! Formats a 2D array of `&'static str` constants as a table of aligned columns.
+//!
+//! - [`grid`]:
+//! Lays out a list of values into rows of aligned columns.
+//!
 //! - [`map_ascii_case`]:
 //! Converts a `&'static str` constant to a different casing style,
 //! determined by a [`Case`] argument.
@@ -45,12 +169,58 @@
 //! Replaces all the instances of a pattern in a `&'static str` constant with
 //! another `&'static str` constant.
 //!
+//! - [`str_reverse`]:
+//! Reverses a `&'static str` constant by `char`s.
+//!
+//! - [`slugify`]:
+//! Converts a `&'static str` constant into a lowercase slug, eg: `"Hello, World!"` to `"hello-world"`.
+//!
+//! - [`deaccent`]:
+//! Replaces the accented latin letters of a `&'static str` constant with ASCII letters.
+//!
+//! - [`str_splitn`]:
+//! Splits a `&'static str` constant by a pattern into at most `limit` parts.
+//!
+//! - [`strip_ansi`]:
+//! Removes the ANSI escape sequences from a `&'static str` constant.
+//!
+//! - [`strip_comments`]/[`strip_c_comments`]:
+//! Removes the line comments/C-style comments from a `&'static str` constant.
+//!
+//! - [`capitalize`]:
+//! Makes the first character of a `&'static str` constant uppercase, eg: `"hello"` to `"Hello"`.
+//!
+//! - [`swap_case`]:
+//! Swaps the case of the ASCII letters in a `&'static str` constant, eg: `"Hello"` to `"hELLO"`.
+//!
+//! - [`dedup_whitespace`]:
+//! Collapses the runs of whitespace in a `&'static str` constant into single spaces,
+//! trimming both ends.
+//!
+//! - [`title_from_snake`]:
+//! Converts a snake case `&'static str` constant into a title, eg: `"user_id"` to `"User Id"`.
+//!
+//! - [`snake_to_camel`]:
+//! Converts a snake case `&'static str` constant into camel case, eg: `"user_id"` to `"userId"`.
+//!
+//! - [`camel_to_snake`]:
+//! Converts a camel case `&'static str` constant into snake case, eg: `"userId"` to `"user_id"`.
+//!
+//! - [`wrap_text`]:
+//! Wraps a `&'static str` constant into lines of at most `width` `char`s, breaking at spaces.
+//!
+//! - [`zip_join`]:
+//! Joins the pairs of strings from two `&'static str` arrays, eg: `"a=1\nb=2"`.
+//!
+//! - [`concat_range`]:
+//! Concatenates a `&'static str` template for every index in a range, eg: `"0,1,2,3,4"`.
+//!
 //! ### Rust 1.57.0
 //!
 //! The "assertcp" feature enables the [`assertcp`], [`assertcp_eq`],
-//! and [`assertcp_ne`] macros.
-//! These macros are like the standard library assert macros,
-//! but evaluated at compile-time,
+//! [`assertcp_ne`], and [`panic_fmt`] macros.
+//! These macros are like the standard library assert macros
+//! (and `panic` macro), but with the message formatted at compile-time,
 //! with the limitation that they can only have primitive types as arguments
 //! (just like [`concatcp`] and [`formatcp`]).
 //!
@@ -74,6 +244,17 @@
 //! [`write`]-like macro that can format many standard library and user defined types
 //! into a type that implements [`WriteMarker`].
 //!
+//! - [`writecln`]:
+//! [`writec`]-like macro that writes a newline after the formatted text.
+//!
+//! - [`cond_fmt`]:
+//! [`writec`]-like macro that only writes when a condition is true,
+//! returning early on errors.
+//!
+//! - [`formatc_writer`]:
+//! [`formatc`]-like macro that evaluates to a [`StrWriter`] constant,
+//! which can be written to afterwards.
+//!
 //! The "derive" feature enables the [`ConstDebug`] macro,
 //! and the "fmt" feature.<br>
 //! [`ConstDebug`] derives the [`FormatMarker`] trait,
@@ -216,6 +397,9 @@
 //! - They cannot be used places that take string literals.
 //! So `#[doc = "foobar"]` cannot be replaced with `#[doc = concatcp!("foo", "bar") ]`.
 //!
+//! The formatting macros don't have a maximum amount of arguments,
+//! passing thousands of arguments only makes them slower to compile.
+//!
 //! <span id="integer-args"></span>
 //!
 //! ### Integer arguments
@@ -265,7 +449,8 @@
 //! but it was renamed to avoid confusion with the "assertcp" feature.
 //!
 //! - "assertcp": Requires Rust 1.57.0, implies the "const_generics" feature.
-//! Enables the [`assertcp`], [`assertcp_eq`], and [`assertcp_ne`] assertion macros.
+//! Enables the [`assertcp`], [`assertcp_eq`], and [`assertcp_ne`] assertion macros,
+//! and the [`panic_fmt`] macro.
 //!
 //! - "constant_time_as_str": implies the "fmt" feature.
 //! An optimization that requires a few additional nightly features,
@@ -277,11 +462,36 @@
 //! Also changes the the implementation of the [`concatcp`] and [`formatcp`]
 //! macros to use const generics.
 //!
+//! - "net": Requires Rust 1.77.0.
+//! Allows passing `core::net::{Ipv4Addr, Ipv6Addr, IpAddr}` constants to
+//! the [`concatcp`] and [`formatcp`] macros, and to the [`const_format::fmt`] API.
+//! These are formatted like their `Display` impls in the standard library,
+//! eg: `127.0.0.1`, `2001:db8::1`.
+//!
+//! - "core_error": Requires Rust 1.81.0, implies the "fmt" feature.
+//! Implements [`core::error::Error`] for [`const_format::Error`],
+//! so that it can be converted into a `Box<dyn Error>` with the `?` operator.
+//!
+//! - "no_color": Makes the [`colorize`] macro return its string argument unchanged,
+//! without ANSI escape sequences.
+//!
+//! - "heapless": implies the "fmt" feature.
+//! Allows passing [`heapless::String`]s to the [`writec`] macro,
+//! by converting them into a [`HeaplessStringWriter`].
+//!
+//! - "std": implies the "fmt" feature.
+//! Allows passing `String`s to the [`writec`] macro,
+//! by converting them into a [`StdStringWriter`],
+//! the `String` grows as needed, so writing into it never returns
+//! `Err(Error::NotEnoughSpace)`.
+//!
 //!
 //!
 //! # No-std support
 //!
-//! `const_format` is unconditionally `#![no_std]`, it can be used anywhere Rust can be used.
+//! `const_format` is `#![no_std]`, it can be used anywhere Rust can be used.
+//!
+//! The "std" feature links to the standard library, to support writing into `String`s.
 //!
 //! # Minimum Supported Rust Version
 //!
@@ -303,22 +513,58 @@
 //!
 //! [`assertcp_ne`]: ./macro.assertcp_ne.html
 //!
+//! [`panic_fmt`]: ./macro.panic_fmt.html
+//!
 //! [`concatcp`]: ./macro.concatcp.html
 //!
 //! [`formatcp`]: ./macro.formatcp.html
 //!
+//! [`format_arg_count`]: ./macro.format_arg_count.html
+//!
+//! [`interp_env`]: ./macro.interp_env.html
+//!
+//! [`is_valid_ident`]: ./macro.is_valid_ident.html
+//!
+//! [`col_width`]: ./macro.col_width.html
+//!
+//! [`wrap_in`]: ./macro.wrap_in.html
+//!
+//! [`pluralize`]: ./macro.pluralize.html
+//!
+//! [`ordinal`]: ./macro.ordinal.html
+//!
+//! [`abbreviate_number`]: ./macro.abbreviate_number.html
+//!
+//! [`to_fixed_point`]: ./macro.to_fixed_point.html
+//!
 //! [`format`]: https://doc.rust-lang.org/std/macro.format.html
 //!
 //! [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
 //!
 //! [`const_format::fmt`]: ./fmt/index.html
 //!
+//! [`const_format::Error`]: ./fmt/enum.Error.html
+//!
+//! [`core::error::Error`]: https://doc.rust-lang.org/core/error/trait.Error.html
+//!
 //! [`concatc`]: ./macro.concatc.html
 //!
 //! [`formatc`]: ./macro.formatc.html
 //!
 //! [`writec`]: ./macro.writec.html
 //!
+//! [`writecln`]: ./macro.writecln.html
+//!
+//! [`cond_fmt`]: ./macro.cond_fmt.html
+//!
+//! [`formatc_writer`]: ./macro.formatc_writer.html
+//!
+//! [`HeaplessStringWriter`]: ./fmt/struct.HeaplessStringWriter.html
+//!
+//! [`StdStringWriter`]: ./fmt/struct.StdStringWriter.html
+//!
+//! [`heapless::String`]: https://docs.rs/heapless/0.7/heapless/struct.String.html
+//!
 //! [`write`]: https://doc.rust-lang.org/std/macro.write.html
 //!
 //! [`Formatter`]: ./fmt/struct.Formatter.html
@@ -331,21 +577,137 @@
 //!
 //! [`WriteMarker`]: ./marker_traits/trait.WriteMarker.html
 //!
+//! [`escape_debug`]: ./macro.escape_debug.html
+//!
+//! [`str::escape_debug`]: https://doc.rust-lang.org/std/primitive.str.html#method.escape_debug
+//!
+//! [`escape_html`]: ./macro.escape_html.html
+//!
+//! [`hex_decode`]: ./macro.hex_decode.html
+//!
+//! [`hex_dump`]: ./macro.hex_dump.html
+//!
+//! [`json_escape`]: ./macro.json_escape.html
+//!
 //! [`map_ascii_case`]: ./macro.map_ascii_case.html
 //!
 //! [`Case`]: ./enum.Case.html
 //!
 //!
+//! [`char_to_str`]: ./macro.char_to_str.html
+//!
+//! [`colorize`]: ./macro.colorize.html
+//!
+//! [`cstr`]: ./macro.cstr.html
+//!
+//! [`common_prefix`]: ./macro.common_prefix.html
+//!
+//! [`common_suffix`]: ./macro.common_suffix.html
+//!
+//! [`str_prefix_len`]: ./macro.str_prefix_len.html
+//!
+//! [`first_line`]: ./macro.first_line.html
+//!
+//! [`last_line`]: ./macro.last_line.html
+//!
+//! [`word_count`]: ./macro.word_count.html
+//!
+//! [`parse_bool`]: ./macro.parse_bool.html
+//!
+//! [`count_leading`]: ./macro.count_leading.html
+//!
+//! [`count_trailing`]: ./macro.count_trailing.html
+//!
+//! [`truncate_words`]: ./macro.truncate_words.html
+//!
+//! [`str_min`]: ./macro.str_min.html
+//!
+//! [`str_max`]: ./macro.str_max.html
+//!
+//! [`crc32`]: ./macro.crc32.html
+//!
+//! [`fnv1a`]: ./macro.fnv1a.html
+//!
+//! [`Color`]: ./enum.Color.html
+//!
+//! [`pad_start`]: ./macro.pad_start.html
+//!
+//! [`pad_end`]: ./macro.pad_end.html
+//!
+//! [`center`]: ./macro.center.html
+//!
+//! [`mask_middle`]: ./macro.mask_middle.html
+//!
+//! [`format_fixed_width_int`]: ./macro.format_fixed_width_int.html
+//!
+//! [`align_table`]: ./macro.align_table.html
+//!
+//! [`grid`]: ./macro.grid.html
+//!
+//! [`to_binary_padded`]: ./macro.to_binary_padded.html
+//!
+//! [`as_raw_literal`]: ./macro.as_raw_literal.html
+//!
+//! [`normalize_newlines`]: ./macro.normalize_newlines.html
+//!
+//! [`to_crlf`]: ./macro.to_crlf.html
+//!
 //! [`str_get`]: ./macro.str_get.html
 //!
 //! [`str_index`]: ./macro.str_index.html
 //!
+//! [`strip_bom`]: ./macro.strip_bom.html
+//!
+//! [`clamp_len`]: ./macro.clamp_len.html
+//!
+//! [`char_to_byte_index`]: ./macro.char_to_byte_index.html
+//!
+//! [`byte_to_char_index`]: ./macro.byte_to_char_index.html
+//!
+//! [`str_match`]: ./macro.str_match.html
+//!
 //! [`str_repeat`]: ./macro.str_repeat.html
 //!
+//! [`repeat_to_width`]: ./macro.repeat_to_width.html
+//!
 //! [`str_splice`]: ./macro.str_splice.html
 //!
+//! [`replace_range`]: ./macro.replace_range.html
+//!
 //! [`str_replace`]: ./macro.str_replace.html
 //!
+//! [`str_reverse`]: ./macro.str_reverse.html
+//!
+//! [`slugify`]: ./macro.slugify.html
+//!
+//! [`deaccent`]: ./macro.deaccent.html
+//!
+//! [`zip_join`]: ./macro.zip_join.html
+//!
+//! [`concat_range`]: ./macro.concat_range.html
+//!
+//! [`str_splitn`]: ./macro.str_splitn.html
+//!
+//! [`strip_ansi`]: ./macro.strip_ansi.html
+//!
+//! [`strip_comments`]: ./macro.strip_comments.html
+//!
+//! [`strip_c_comments`]: ./macro.strip_c_comments.html
+//!
+//! [`capitalize`]: ./macro.capitalize.html
+//!
+//! [`swap_case`]: ./macro.swap_case.html
+//!
+//! [`dedup_whitespace`]: ./macro.dedup_whitespace.html
+//!
+//! [`title_from_snake`]: ./macro.title_from_snake.html
+//!
+//! [`snake_to_camel`]: ./macro.snake_to_camel.html
+//!
+//! [`camel_to_snake`]: ./macro.camel_to_snake.html
+//!
+//! [`wrap_text`]: ./macro.wrap_text.html
+//!
 //! [`str::replace`]: https://doc.rust-lang.org/std/primitive.str.html#method.replace
 //!
 #![no_std]
@@ -368,6 +730,12 @@
 #![allow(clippy::wrong_self_convention)]
 #![deny(missing_docs)]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "heapless")]
+extern crate heapless_crate as heapless;
+
 include! {"const_debug_derive.rs"}
 
 #[macro_use]
@@ -385,17 +753,18 @@ pub mod for_assert_macros;
 
 mod char_encoding;
 
+mod duration_encoding;
+
+mod ip_encoding;
+
 mod pargument;
 
 #[cfg(feature = "const_generics")]
 mod const_generic_concatcp;
 
-#[cfg_attr(feature = "docsrs", doc(cfg(feature = "fmt")))]
-#[cfg(feature = "fmt")]
 pub mod utils;
 
 #[doc(hidden)]
-#[cfg(any(feature = "fmt", feature = "assertcp"))]
 mod slice_cmp;
 
 #[doc(hidden)]
@@ -435,7 +804,7 @@ pub mod __ascii_case_conv;
 #[doc(hidden)]
 pub mod __str_methods;
 
-pub use __str_methods::SplicedStr;
+pub use __str_methods::{Color, SplicedStr};
 
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "const_generics")))]
 #[cfg(feature = "const_generics")]
@@ -445,12 +814,22 @@ pub use __ascii_case_conv::Case;
 #[doc(no_inline)]
 pub use crate::fmt::{Error, Formatter, FormattingFlags, Result, StrWriter, StrWriterMut};
 
+// `FormattingFlags` constants can be used with `formatcp` (eg: `"{:@FLAGS}"`),
+// which doesn't require the "fmt" feature.
+#[cfg(not(feature = "fmt"))]
+pub use crate::formatting::FormattingFlags;
+
+pub use crate::formatting::ScientificNotation;
+
 #[cfg(feature = "fmt")]
 pub use crate::wrapper_types::ascii_str::AsciiStr;
 
 #[cfg(feature = "fmt")]
 pub use crate::wrapper_types::sliced::Sliced;
 
+#[cfg(feature = "fmt")]
+pub use crate::wrapper_types::utf8_lossy::Utf8Lossy;
+
 #[cfg_attr(not(feature = "fmt"), doc(hidden))]
 pub use crate::wrapper_types::pwrapper::PWrapper;
 
@@ -462,19 +841,24 @@ pub mod __cf_osRcTFl4A {
 
 #[doc(hidden)]
 pub mod pmr {
-    pub use {bool, str, u8, usize};
+    pub use {bool, char, str, u128, u32, u64, u8, usize};
 
-    pub use const_format_proc_macros::{__concatcp_impl, __formatcp_impl, respan_to};
+    pub use const_format_proc_macros::{
+        __concatcp_impl, __format_arg_count_impl, __formatcp_impl, __interp_env_impl,
+        __is_valid_ident_impl, respan_to,
+    };
 
     #[cfg(feature = "fmt")]
     pub use const_format_proc_macros::{__formatc_if_impl, __formatc_impl, __writec_impl};
 
     #[cfg(feature = "assertcp")]
-    pub use const_format_proc_macros::__formatcp_if_impl;
+    pub use const_format_proc_macros::{__formatcp_if_impl, __formatting_flags_impl};
 
     pub use core::{
         cmp::Reverse,
+        concat,
         convert::identity,
+        env,
         mem::transmute,
         num::Wrapping,
         ops::Range,
@@ -486,20 +870,21 @@ pub mod pmr {
     pub use crate::const_generic_concatcp::__priv_concatenate;
 
     #[cfg(feature = "assertcp")]
-    pub use crate::for_assert_macros::{assert_, ConcatArgsIf};
+    pub use crate::for_assert_macros::{assert_, panic_, ConcatArgsIf};
 
     #[cfg(feature = "fmt")]
     pub use crate::{
         fmt::{ComputeStrLength, Error, Formatter, StrWriter, StrWriterMut, ToResult},
         marker_traits::{
-            FormatMarker, IsAFormatMarker, IsAWriteMarker, IsNotStdKind, IsStdKind, WriteMarker,
+            BorrowMutably, FormatMarker, IsAFormatMarker, IsAWriteMarker, IsNotStdKind, IsStdKind,
+            WriteMarker,
         },
     };
 
     pub use crate::{
         formatting::{
             hex_as_ascii, ForEscaping, Formatting, FormattingFlags, LenAndArray, NumberFormatting,
-            StartAndArray, FOR_ESCAPING,
+            ScientificNotation, StartAndArray, FOR_ESCAPING,
         },
         pargument::{PArgument, PConvWrapper, PVariant},
         wrapper_types::PWrapper,
@@ -118,6 +118,34 @@ with_shared_docs! {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __assertcp_equality_inner {
+    (
+        ($($parameters:tt)*)
+        ($left:expr, $right:expr, fmt = $spec:literal $(, $($rem:tt)*)?)
+        ($($op:tt)*)
+        ($op_str:expr)
+    )=>{
+        $crate::__assertcp_equality_inner!{
+            ($($parameters)*)
+            ($left, $right $(, $($rem)*)?)
+            ($($op)*)
+            ($op_str)
+            ($crate::pmr::__formatting_flags_impl!(($spec)))
+        }
+    };
+    (
+        ($($parameters:tt)*)
+        ($($args:tt)*)
+        ($($op:tt)*)
+        ($op_str:expr)
+    )=>{
+        $crate::__assertcp_equality_inner!{
+            ($($parameters)*)
+            ($($args)*)
+            ($($op)*)
+            ($op_str)
+            ($crate::pmr::FormattingFlags::NEW.set_alternate(true))
+        }
+    };
     (
         ($($parameters:tt)*)
         (
@@ -127,6 +155,7 @@ macro_rules! __assertcp_equality_inner {
         )
         ($($op:tt)*)
         ($op_str:expr)
+        ($flags:expr)
     )=>{
         #[allow(non_snake_case)]
         const _: () = {
@@ -137,7 +166,7 @@ macro_rules! __assertcp_equality_inner {
                 let left = $crate::PWrapper($left);
                 let right = $crate::pmr::PConvWrapper($right);
                 let cond = left.const_eq(&right.0);
-                let fmt = $crate::pmr::FormattingFlags::NEW.set_alternate(true);
+                let fmt = $flags;
                 (
                     cond,
                     $crate::pmr::PConvWrapper(left.0).to_pargument_debug(fmt),
@@ -172,6 +201,13 @@ with_shared_docs! {
     ///
     ;clarification
     ;syntax
+    /// The compared values are printed with `{:#?}` formatting by default,
+    /// this can be changed by passing a `fmt = "spec"` argument after them,
+    /// where `spec` is anything that can go after the `:` in `{:spec}`.
+    ///
+    /// eg: `assertcp_eq!(LEFT, RIGHT, fmt = "#x")` prints the values as `0xFF`,
+    /// and `assertcp_eq!(LEFT, RIGHT, fmt = "x", "message")` prints them as `FF`.
+    ///
     ;limitations
     ///
     /// # Examples
@@ -222,6 +258,35 @@ with_shared_docs! {
     ///
     /// ```
     ///
+    /// ### Hexadecimal output
+    ///
+    /// This example demonstrates how the compared values can be printed in hexadecimal,
+    /// and how the compiler error looks like.
+    ///
+    /// ```compile_fail
+    /// use const_format::assertcp_eq;
+    ///
+    /// const FLAGS: u32 = 0b1011_0000;
+    ///
+    /// assertcp_eq!(FLAGS & 0xF0, 0xA0u32, fmt = "#x", "unexpected flags");
+    ///
+    /// ```
+    ///
+    /// This is the compiler output:
+    ///
+    /// ```text
+    /// error[E0080]: evaluation panicked:
+    ///               assertion failed: `(left == right)`
+    ///                left: `0xB0`
+    ///               right: `0xA0`
+    ///               unexpected flags
+    ///
+    ///  --> src/macros/assertions/assertcp_macros.rs:7:14
+    ///   |
+    /// 7 | assertcp_eq!(FLAGS & 0xF0, 0xA0u32, fmt = "#x", "unexpected flags");
+    ///   |              ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ evaluation of `_` failed here
+    /// ```
+    ///
     #[cfg_attr(feature = "docsrs", doc(cfg(feature = "assertcp")))]
     #[macro_export]
     macro_rules! assertcp_eq {
@@ -240,6 +305,10 @@ with_shared_docs! {
     ///
     ;clarification
     ;syntax
+    /// The compared values are printed with `{:#?}` formatting by default,
+    /// which can be changed with a `fmt = "spec"` argument,
+    /// [like in `assertcp_eq`](./macro.assertcp_eq.html#syntax).
+    ///
     ;limitations
     ///
     /// # Examples
@@ -296,3 +365,87 @@ with_shared_docs! {
         );
     }
 }
+
+with_shared_docs! {
+    /// Panics with a message formatted at compile-time.
+    ///
+    ;clarification
+    /// Unlike [`assertcp`], this macro always panics when it's evaluated,
+    /// it's meant to be used in `const fn`s and constants,
+    /// in the branches for invalid inputs (eg: in an `else` branch).
+    ///
+    /// This macro evaluates to an expression of type `!`,
+    /// so it can be used wherever any type is expected.
+    ///
+    /// Because the message is formatted at compile-time,
+    /// the arguments must be constants,
+    /// they can't be the parameters of the `const fn` that uses this macro.
+    ///
+    ;syntax
+    ;limitations
+    ///
+    /// # Examples
+    ///
+    /// ### Not panicking
+    ///
+    /// ```rust
+    /// use const_format::panic_fmt;
+    ///
+    /// const MAX: u32 = 100;
+    ///
+    /// const fn percent(n: u32) -> u32 {
+    ///     if n <= MAX {
+    ///         n
+    ///     } else {
+    ///         panic_fmt!("percentages can't be larger than {MAX}")
+    ///     }
+    /// }
+    ///
+    /// const HALF: u32 = percent(50);
+    /// assert_eq!(HALF, 50);
+    /// ```
+    ///
+    /// ### Panicking
+    ///
+    /// This example demonstrates a panic in a constant,
+    /// and how the compiler error looks like.
+    ///
+    /// ```compile_fail
+    /// use const_format::panic_fmt;
+    ///
+    /// const LEN: usize = 3;
+    /// const INDEX: usize = 5;
+    ///
+    /// const ELEM: u8 = if INDEX < LEN {
+    ///     [3, 5, 8][INDEX]
+    /// } else {
+    ///     panic_fmt!("index out of bounds: the len is {} but the index is {}", LEN, INDEX)
+    /// };
+    /// ```
+    ///
+    /// This is the compiler output:
+    ///
+    /// ```text
+    /// error[E0080]: evaluation panicked: index out of bounds: the len is 3 but the index is 5
+    ///  --> src/main.rs:9:16
+    ///   |
+    /// 9 |     panic_fmt!("index out of bounds: the len is {} but the index is {}", LEN, INDEX)
+    ///   |                ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ evaluation of `ELEM` failed here
+    /// ```
+    ///
+    /// [`assertcp`]: ./macro.assertcp.html
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "assertcp")))]
+    #[macro_export]
+    macro_rules! panic_fmt {
+        ($($parameters:tt)*) => ({
+            use $crate::__cf_osRcTFl4A;
+
+            const MSG_NHPMWYD3NJA: &$crate::pmr::str = $crate::formatcp!($($parameters)*);
+
+            __cf_osRcTFl4A::pmr::respan_to!{
+                ($($parameters)*)
+                __cf_osRcTFl4A::pmr::panic_(MSG_NHPMWYD3NJA)
+            }
+        });
+    }
+}
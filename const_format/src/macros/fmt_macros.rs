@@ -6,6 +6,14 @@
 ///
 /// `concatcp` stands for "concatenate constants (of) primitives"
 ///
+/// # Formatting
+///
+/// Arguments are written with `{}` formatting by default,
+/// an `(argument, "spec")` tuple formats the argument as [`formatcp`]'s `{:spec}` would,
+/// eg: `concatcp!((0xFFu16, "#x"))` is the same as `formatcp!("{:#x}", 0xFFu16)`.
+///
+/// An invalid spec causes a compile-time error.
+///
 /// # Limitations
 ///
 /// This macro can only take constants of these types as inputs:
@@ -18,6 +26,12 @@
 ///
 /// - `bool`
 ///
+/// - `core::net::{Ipv4Addr, Ipv6Addr, IpAddr}`, with the "net" feature.
+///
+/// Width and alignment (eg: `{:5}`, `{:width$}`, `{:>5}`) aren't supported,
+/// strings can be padded with the [`pad_start`] and [`pad_end`] macros instead.
+/// Precision is only supported in scientific notation (eg: `{:.2e}`).
+///
 /// This macro also shares
 /// [the limitations described in here](./index.html#macro-limitations)
 /// as well.
@@ -52,6 +66,25 @@
 ///
 /// ```
 ///
+/// ### Formatted arguments
+///
+/// ```rust
+/// use const_format::concatcp;
+///
+/// const ID: u32 = 0xC0FFEE;
+///
+/// const MSG: &str = concatcp!("id=", (ID, "#x"), " bits=", (0b101u8, "b"), " ", ("a\tb", "?"));
+///
+/// assert_eq!(MSG, r#"id=0xC0FFEE bits=101 "a\tb""#);
+///
+/// ```
+///
+/// [`formatcp`]: ./macro.formatcp.html
+///
+/// [`pad_start`]: ./macro.pad_start.html
+///
+/// [`pad_end`]: ./macro.pad_end.html
+///
 #[macro_export]
 macro_rules! concatcp {
     ()=>{""};
@@ -87,6 +120,8 @@ macro_rules! __concatcp_inner {
                     PVariant::Str(s) => __write_pvariant!(str, current, s => out),
                     PVariant::Int(int) => __write_pvariant!(int, current, int => out),
                     PVariant::Char(c) => __write_pvariant!(char, current, c => out),
+                    PVariant::Ip(ip) => __write_pvariant!(char, current, ip => out),
+                    PVariant::Duration(d) => __write_pvariant!(char, current, d => out),
                 }
             }
             &{ out }
@@ -159,6 +194,26 @@ macro_rules! __concatcp_inner {
 ///
 /// - Use Display formatting: `formatcp!("{}", "hello" )`
 ///
+/// - Use the flags of a [`FormattingFlags`] constant from scope
+/// (eg: `formatcp!("{:@FLAGS?}", 10u8)`).
+/// Without the trailing `?` it uses Display formatting with the flags,
+/// otherwise it uses Debug formatting.
+///
+/// - Display a `bool` with custom labels (eg: `formatcp!("{:bool(on,off)}", true)`):
+/// Writes the first label for `true`, and the second for `false`,
+/// the labels are used verbatim, including whitespace.
+///
+/// - Group the digits of integers (eg: `formatcp!("{:,}", 1234567u32)`):
+/// Writes a `','` between each group of three digits (`"1,234,567"`),
+/// `{:group(_)}` writes the single character inside the parentheses instead.
+/// This can't be combined with other formatting, like `{:,x}`.
+///
+/// - Write integers in scientific notation (eg: `formatcp!("{:e}", 1200u32)`):
+/// Writes the mantissa and the exponent, separated by an `e` (`"1.2e3"`),
+/// or by an `E` with `{:E}`.
+/// A precision (eg: `{:.2e}`) sets how many digits are written after the decimal point,
+/// rounding the mantissa the same way that the standard library does.
+///
 ///
 /// # Limitations
 ///
@@ -172,6 +227,11 @@ macro_rules! __concatcp_inner {
 ///
 /// - `bool`
 ///
+/// - `core::net::{Ipv4Addr, Ipv6Addr, IpAddr}`, with the "net" feature.
+///
+/// - `core::time::Duration`, only with `{:?}` formatting (eg: `1.5ms`),
+/// because the standard library doesn't implement `Display` for it.
+///
 /// This macro also shares
 /// [the limitations described in here](./index.html#macro-limitations)
 /// as well.
@@ -230,6 +290,27 @@ macro_rules! __concatcp_inner {
 ///
 /// ```
 ///
+/// ### Const fn arguments
+///
+/// The arguments can be any constant expression, including `const fn` calls.
+///
+/// ```rust
+/// use const_format::formatcp;
+///
+/// const fn compute_id() -> u32 {
+///     0x100 + 0x23
+/// }
+///
+/// const fn name() -> &'static str {
+///     "widget"
+/// }
+///
+/// const MSG: &str = formatcp!("{}#{:x}", name(), compute_id());
+///
+/// assert_eq!(MSG, "widget#123");
+///
+/// ```
+///
 /// ### Debug-like and Display formatting
 ///
 /// ```rust
@@ -248,8 +329,54 @@ macro_rules! __concatcp_inner {
 /// }
 /// ```
 ///
+/// ### Flags from a constant
+///
+/// ```rust
+/// use const_format::{FormattingFlags, formatcp};
+///
+/// const HEX: FormattingFlags = FormattingFlags::NEW.set_hexadecimal().set_alternate(true);
+///
+/// const MSG: &str = formatcp!("{0:@HEX?} {1:@HEX?} {0}", 255u8, "foo");
+///
+/// assert_eq!(MSG, r#"0xFF "foo" 255"#);
+/// ```
+///
+/// ### Digit grouping
+///
+/// ```rust
+/// use const_format::formatcp;
+///
+/// const POPULATION: u64 = 8_045_311_447;
+///
+/// const US: &str = formatcp!("{:,} people", POPULATION);
+/// const EU: &str = formatcp!("{:group(.)} people", POPULATION);
+///
+/// assert_eq!(US, "8,045,311,447 people");
+/// assert_eq!(EU, "8.045.311.447 people");
+/// ```
+///
+/// ### Scientific notation
+///
+/// ```rust
+/// use const_format::formatcp;
+///
+/// const DISTANCE: u64 = 149_597_870_700;
+///
+/// const FULL: &str = formatcp!("{:e} meters", DISTANCE);
+/// const SHORT: &str = formatcp!("{:.2E} meters", DISTANCE);
+///
+/// assert_eq!(FULL, "1.495978707e11 meters");
+/// assert_eq!(SHORT, "1.50E11 meters");
+/// ```
+///
 /// [`format`]: https://doc.rust-lang.org/std/macro.format.html
 ///
+/// [`FormattingFlags`]: crate::FormattingFlags
+///
+/// [`pad_start`]: ./macro.pad_start.html
+///
+/// [`pad_end`]: ./macro.pad_end.html
+///
 /// [`format_args_implicits` RFC]:
 /// https://github.com/rust-lang/rfcs/blob/master/text/2795-format-args-implicit-identifiers.md
 ///
@@ -268,6 +395,482 @@ macro_rules! formatcp {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// Counts the distinct arguments that a [`formatcp`]-style format string uses,
+/// evaluating to a `usize` constant.
+///
+/// Like in the formatting macros, every `{}` (or `{:?}`, etc) argument
+/// uses the next positional argument.
+/// Arguments that are used multiple times (eg: `{0}` and `{0:?}`, or `{x}` and `{x:b}`)
+/// are only counted once.
+///
+/// This counts the arguments that are used, rather than the highest positional index,
+/// so `format_arg_count!("{1}")` is `1`.
+///
+/// The format string can be a string literal, or a `concat!` of string literals.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::format_arg_count;
+///
+/// const COUNT: usize = format_arg_count!("{} {x} {}");
+/// assert_eq!(COUNT, 3);
+///
+/// assert_eq!(format_arg_count!("no arguments {{}}"), 0);
+/// assert_eq!(format_arg_count!("{0} {0:?} {x} {x:b}"), 2);
+/// assert_eq!(format_arg_count!("{} {0} {}"), 2);
+/// assert_eq!(format_arg_count!(concat!("{}", "-{}")), 2);
+///
+/// ```
+///
+/// [`formatcp`]: ./macro.formatcp.html
+///
+#[macro_export]
+macro_rules! format_arg_count {
+    ($format_string:expr $(,)?) => {
+        $crate::pmr::__format_arg_count_impl!(($format_string))
+    };
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Substitutes the `${NAME}`s in a string literal template with the values of
+/// the `NAME` environment variables at compile-time, evaluating to a `&'static str` constant.
+///
+/// Write `$$` for a literal `$`.
+///
+/// It's a compile-time error for an environment variable to not be defined,
+/// the error (from the [`env`] macro) says which one is missing.
+///
+/// Unlike [`formatcp`], this only takes a string literal,
+/// and the `{` and `}` characters don't need any escaping.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::interp_env;
+///
+/// const DESC: &str = interp_env!("${CARGO_PKG_NAME} v${CARGO_PKG_VERSION}");
+/// assert_eq!(
+///     DESC,
+///     concat!(env!("CARGO_PKG_NAME"), " v", env!("CARGO_PKG_VERSION")),
+/// );
+///
+/// assert_eq!(interp_env!("costs $$10 {}"), "costs $10 {}");
+///
+/// ```
+///
+/// This fails to compile because the `NOT_A_DEFINED_VAR_VCF8Y` variable isn't defined:
+///
+/// ```compile_fail
+/// const _: &str = const_format::interp_env!("${NOT_A_DEFINED_VAR_VCF8Y}");
+/// ```
+///
+/// [`env`]: https://doc.rust-lang.org/core/macro.env.html
+///
+/// [`formatcp`]: ./macro.formatcp.html
+///
+#[macro_export]
+macro_rules! interp_env {
+    ($template:expr $(,)?) => {{
+        use $crate::__cf_osRcTFl4A;
+
+        $crate::pmr::__interp_env_impl!(($template))
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Checks whether a string literal is a valid Rust identifier,
+/// evaluating to a `bool` constant.
+///
+/// An identifier is valid if it starts with a character with the `XID_Start` property
+/// or an underscore, and is followed by characters with the `XID_Continue` property,
+/// the same check that the [`formatcp`] macro uses for the identifiers in format strings.
+/// A lone `_` isn't a valid identifier.
+///
+/// Keywords (eg: `type`, `fn`, `self`) are not valid identifiers,
+/// this includes the keywords that are reserved in any edition, like `async`, `try`, and `gen`.
+/// Weak keywords like `union` and `macro_rules` are valid identifiers.
+///
+/// Raw identifiers (eg: `r#type`) are valid if the name after the `r#` is,
+/// except for `r#crate`, `r#self`, `r#Self`, and `r#super`,
+/// which the compiler doesn't accept.
+///
+/// Like [`interp_env`], this only takes a string literal.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::is_valid_ident;
+///
+/// const IS_VALID: bool = is_valid_ident!("foo_bar");
+/// assert!(IS_VALID);
+///
+/// assert!(is_valid_ident!("_private"));
+/// assert!(is_valid_ident!("ñandú"));
+/// assert!(is_valid_ident!("r#type"));
+///
+/// // keywords
+/// assert!(!is_valid_ident!("type"));
+/// assert!(!is_valid_ident!("self"));
+/// assert!(!is_valid_ident!("r#self"));
+///
+/// // invalid characters
+/// assert!(!is_valid_ident!("foo-bar"));
+/// assert!(!is_valid_ident!("2nd"));
+/// assert!(!is_valid_ident!(""));
+/// assert!(!is_valid_ident!("_"));
+///
+/// ```
+///
+/// [`formatcp`]: ./macro.formatcp.html
+///
+/// [`interp_env`]: ./macro.interp_env.html
+///
+#[macro_export]
+macro_rules! is_valid_ident {
+    ($name:expr $(,)?) => {
+        $crate::pmr::__is_valid_ident_impl!(($name))
+    };
+}
+
+/// Computes the length of the longest of the arguments when they're
+/// [`formatcp`]ed with `{}`, evaluating to a `usize` constant.
+///
+/// The arguments can be `integers`, `bool`, `char`, and `&str` constants,
+/// and their length is measured in `char`s, not bytes,
+/// so that it can be used as the width of a column of aligned text.
+///
+/// This evaluates to `0` when no arguments are passed.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{col_width, formatcp, str_repeat};
+///
+/// const A: u32 = 7;
+/// const B: u32 = 1024;
+/// const C: u32 = 64;
+///
+/// const WIDTH: usize = col_width!([A, B, C]);
+/// assert_eq!(WIDTH, 4);
+///
+/// assert_eq!(col_width!(["foo", "ñandú", 'c', true, -100i8]), 5);
+/// assert_eq!(col_width!([]), 0);
+///
+/// // Right-aligning a value in the column
+/// const ROW: &str = formatcp!("|{}{}|", str_repeat!(" ", WIDTH - col_width!([A])), A);
+/// assert_eq!(ROW, "|   7|");
+///
+/// ```
+///
+/// [`formatcp`]: ./macro.formatcp.html
+///
+#[macro_export]
+macro_rules! col_width {
+    ([$($arg:expr),* $(,)?] $(,)?) => {{
+        const W_OSRCTFL4A: $crate::pmr::usize = $crate::__hidden_utils::max_usize_of(&[
+            $( $crate::utils::count_chars($crate::formatcp!("{}", $arg)), )*
+        ]);
+        W_OSRCTFL4A
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Wraps a constant between a prefix and a suffix,
+/// evaluating to a `&'static str` constant.
+///
+/// When only two arguments are passed, the first one is used as both the prefix and the suffix.
+///
+/// The arguments can be any of the types that [`concatcp`] takes,
+/// the wrapped constant is formatted like in [`concatcp`].
+///
+/// # Signature
+///
+/// ```rust
+/// # macro_rules! dont_run {() => {
+/// wrap_in!(prefix: &'static str, wrapped: &'static str, suffix: &'static str) -> &'static str
+///
+/// wrap_in!(wrapper: &'static str, wrapped: &'static str) -> &'static str
+/// # }}
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::wrap_in;
+///
+/// const NAME: &str = "config";
+///
+/// assert_eq!(wrap_in!("[", NAME, "]"), "[config]");
+/// assert_eq!(wrap_in!("\"", NAME), "\"config\"");
+/// assert_eq!(wrap_in!("**", "bold"), "**bold**");
+/// assert_eq!(wrap_in!("(", 42u8, ")"), "(42)");
+///
+/// ```
+///
+/// [`concatcp`]: ./macro.concatcp.html
+///
+#[macro_export]
+macro_rules! wrap_in {
+    ($prefix:expr, $wrapped:expr, $suffix:expr $(,)?) => {
+        $crate::concatcp!($prefix, $wrapped, $suffix)
+    };
+    ($wrapper:expr, $wrapped:expr $(,)?) => {
+        $crate::concatcp!($wrapper, $wrapped, $wrapper)
+    };
+}
+
+/// Concatenates an integer constant with the singular or plural form of a noun,
+/// evaluating to a `&'static str` constant.
+///
+/// The singular form is used when the count is `1`, the plural form otherwise.
+///
+/// When only the singular form is passed,
+/// the plural form is made by appending `es` to words that end in
+/// `s`, `x`, `z`, `ch`, or `sh`, and `s` to every other word.
+///
+/// The count must be of a concrete integer type, eg: `2u8` instead of `2`,
+/// like the arguments of [`concatcp`].
+///
+/// # Signature
+///
+/// ```rust
+/// # macro_rules! dont_run {() => {
+/// pluralize!(count: integer, singular: &'static str, plural: &'static str) -> &'static str
+///
+/// pluralize!(count: integer, singular: &'static str) -> &'static str
+/// # }}
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::pluralize;
+///
+/// const APPLES: u32 = 3;
+///
+/// assert_eq!(pluralize!(APPLES, "apple"), "3 apples");
+/// assert_eq!(pluralize!(1u8, "apple"), "1 apple");
+/// assert_eq!(pluralize!(0u8, "box"), "0 boxes");
+/// assert_eq!(pluralize!(2u32, "mouse", "mice"), "2 mice");
+/// assert_eq!(pluralize!(-1i8, "mouse", "mice"), "-1 mice");
+///
+/// ```
+///
+/// [`concatcp`]: ./macro.concatcp.html
+///
+#[macro_export]
+macro_rules! pluralize {
+    ($count:expr, $singular:expr, $plural:expr $(,)?) => {
+        $crate::concatcp!($count, " ", if $count == 1 { $singular } else { $plural },)
+    };
+    ($count:expr, $singular:expr $(,)?) => {
+        $crate::concatcp!(
+            $count,
+            " ",
+            $singular,
+            if $count == 1 {
+                ""
+            } else {
+                $crate::__hidden_utils::english_plural_suffix($singular)
+            },
+        )
+    };
+}
+
+/// Concatenates an integer constant with its English ordinal suffix,
+/// evaluating to a `&'static str` constant, eg: `"21st"`.
+///
+/// Numbers that end in `1`, `2`, and `3` get the `st`, `nd`, and `rd` suffixes respectively,
+/// except for the ones that end in `11`, `12`, and `13`,
+/// which get the `th` suffix like every other number.
+///
+/// The number must be of a concrete integer type, eg: `2u8` instead of `2`,
+/// like the arguments of [`concatcp`].
+///
+/// # Signature
+///
+/// ```rust
+/// # macro_rules! dont_run {() => {
+/// ordinal!(number: integer) -> &'static str
+/// # }}
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::ordinal;
+///
+/// const PLACE: u32 = 21;
+///
+/// assert_eq!(ordinal!(PLACE), "21st");
+/// assert_eq!(ordinal!(2u8), "2nd");
+/// assert_eq!(ordinal!(13u8), "13th");
+/// assert_eq!(ordinal!(103u64), "103rd");
+/// assert_eq!(ordinal!(-1i32), "-1st");
+///
+/// ```
+///
+/// [`concatcp`]: ./macro.concatcp.html
+///
+#[macro_export]
+macro_rules! ordinal {
+    ($number:expr $(,)?) => {
+        $crate::concatcp!(
+            $number,
+            $crate::__hidden_utils::english_ordinal_suffix(($number % 100) as i8),
+        )
+    };
+}
+
+/// Formats a `u64` constant as a short, human-readable number,
+/// evaluating to a `&'static str` constant.
+///
+/// Numbers of at least a thousand are divided by the largest unit they reach,
+/// and written with at most one decimal place,
+/// followed by the suffix of the unit:
+///
+/// | unit                | suffix |
+/// |---------------------|--------|
+/// | `1_000`             | `K`    |
+/// | `1_000_000`         | `M`    |
+/// | `1_000_000_000`     | `G`    |
+/// | `1_000_000_000_000` | `T`    |
+///
+/// Numbers below a thousand are written without a suffix.
+///
+/// The decimal place is truncated instead of rounded,
+/// so that numbers are never written larger than they are (eg: `999_999` is written `999.9K`),
+/// and it's omitted when it's zero (eg: `1_000` is written `1K` instead of `1.0K`).
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// fn abbreviate_number(number: u64) -> &'static str
+/// # {""}
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{abbreviate_number, concatcp};
+///
+/// const DOWNLOADS: u64 = 1_534_210;
+/// const BADGE: &str = concatcp!("downloads: ", abbreviate_number!(DOWNLOADS));
+///
+/// assert_eq!(BADGE, "downloads: 1.5M");
+///
+/// assert_eq!(abbreviate_number!(999), "999");
+/// assert_eq!(abbreviate_number!(1000), "1K");
+/// assert_eq!(abbreviate_number!(1099), "1K");
+/// assert_eq!(abbreviate_number!(1100), "1.1K");
+/// assert_eq!(abbreviate_number!(12_300_000_000), "12.3G");
+/// ```
+///
+#[macro_export]
+macro_rules! abbreviate_number {
+    ($number:expr $(,)?) => {{
+        const P_OSRCTFL4A: $crate::__str_methods::AbbreviateNumberArgs =
+            $crate::__str_methods::AbbreviateNumberArgs::new($number);
+
+        $crate::concatcp!(P_OSRCTFL4A.whole, P_OSRCTFL4A.decimal, P_OSRCTFL4A.suffix)
+    }};
+}
+
+/// Formats an integer constant as a decimal number with `scale` decimal places,
+/// evaluating to a `&'static str`.
+/// Integer literals require a type suffix, eg: `to_fixed_point!(12345u32, 2)`.
+///
+/// The number is written as if it was divided by `10` to the power of `scale`,
+/// eg: `12345` with a scale of `2` is written `123.45`,
+/// which is useful for amounts stored in the smallest unit of a currency.
+///
+/// The fractional part is padded with leading zeroes to be `scale` digits long,
+/// so numbers smaller than the scale are written with a `0` integer part
+/// (eg: `5` with a scale of `2` is written `0.05`),
+/// and a scale of `0` writes the number unchanged.
+///
+/// The number is formatted like in [`concatcp`],
+/// which is why integer literals require a type suffix.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// fn to_fixed_point(number: impl Integer, scale: u32) -> &'static str
+/// # {""}
+/// # trait Integer {}
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{concatcp, to_fixed_point};
+///
+/// const PRICE_CENTS: u32 = 1999;
+/// const PRICE: &str = concatcp!("$", to_fixed_point!(PRICE_CENTS, 2));
+///
+/// assert_eq!(PRICE, "$19.99");
+///
+/// assert_eq!(to_fixed_point!(12345u16, 2), "123.45");
+/// assert_eq!(to_fixed_point!(12345u16, 0), "12345");
+/// assert_eq!(to_fixed_point!(5u8, 2), "0.05");
+/// assert_eq!(to_fixed_point!(-5i8, 2), "-0.05");
+/// assert_eq!(to_fixed_point!(-100500i64, 3), "-100.500");
+/// ```
+///
+/// ### Failing
+///
+/// Scales larger than `38` cause a compile-time error.
+///
+/// ```compile_fail
+/// const _: &str = const_format::to_fixed_point!(1u8, 39);
+/// ```
+///
+/// Integer literals without a type suffix cause a compile-time error.
+///
+/// ```compile_fail
+/// const _: &str = const_format::to_fixed_point!(12345, 2);
+/// ```
+///
+/// [`concatcp`]: ./macro.concatcp.html
+///
+#[macro_export]
+macro_rules! to_fixed_point {
+    ($number:expr, $scale:expr $(,)?) => {{
+        const P_OSRCTFL4A: $crate::__str_methods::FixedPointArgs =
+            $crate::__str_methods::FixedPointArgs::new(
+                $crate::pmr::PConvWrapper($number).to_integer(),
+                $scale,
+            );
+
+        $crate::pmr::respan_to! {
+            ($scale)
+            const _ASSERT_VALID: () = P_OSRCTFL4A.assert_valid();
+        }
+
+        const ZEROS_OSRCTFL4A: $crate::pmr::usize = P_OSRCTFL4A.fraction_zeros;
+        const FRACTION_OSRCTFL4A: &$crate::pmr::str = $crate::concatcp!(
+            ".",
+            $crate::str_repeat!("0", ZEROS_OSRCTFL4A),
+            P_OSRCTFL4A.fraction,
+        );
+        const HAS_FRACTION_OSRCTFL4A: $crate::pmr::usize = P_OSRCTFL4A.has_fraction;
+
+        $crate::concatcp!(
+            P_OSRCTFL4A.sign,
+            P_OSRCTFL4A.whole,
+            $crate::str_repeat!(FRACTION_OSRCTFL4A, HAS_FRACTION_OSRCTFL4A),
+        )
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 /// Concatenates constants of standard library and/or user-defined types into a `&'static str`.
 ///
 /// User defined types must implement the [`FormatMarker`] trait and
@@ -502,6 +1105,95 @@ macro_rules! formatc {
     });
 }
 
+/// Formats constants into a [`StrWriter`] with a capacity of `capacity` bytes,
+/// evaluating to a `StrWriter<[u8; capacity]>` constant.
+///
+/// The returned `StrWriter` contains the formatted text,
+/// and can be written to afterwards, eg: with the [`writec`] macro.
+///
+/// It's a compile-time error for the formatted text to be longer than `capacity` bytes.
+///
+/// # Syntax
+///
+/// The first argument is the capacity of the `StrWriter`,
+/// the arguments after it use the same syntax as the [`formatc`] macro.
+///
+/// # Limitations
+///
+/// This macro has [the limitations described in here](./index.html#macro-limitations).
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(const_mut_refs)]
+///
+/// use const_format::{formatc_writer, writec, StrWriter};
+///
+/// const PREFIX: StrWriter<[u8; 32]> = formatc_writer!(32, "{}:{:?}", "log", [3u8, 5]);
+///
+/// let mut writer = PREFIX;
+/// assert_eq!(writer.r().as_str(), "log:[3, 5]");
+/// assert_eq!(writer.r().capacity(), 32);
+///
+/// writec!(writer, " {}", 8u8).unwrap();
+/// assert_eq!(writer.r().as_str(), "log:[3, 5] 8");
+///
+/// ```
+///
+/// ### Not enough space
+///
+/// ```compile_fail
+/// #![feature(const_mut_refs)]
+///
+/// use const_format::{formatc_writer, StrWriter};
+///
+/// const TOO_SHORT: StrWriter<[u8; 4]> = formatc_writer!(4, "{}", "hello");
+/// ```
+///
+/// [`StrWriter`]: crate::fmt::StrWriter
+/// [`writec`]: crate::writec
+/// [`formatc`]: crate::formatc
+///
+#[cfg(feature = "fmt")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "fmt")))]
+#[cfg_attr(
+    feature = "testing",
+    doc = r##"
+```rust
+#![feature(const_mut_refs)]
+
+const W: const_format::StrWriter<[u8; 5]> = const_format::formatc_writer!(5, "{}", "hello");
+assert_eq!(W.r().as_str(), "hello");
+```
+"##
+)]
+#[macro_export]
+macro_rules! formatc_writer {
+    ($capacity:expr, $format_string:expr $( $(, $expr:expr )+ )? $(,)? ) => ({
+        const fn str_writer_nhpmwyd3nja(
+        ) -> $crate::msg::ErrorTupleAndStrWriter<[$crate::pmr::u8; $capacity]> {
+            let mut writer = $crate::fmt::StrWriter::new([0; $capacity]);
+            let error = $crate::writec!(writer, $format_string $(, $($expr),+)?);
+
+            $crate::msg::ErrorTupleAndStrWriter {
+                error: $crate::msg::ErrorTuple::new(error, writer.r()),
+                writer,
+            }
+        }
+
+        const STR_WRITER_NHPMWYD3NJA: $crate::msg::ErrorTupleAndStrWriter<
+            [$crate::pmr::u8; $capacity],
+        > = str_writer_nhpmwyd3nja();
+
+        const _: $crate::msg::Ok = <<$crate::msg::ErrorPicker<
+            [(); STR_WRITER_NHPMWYD3NJA.error.error_variant],
+            [(); STR_WRITER_NHPMWYD3NJA.error.capacity],
+        > as $crate::msg::ErrorAsType>::Type>::NEW;
+
+        STR_WRITER_NHPMWYD3NJA.writer
+    });
+}
+
 /// Writes some formatted standard library and/or user-defined types into a buffer.
 ///
 /// This macro evaluates to a `Result<(), const_format::Error>` which must be handled.
@@ -536,6 +1228,11 @@ macro_rules! formatc {
 /// [This example](#custom-writable-example) below shows how to use this macro
 /// with a custom type.
 ///
+/// Passing a [`ComputeStrLength`] as the writer computes the length of the
+/// formatted output without writing it anywhere,
+/// which can be used to get the capacity that a buffer needs
+/// ([example](./fmt/struct.ComputeStrLength.html#using-writec)).
+///
 /// # Limitations
 ///
 /// Integer arguments must have a type inferrable from context,
@@ -681,6 +1378,7 @@ macro_rules! formatc {
 /// ```
 ///
 /// [`Formatter`]: ./fmt/struct.Formatter.html
+/// [`ComputeStrLength`]: ./fmt/struct.ComputeStrLength.html
 /// [`WriteMarker`]: ./marker_traits/trait.WriteMarker.html
 ///
 ///
@@ -700,3 +1398,120 @@ macro_rules! writec {
         }
     });
 }
+
+/// Writes to a [`WriteMarker`] type like [`writec`] does, followed by a newline.
+///
+/// This is the const equivalent of [`writeln`].
+/// `writecln!(writer)` (without a format string) writes only the newline.
+///
+/// Like [`writec`], this evaluates to a `Result<(), const_format::Error>`.
+///
+/// # Signature
+///
+/// ```rust
+/// # macro_rules! dont_run {() => {
+/// writecln!(writer: impl WriteMarker, format_string: &'static str, args...)
+/// # }}
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(const_mut_refs)]
+///
+/// use const_format::{Error, Formatter, FormattingFlags, StrWriter};
+/// use const_format::{try_, writecln};
+///
+/// const fn write_header(f: &mut Formatter<'_>, name: &str, version: u32) -> Result<(), Error> {
+///     try_!(writecln!(f, "[package]"));
+///     try_!(writecln!(f, "name = {:?}", name));
+///     try_!(writecln!(f, "version = {version}"));
+///     writecln!(f)
+/// }
+///
+/// let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+///
+/// write_header(&mut writer.make_formatter(FormattingFlags::NEW), "foo", 3)?;
+/// assert_eq!(writer.as_str(), "[package]\nname = \"foo\"\nversion = 3\n\n");
+///
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`WriteMarker`]: ./marker_traits/trait.WriteMarker.html
+/// [`writec`]: ./macro.writec.html
+/// [`writeln`]: https://doc.rust-lang.org/std/macro.writeln.html
+#[macro_export]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "fmt")))]
+#[cfg(feature = "fmt")]
+macro_rules! writecln {
+    ($writer:expr $(,)?) => {
+        $crate::writec!($writer, "\n")
+    };
+    ( $writer:expr, $format_string:expr $( $(, $expr:expr )+ )? $(,)? ) => {
+        $crate::writec!($writer, concat!($format_string, "\n") $(, $($expr),+)?)
+    };
+}
+
+/// Writes to a [`WriteMarker`] type like [`writec`] does, only if `condition` is `true`.
+///
+/// This is sugar for
+/// ```ignore
+/// if condition {
+///     try_!(writec!(writer, "format string", args...));
+/// }
+/// ```
+/// which means that on errors it returns `Err(error)` from the enclosing function,
+/// like [`try_`] does.
+///
+/// # Signature
+///
+/// ```rust
+/// # macro_rules! dont_run {() => {
+/// cond_fmt!(writer: impl WriteMarker, condition: bool, format_string: &'static str, args...)
+/// # }}
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(const_mut_refs)]
+///
+/// use const_format::{Error, Formatter, FormattingFlags, StrWriter};
+/// use const_format::cond_fmt;
+///
+/// const VERBOSE: bool = false;
+///
+/// const fn write_user(f: &mut Formatter<'_>, name: &str, id: u32) -> Result<(), Error> {
+///     cond_fmt!(f, VERBOSE, "user ");
+///     cond_fmt!(f, true, "{}", name);
+///     cond_fmt!(f, id != 0, " #{}", id);
+///     Ok(())
+/// }
+///
+/// let writer: &mut StrWriter = &mut StrWriter::new([0; 32]);
+///
+/// write_user(&mut writer.make_formatter(FormattingFlags::NEW), "bob", 7)?;
+/// assert_eq!(writer.as_str(), "bob #7");
+///
+/// writer.clear();
+/// write_user(&mut writer.make_formatter(FormattingFlags::NEW), "alice", 0)?;
+/// assert_eq!(writer.as_str(), "alice");
+///
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// [`WriteMarker`]: ./marker_traits/trait.WriteMarker.html
+/// [`writec`]: ./macro.writec.html
+/// [`try_`]: ./macro.try_.html
+#[macro_export]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "fmt")))]
+#[cfg(feature = "fmt")]
+macro_rules! cond_fmt {
+    (
+        $writer:expr, $condition:expr, $format_string:expr $( $(, $expr:expr )+ )? $(,)?
+    ) => {
+        if $condition {
+            $crate::try_!($crate::writec!($writer, $format_string $(, $($expr),+)?));
+        }
+    };
+}
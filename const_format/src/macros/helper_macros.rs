@@ -25,6 +25,8 @@ macro_rules! iter_copy_slice{
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __write_pvariant {
+    // Also used for `PVariant::Ip` and `PVariant::Duration`,
+    // which are pre-encoded the same way as chars.
     (char, $parg:expr, $elem:ident => $out:ident) => {{
         let encoded = $elem.encoded();
         let len = $elem.len();
@@ -37,39 +39,104 @@ macro_rules! __write_pvariant {
         }
     }};
     (int, $parg:expr, $elem:ident => $out:ident) => {{
-        let wrapper = $crate::pmr::PWrapper($elem);
+        if let Some(sci) = $parg.scientific() {
+            $crate::__write_pvariant!(scientific, $parg, sci => $out);
+        } else {
+            let wrapper = $crate::pmr::PWrapper($elem);
 
-        let debug_display;
-        let bin;
-        let hex;
+            let debug_display;
+            let bin;
+            let hex;
 
-        let sa: &$crate::pmr::StartAndArray<[_]> = match $parg.fmt {
-            $crate::pmr::Formatting::Display => {
-                debug_display = wrapper.to_start_array_display();
-                &debug_display
-            }
-            $crate::pmr::Formatting::Debug => match $parg.fmt_flags.num_fmt() {
-                $crate::pmr::NumberFormatting::Decimal => {
-                    debug_display = wrapper.to_start_array_debug();
+            let sa: &$crate::pmr::StartAndArray<[_]> = match $parg.fmt {
+                $crate::pmr::Formatting::Display => {
+                    debug_display = wrapper.to_start_array_display();
                     &debug_display
                 }
-                $crate::pmr::NumberFormatting::Binary => {
-                    bin = wrapper.to_start_array_binary($parg.fmt_flags);
-                    &bin
+                $crate::pmr::Formatting::Debug => match $parg.fmt_flags.num_fmt() {
+                    $crate::pmr::NumberFormatting::Decimal => {
+                        debug_display = wrapper.to_start_array_debug();
+                        &debug_display
+                    }
+                    $crate::pmr::NumberFormatting::Binary => {
+                        bin = wrapper.to_start_array_binary($parg.fmt_flags);
+                        &bin
+                    }
+                    $crate::pmr::NumberFormatting::Hexadecimal => {
+                        hex = wrapper.to_start_array_hexadecimal($parg.fmt_flags);
+                        &hex
+                    }
+                },
+            };
+
+            let mut start = sa.start;
+            if let Some(sep) = $parg.digit_grouping() {
+                if sa.array[start] == b'-' {
+                    $out.array[$out.len] = b'-';
+                    $out.len += 1;
+                    start += 1;
                 }
-                $crate::pmr::NumberFormatting::Hexadecimal => {
-                    hex = wrapper.to_start_array_hexadecimal($parg.fmt_flags);
-                    &hex
+
+                let mut digits_left = sa.array.len() - start;
+                while start < sa.array.len() {
+                    $out.array[$out.len] = sa.array[start];
+                    $out.len += 1;
+                    start += 1;
+                    digits_left -= 1;
+
+                    if digits_left != 0 && digits_left % 3 == 0 {
+                        $crate::__write_pvariant!(char, $parg, sep => $out);
+                    }
                 }
-            },
-        };
+            } else {
+                while start < sa.array.len() {
+                    $out.array[$out.len] = sa.array[start];
+                    $out.len += 1;
+                    start += 1;
+                }
+            }
+        }
+    }};
+    (scientific, $parg:expr, $elem:ident => $out:ident) => {{
+        if $elem.is_negative {
+            $out.array[$out.len] = b'-';
+            $out.len += 1;
+        }
 
-        let mut start = sa.start;
-        while start < sa.array.len() {
-            $out.array[$out.len] = sa.array[start];
+        // The digits are written from the last one to the first one.
+        let mantissa_end = $out.len + $elem.mantissa_len + $elem.has_point as usize;
+        let mut n = $elem.mantissa;
+        let mut i = mantissa_end;
+        while i > $out.len {
+            i -= 1;
+            if $elem.has_point && i == $out.len + 1 {
+                $out.array[i] = b'.';
+            } else {
+                $out.array[i] = b'0' + (n % 10) as u8;
+                n /= 10;
+            }
+        }
+        $out.len = mantissa_end;
+
+        let mut zeros = $elem.added_zeros;
+        while zeros != 0 {
+            $out.array[$out.len] = b'0';
             $out.len += 1;
-            start += 1;
+            zeros -= 1;
+        }
+
+        $out.array[$out.len] = if $elem.is_uppercase { b'E' } else { b'e' };
+        $out.len += 1;
+
+        let exponent_end = $out.len + $elem.exponent_len;
+        let mut n = $elem.exponent;
+        let mut i = exponent_end;
+        while i > $out.len {
+            i -= 1;
+            $out.array[i] = b'0' + (n % 10) as u8;
+            n /= 10;
         }
+        $out.len = exponent_end;
     }};
     (str, $parg:expr, $elem:ident => $out:ident) => {{
         let str = $elem.as_bytes();
@@ -12,3 +12,6 @@ pub use self::{
     format_marker::{FormatMarker, IsAFormatMarker, IsArrayKind, IsNotStdKind, IsStdKind},
     write_marker::{IsAStrWriter, IsAWriteMarker, IsNotAStrWriter, WriteMarker},
 };
+
+#[doc(hidden)]
+pub use self::write_marker::BorrowMutably;
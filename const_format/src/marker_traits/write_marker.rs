@@ -2,7 +2,13 @@
 //!
 //!
 
-use crate::fmt::{Formatter, StrWriter, StrWriterMut};
+use crate::fmt::{ComputeStrLength, Formatter, StrWriter, StrWriterMut};
+
+#[cfg(feature = "heapless")]
+use crate::fmt::HeaplessStringWriter;
+
+#[cfg(feature = "std")]
+use crate::fmt::StdStringWriter;
 
 use core::marker::PhantomData;
 
@@ -25,7 +31,9 @@ use core::marker::PhantomData;
 ///
 /// The [`Kind`](#associatedtype.Kind) and [`This`](#associatedtype.This) associated types
 /// are used in the [`IsAWriteMarker`] marker type
-/// to convert a `&mut StrWriter<_>` to a `StrWriterMut<'_>`,
+/// to convert a `&mut StrWriter<_>` to a `StrWriterMut<'_>`
+/// (and a `&mut heapless::String<N>` to a `HeaplessStringWriter<'_, N>`,
+/// or a `&mut String` to a `StdStringWriter<'_>`),
 /// and leave other mutable references unconverted.
 ///
 /// # Example
@@ -101,6 +109,9 @@ pub trait WriteMarker {
 
 /// Marker type for `StrWriter`'s [`Kind`] in [`WriteMarker`]s
 ///
+/// This is also the `Kind` of `heapless::String`, with the "heapless" feature,
+/// and of `String`, with the "std" feature.
+///
 /// [`Kind`]: ./trait.WriteMarker.html#associatedtype.Kind
 /// [`WriteMarker`]: ./trait.WriteMarker.html
 ///
@@ -130,6 +141,23 @@ impl WriteMarker for Formatter<'_> {
     type This = Self;
 }
 
+impl WriteMarker for ComputeStrLength {
+    type Kind = IsNotAStrWriter;
+    type This = Self;
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> WriteMarker for heapless::String<N> {
+    type Kind = IsAStrWriter;
+    type This = Self;
+}
+
+#[cfg(feature = "std")]
+impl WriteMarker for std::string::String {
+    type Kind = IsAStrWriter;
+    type This = Self;
+}
+
 impl<T> WriteMarker for &T
 where
     T: ?Sized + WriteMarker,
@@ -170,10 +198,23 @@ where
 /// The `coerce` method is what does the conversion from a `&mut T`
 /// depending on the `K` type parameter:
 ///
-/// - [`IsAStrWriter`]: the reference is converted into a `StrWriterMut<'_>`.
+/// - [`IsAStrWriter`]: the reference is converted into a `StrWriterMut<'_>`,
+/// or into a `HeaplessStringWriter<'_, N>` if it's a reference to a `heapless::String<N>`,
+/// or into a `StdStringWriter<'_>` if it's a reference to a `String`.
 ///
 /// - [`IsNotAStrWriter`]: the reference is simply returned unchanged.
-///  
+///
+/// # Reserve Methods
+///
+/// The `needs_reserve` method returns whether the coerced writer
+/// can only grow by having capacity reserved before it's written to,
+/// which the [`writec`] macro does by passing the length of the formatted string
+/// to the `reserve` method.
+///
+/// This is only true for `String`s.
+///
+/// [`writec`]: ../macro.writec.html
+///
 ///
 /// [`StrWriter`]: ../fmt/struct.StrWriter.html
 ///
@@ -226,6 +267,56 @@ impl<T: ?Sized, R: ?Sized> IsAWriteMarker<IsAStrWriter, StrWriter<T>, R> {
     pub const fn coerce(self, mutref: &mut StrWriter) -> StrWriterMut<'_> {
         mutref.as_mut()
     }
+
+    /// Returns false, `StrWriter`s have a fixed capacity.
+    #[inline(always)]
+    pub const fn needs_reserve(self) -> bool {
+        false
+    }
+
+    /// Does nothing.
+    #[inline(always)]
+    pub const fn reserve(self, _: &mut StrWriterMut<'_>, _additional: usize) {}
+}
+
+#[cfg(feature = "heapless")]
+impl<R: ?Sized, const N: usize> IsAWriteMarker<IsAStrWriter, heapless::String<N>, R> {
+    /// Converts the `&mut heapless::String<N>` to a `HeaplessStringWriter<'_, N>`.
+    #[inline(always)]
+    pub fn coerce(self, mutref: &mut heapless::String<N>) -> HeaplessStringWriter<'_, N> {
+        HeaplessStringWriter::new(mutref)
+    }
+
+    /// Returns false, `heapless::String`s have a fixed capacity.
+    #[inline(always)]
+    pub const fn needs_reserve(self) -> bool {
+        false
+    }
+
+    /// Does nothing.
+    #[inline(always)]
+    pub fn reserve(self, _: &mut HeaplessStringWriter<'_, N>, _additional: usize) {}
+}
+
+#[cfg(feature = "std")]
+impl<R: ?Sized> IsAWriteMarker<IsAStrWriter, std::string::String, R> {
+    /// Converts the `&mut String` to a `StdStringWriter<'_>`.
+    #[inline(always)]
+    pub fn coerce(self, mutref: &mut std::string::String) -> StdStringWriter<'_> {
+        StdStringWriter::new(mutref)
+    }
+
+    /// Returns true, `String`s need to reserve capacity before they're written to.
+    #[inline(always)]
+    pub const fn needs_reserve(self) -> bool {
+        true
+    }
+
+    /// Reserves capacity for at least `additional` more bytes in the `String`.
+    #[inline(always)]
+    pub fn reserve(self, writer: &mut StdStringWriter<'_>, additional: usize) {
+        writer.reserve(additional);
+    }
 }
 
 impl<T: ?Sized, R: ?Sized> IsAWriteMarker<IsNotAStrWriter, T, R> {
@@ -234,6 +325,43 @@ impl<T: ?Sized, R: ?Sized> IsAWriteMarker<IsNotAStrWriter, T, R> {
     pub const fn coerce(self, mutref: &mut T) -> &mut T {
         mutref
     }
+
+    /// Returns false.
+    #[inline(always)]
+    pub const fn needs_reserve(self) -> bool {
+        false
+    }
+
+    /// Does nothing.
+    #[inline(always)]
+    pub const fn reserve(self, _: &mut &mut T, _additional: usize) {}
 }
 
 /////////////////////////////////////////////////////////////////////////////
+
+/////////////////////////////////////////////////////////////////////////////
+
+/// Provides the `borrow_mutably` method for types that can't have inherent methods
+/// added by this crate, this trait is imported by the [`writec`] macro.
+///
+/// [`writec`]: ../macro.writec.html
+#[doc(hidden)]
+pub trait BorrowMutably {
+    fn borrow_mutably(&mut self) -> &mut Self;
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> BorrowMutably for heapless::String<N> {
+    #[inline(always)]
+    fn borrow_mutably(&mut self) -> &mut Self {
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl BorrowMutably for std::string::String {
+    #[inline(always)]
+    fn borrow_mutably(&mut self) -> &mut Self {
+        self
+    }
+}
@@ -2,7 +2,9 @@
 
 use crate::{
     char_encoding::FmtChar,
-    formatting::{Formatting, FormattingFlags},
+    duration_encoding::FmtDuration,
+    formatting::{Formatting, FormattingFlags, NumberFormatting, ScientificNotation},
+    ip_encoding::FmtIpAddr,
     wrapper_types::PWrapper,
 };
 
@@ -27,6 +29,163 @@ impl PArgument {
 
         sum
     }
+
+    /// The separator written between each group of three digits of this argument,
+    /// if it's a decimal integer.
+    pub const fn digit_grouping(&self) -> Option<FmtChar> {
+        match (&self.elem, self.fmt_flags.digit_grouping()) {
+            (PVariant::Int(_), Some(sep)) if is_decimal(self.fmt, self.fmt_flags) => {
+                Some(crate::char_encoding::char_to_display(sep))
+            }
+            _ => None,
+        }
+    }
+
+    /// This argument in scientific notation, if it's a decimal integer.
+    pub const fn scientific(&self) -> Option<Scientific> {
+        match (&self.elem, self.fmt_flags.scientific()) {
+            (PVariant::Int(int), Some(notation)) if is_decimal(self.fmt, self.fmt_flags) => {
+                Some(Scientific::new(*int, notation))
+            }
+            _ => None,
+        }
+    }
+}
+
+const fn is_decimal(fmt: Formatting, fmt_flags: FormattingFlags) -> bool {
+    match fmt {
+        Formatting::Display => true,
+        Formatting::Debug => matches!(fmt_flags.num_fmt(), NumberFormatting::Decimal),
+    }
+}
+
+/// Adds the length of the digit separators to `len`,
+/// the length of an integer formatted without them.
+const fn grouped_len(
+    len: usize,
+    is_negative: bool,
+    fmt: Formatting,
+    fmt_flags: FormattingFlags,
+) -> usize {
+    match fmt_flags.digit_grouping() {
+        Some(sep) if is_decimal(fmt, fmt_flags) => {
+            let digits = len - is_negative as usize;
+            let sep_len = crate::char_encoding::char_to_display(sep).len();
+            len + (digits - 1) / 3 * sep_len
+        }
+        _ => len,
+    }
+}
+
+/// The length of `int` after it's formatted according to `fmt_flags`,
+/// where `len` is its length without digit separators or scientific notation.
+const fn integer_len(
+    len: usize,
+    int: Integer,
+    fmt: Formatting,
+    fmt_flags: FormattingFlags,
+) -> usize {
+    match fmt_flags.scientific() {
+        Some(notation) if is_decimal(fmt, fmt_flags) => Scientific::new(int, notation).len(),
+        _ => grouped_len(len, int.is_negative, fmt, fmt_flags),
+    }
+}
+
+/// An integer in scientific notation,
+/// written as the digits of `mantissa` (with a decimal point after the first one if
+/// `has_point` is true), `added_zeros` zeros, an `e` or `E`, and then `exponent`.
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub struct Scientific {
+    pub is_negative: bool,
+    pub is_uppercase: bool,
+    pub mantissa: u128,
+    pub mantissa_len: usize,
+    pub has_point: bool,
+    pub added_zeros: usize,
+    pub exponent: usize,
+    pub exponent_len: usize,
+}
+
+impl Scientific {
+    /// Converts `int` to scientific notation,
+    /// rounding the mantissa the same way that the `LowerExp` impls of std integers do.
+    // `u128::is_multiple_of` is only usable in const fns since Rust 1.87.0
+    #[allow(clippy::manual_is_multiple_of)]
+    pub const fn new(int: Integer, notation: ScientificNotation) -> Self {
+        let mut mantissa = int.unsigned;
+        let mut exponent = 0;
+
+        while mantissa % 10 == 0 && mantissa >= 10 {
+            mantissa /= 10;
+            exponent += 1;
+        }
+
+        let mut added_zeros = 0;
+        let digits_after_point = decimal_len(mantissa) - 1;
+
+        match notation.precision() {
+            Some(precision) if precision >= digits_after_point => {
+                added_zeros = precision - digits_after_point;
+            }
+            Some(precision) => {
+                let removed = digits_after_point - precision;
+
+                let mut i = 1;
+                while i < removed {
+                    mantissa /= 10;
+                    exponent += 1;
+                    i += 1;
+                }
+                let last_removed = mantissa % 10;
+                mantissa /= 10;
+                exponent += 1;
+
+                // Ties round to even, a tie is only possible when exactly one digit was removed,
+                // because the mantissa doesn't end with a `0`.
+                if last_removed > 5 || (last_removed == 5 && (mantissa % 2 != 0 || removed > 1)) {
+                    mantissa += 1;
+
+                    // Rounding carried into a new digit (eg: `9.99` => `10.0`),
+                    // which is moved to the exponent (eg: `1.00e1`).
+                    if decimal_len(mantissa) > precision + 1 {
+                        mantissa /= 10;
+                        exponent += 1;
+                    }
+                }
+            }
+            None => {}
+        }
+
+        let mantissa_len = decimal_len(mantissa);
+        let exponent = exponent + mantissa_len - 1;
+
+        Self {
+            is_negative: int.is_negative,
+            is_uppercase: notation.is_uppercase(),
+            mantissa,
+            mantissa_len,
+            has_point: mantissa_len > 1 || added_zeros != 0,
+            added_zeros,
+            exponent,
+            exponent_len: decimal_len(exponent as u128),
+        }
+    }
+
+    /// The length of this integer in scientific notation.
+    pub const fn len(&self) -> usize {
+        self.is_negative as usize
+            + self.mantissa_len
+            + self.has_point as usize
+            + self.added_zeros
+            + 1
+            + self.exponent_len
+    }
+}
+
+/// The amount of decimal digits in `n`.
+const fn decimal_len(n: u128) -> usize {
+    PWrapper(n).compute_display_len(FormattingFlags::NEW)
 }
 
 #[doc(hidden)]
@@ -34,6 +193,8 @@ pub enum PVariant {
     Str(&'static str),
     Int(Integer),
     Char(FmtChar),
+    Ip(FmtIpAddr),
+    Duration(FmtDuration),
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -91,11 +252,13 @@ macro_rules! pconvwrapper_impls {
             #[doc(hidden)]
             impl PConvWrapper<$Signed> {
                 pub const fn $method(self, fmt_flags: FormattingFlags)->PArgument{
+                    let len = $crate::pmr::PWrapper(self.0).$called(fmt_flags);
+                    let elem = self.to_integer();
                     PArgument {
-                        fmt_len: $crate::pmr::PWrapper(self.0).$called(fmt_flags),
+                        fmt_len: integer_len(len, elem, $formatting, fmt_flags),
                         fmt: $formatting,
                         fmt_flags,
-                        elem: PVariant::Int(self.to_integer()),
+                        elem: PVariant::Int(elem),
                     }
                 }
             }
@@ -103,11 +266,13 @@ macro_rules! pconvwrapper_impls {
             #[doc(hidden)]
             impl PConvWrapper<$Unsigned> {
                 pub const fn $method(self, fmt_flags: FormattingFlags)->PArgument{
+                    let len = $crate::pmr::PWrapper(self.0).$called(fmt_flags);
+                    let elem = self.to_integer();
                     PArgument {
-                        fmt_len: $crate::pmr::PWrapper(self.0).$called(fmt_flags),
+                        fmt_len: integer_len(len, elem, $formatting, fmt_flags),
                         fmt: $formatting,
                         fmt_flags,
-                        elem: PVariant::Int(self.to_integer()),
+                        elem: PVariant::Int(elem),
                     }
                 }
             }
@@ -173,6 +338,53 @@ impl PConvWrapper<char> {
     }
 }
 
+macro_rules! pconvwrapper_ip_impls {
+    ( $( ($Ip:ty, $to_display:ident) )* ) => (
+        $(
+            #[doc(hidden)]
+            #[cfg(feature = "net")]
+            impl PConvWrapper<$Ip> {
+                #[inline]
+                pub const fn to_pargument_display(self, fmt_flags: FormattingFlags) -> PArgument {
+                    let elem = crate::ip_encoding::$to_display(self.0);
+                    PArgument {
+                        fmt_len: elem.len(),
+                        fmt_flags,
+                        fmt: Formatting::Display,
+                        elem: PVariant::Ip(elem),
+                    }
+                }
+                #[inline]
+                pub const fn to_pargument_debug(self, fmt_flags: FormattingFlags) -> PArgument {
+                    self.to_pargument_display(fmt_flags)
+                }
+            }
+        )*
+    );
+}
+
+pconvwrapper_ip_impls! {
+    (core::net::Ipv4Addr, ipv4_to_display)
+    (core::net::Ipv6Addr, ipv6_to_display)
+    (core::net::IpAddr, ip_to_display)
+}
+
+// There's no `to_pargument_display` method,
+// because `Duration` doesn't implement `Display` in the standard library.
+#[doc(hidden)]
+impl PConvWrapper<core::time::Duration> {
+    #[inline]
+    pub const fn to_pargument_debug(self, fmt_flags: FormattingFlags) -> PArgument {
+        let elem = crate::duration_encoding::duration_to_debug(self.0);
+        PArgument {
+            fmt_len: elem.len(),
+            fmt_flags,
+            fmt: Formatting::Debug,
+            elem: PVariant::Duration(elem),
+        }
+    }
+}
+
 #[doc(hidden)]
 impl PConvWrapper<&'static str> {
     #[inline]
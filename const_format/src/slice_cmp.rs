@@ -1,3 +1,5 @@
+use core::cmp::Ordering;
+
 /// A const equivalent of `&str` equality comparison.
 ///
 /// # Example
@@ -88,6 +90,55 @@ pub const fn u8_slice_eq(left: &[u8], right: &[u8]) -> bool {
     true
 }
 
+/// A const equivalent of `&str` ordering comparison, `left.cmp(right)`.
+///
+/// Strings are compared lexicographically by their bytes,
+/// a string that's a prefix of another is ordered before it.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::utils::compare_str;
+///
+/// use std::cmp::Ordering;
+///
+/// const CMP_0_1: Ordering = compare_str("bar", "baz");
+/// const CMP_1_2: Ordering = compare_str("baz", "ba");
+/// const CMP_2_2: Ordering = compare_str("ba", "ba");
+///
+/// assert_eq!(CMP_0_1, Ordering::Less);
+/// assert_eq!(CMP_1_2, Ordering::Greater);
+/// assert_eq!(CMP_2_2, Ordering::Equal);
+///
+/// ```
+///
+pub const fn compare_str(left: &str, right: &str) -> Ordering {
+    u8_slice_cmp(left.as_bytes(), right.as_bytes())
+}
+
+/// A const equivalent of `&[u8]` ordering comparison, `left.cmp(right)`.
+pub(crate) const fn u8_slice_cmp(left: &[u8], right: &[u8]) -> Ordering {
+    let mut i = 0;
+    while i != left.len() && i != right.len() {
+        if left[i] != right[i] {
+            return if left[i] < right[i] {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+        i += 1;
+    }
+
+    if left.len() < right.len() {
+        Ordering::Less
+    } else if left.len() > right.len() {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,4 +171,31 @@ mod tests {
         assert!(str_eq("0, 1", "0, 1"));
         assert!(!str_eq("0, 1", "0, 2"));
     }
+
+    #[test]
+    fn compare_str_test() {
+        let strs = [
+            "", "\0", "a", "ab", "abc", "abd", "ac", "b", "B", "ñ", "ña", "鉄", "😀", "\u{7F}",
+        ];
+
+        for &left in &strs {
+            for &right in &strs {
+                assert_eq!(
+                    compare_str(left, right),
+                    left.cmp(right),
+                    "{:?} {:?}",
+                    left,
+                    right
+                );
+            }
+        }
+
+        // prefixes
+        assert_eq!(compare_str("ab", "abc"), Ordering::Less);
+        assert_eq!(compare_str("abc", "ab"), Ordering::Greater);
+        assert_eq!(compare_str("", "a"), Ordering::Less);
+        assert_eq!(compare_str("a", ""), Ordering::Greater);
+        assert_eq!(compare_str("", ""), Ordering::Equal);
+        assert_eq!(compare_str("abc", "abc"), Ordering::Equal);
+    }
 }
@@ -1,13 +1,115 @@
 //! Miscelaneous functions.
+//!
+//! # Features
+//!
+//! The UTF-8 functions ([`count_chars`], [`is_char_boundary`], and [`next_char_boundary`])
+//! and [`compare_str`] are always available,
+//! the rest of this module requires the "fmt" feature.
 
+#[cfg(feature = "fmt")]
 use core::ops::Range;
 
 /// Newtype wrapper to get around limitations in `const fn`s
+#[cfg(feature = "fmt")]
 pub(crate) struct Constructor<T>(fn() -> T);
 
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "fmt")))]
+#[cfg(feature = "fmt")]
 pub use crate::slice_cmp::{str_eq, u8_slice_eq};
 
+pub use crate::slice_cmp::compare_str;
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Counts the `char`s in `s`, a const equivalent of `s.chars().count()`.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::utils::count_chars;
+///
+/// const COUNT: usize = count_chars("añ€😀");
+///
+/// assert_eq!(COUNT, 4);
+/// assert_eq!("añ€😀".len(), 10);
+///
+/// ```
+pub const fn count_chars(s: &str) -> usize {
+    let mut count = 0;
+    iter_copy_slice! {b in s.as_bytes() =>
+        // not a continuation byte
+        if (b as i8) >= -0x40 {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Whether `index` is the start of a `char` in `s`, or the end of the string,
+/// a const equivalent of [`str::is_char_boundary`].
+///
+/// This returns `false` for indices larger than `s.len()`.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::utils::is_char_boundary;
+///
+/// const S: &str = "añ€";
+///
+/// assert!(is_char_boundary(S, 0));
+/// assert!(is_char_boundary(S, 1));
+/// assert!(!is_char_boundary(S, 2));
+/// assert!(is_char_boundary(S, 3));
+/// assert!(!is_char_boundary(S, 4));
+/// assert!(!is_char_boundary(S, 5));
+/// assert!(is_char_boundary(S, 6));
+/// assert!(!is_char_boundary(S, 7));
+///
+/// ```
+///
+/// [`str::is_char_boundary`]: https://doc.rust-lang.org/std/primitive.str.html#method.is_char_boundary
+pub const fn is_char_boundary(s: &str, index: usize) -> bool {
+    index <= s.len() && crate::__hidden_utils::is_char_boundary_no_len_check(s.as_bytes(), index)
+}
+
+/// Returns the first char boundary after `index` in `s`,
+/// returning `s.len()` if `index >= s.len()`.
+///
+/// If `index` is the start of a `char`, this returns the end of that `char`.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::utils::next_char_boundary;
+///
+/// const S: &str = "añ€";
+///
+/// assert_eq!(next_char_boundary(S, 0), 1);
+/// assert_eq!(next_char_boundary(S, 1), 3);
+/// assert_eq!(next_char_boundary(S, 2), 3);
+/// assert_eq!(next_char_boundary(S, 3), 6);
+/// assert_eq!(next_char_boundary(S, 6), 6);
+/// assert_eq!(next_char_boundary(S, 100), 6);
+///
+/// ```
+pub const fn next_char_boundary(s: &str, index: usize) -> usize {
+    let bytes = s.as_bytes();
+    if index >= bytes.len() {
+        return bytes.len();
+    }
+
+    let mut index = index + 1;
+    while !crate::__hidden_utils::is_char_boundary_no_len_check(bytes, index) {
+        index += 1;
+    }
+    index
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 #[doc(hidden)]
+#[cfg(feature = "fmt")]
 #[inline]
 pub const fn saturate_range(s: &[u8], range: &Range<usize>) -> Range<usize> {
     let len = s.len();
@@ -61,6 +163,7 @@ macro_rules! slice_up_to_len_alt_docs {
 }
 
 slice_up_to_len_alt_docs! {
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "fmt")))]
     #[cfg(feature = "constant_time_as_str")]
     #[inline(always)]
     pub const fn slice_up_to_len_alt<T>(slice: &[T], len: usize) -> &[T] {
@@ -68,6 +171,8 @@ slice_up_to_len_alt_docs! {
     }
 }
 slice_up_to_len_alt_docs! {
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "fmt")))]
+    #[cfg(feature = "fmt")]
     #[cfg(not(feature = "constant_time_as_str"))]
     pub const fn slice_up_to_len_alt<T>(slice: &[T], len: usize) -> &[T] {
         let mut rem = slice.len().saturating_add(1).saturating_sub(len);
@@ -136,6 +241,7 @@ macro_rules! slice_up_to_len_docs {
 }
 
 slice_up_to_len_docs! {
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "fmt")))]
     #[cfg(feature = "constant_time_as_str")]
     #[inline]
     pub const fn slice_up_to_len<T>(slice: &[T], len: usize) -> &[T] {
@@ -152,6 +258,8 @@ slice_up_to_len_docs! {
 }
 
 slice_up_to_len_docs! {
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "fmt")))]
+    #[cfg(feature = "fmt")]
     #[cfg(not(feature = "constant_time_as_str"))]
     #[inline]
     pub fn slice_up_to_len<T>(slice: &[T], len: usize) -> &[T] {
@@ -165,6 +273,7 @@ slice_up_to_len_docs! {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+#[cfg(feature = "fmt")]
 pub(crate) const fn min_usize(l: usize, r: usize) -> usize {
     if l < r {
         l
@@ -177,6 +286,7 @@ pub(crate) const fn min_usize(l: usize, r: usize) -> usize {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "fmt")]
     #[test]
     fn test_slice_up_to_len_alt() {
         let mut list = [0u16; 256];
@@ -188,6 +298,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "fmt")]
     #[test]
     fn slice_in_bounds() {
         assert_eq!(slice_up_to_len(&[3, 5], 0), []);
@@ -196,4 +307,54 @@ mod tests {
         assert_eq!(slice_up_to_len(&[3, 5], 3), [3, 5]);
         assert_eq!(slice_up_to_len(&[3, 5], 4), [3, 5]);
     }
+
+    const UTF8_STRS: &[&str] = &[
+        "",
+        "a",
+        "hello",
+        "ñ",
+        "añb",
+        "€",
+        "a€b€",
+        "😀",
+        "a😀b",
+        "ñ€😀a",
+        "\u{7F}\u{80}\u{7FF}\u{800}\u{FFFF}\u{10000}\u{10FFFF}",
+        "鉄錆 效率的",
+    ];
+
+    #[test]
+    fn test_count_chars() {
+        for s in UTF8_STRS {
+            assert_eq!(count_chars(s), s.chars().count(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn test_is_char_boundary() {
+        for s in UTF8_STRS {
+            for i in 0..s.len() + 3 {
+                assert_eq!(
+                    is_char_boundary(s, i),
+                    s.is_char_boundary(i),
+                    "{:?} {}",
+                    s,
+                    i
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_char_boundary() {
+        for s in UTF8_STRS {
+            for i in 0..s.len() + 3 {
+                let expected = (i + 1..=s.len())
+                    .find(|&j| s.is_char_boundary(j))
+                    .unwrap_or(s.len());
+
+                assert_eq!(next_char_boundary(s, i), expected, "{:?} {}", s, i);
+            }
+        }
+    }
 }
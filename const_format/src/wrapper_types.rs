@@ -12,12 +12,15 @@ pub(crate) mod pwrapper;
 #[cfg(feature = "fmt")]
 pub(crate) mod sliced;
 
+#[cfg(feature = "fmt")]
+pub(crate) mod utf8_lossy;
+
 #[cfg(feature = "fmt")]
 pub use self::ascii_str::NotAsciiError;
 
 #[doc(no_inline)]
 #[cfg(feature = "fmt")]
-pub use crate::{AsciiStr, Sliced};
+pub use crate::{AsciiStr, Sliced, Utf8Lossy};
 
 #[doc(no_inline)]
 pub use crate::PWrapper;
@@ -12,6 +12,11 @@ use core::fmt::{self, Display};
 /// You can also construct an `AsciiStr` at compile-time with the [`ascii_str`] macro,
 /// erroring at compile if the constant isn't ascii.
 ///
+/// The string is validated once on construction,
+/// so that the `write_ascii*` methods of [`StrWriterMut`] and [`Formatter`]
+/// don't need to check that their range is on `char` boundaries,
+/// unlike the equivalent `write_str*` methods.
+///
 /// # Example
 ///
 /// ```rust
@@ -35,6 +40,8 @@ use core::fmt::{self, Display};
 /// ```
 ///
 /// [`ascii_str`]: ./macro.ascii_str.html
+/// [`StrWriterMut`]: ../fmt/struct.StrWriterMut.html
+/// [`Formatter`]: ../fmt/struct.Formatter.html
 ///
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "fmt")))]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
@@ -0,0 +1,199 @@
+use crate::fmt::{Error, Formatter};
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Wrapper for writing a byte slice as lossily decoded UTF-8.
+///
+/// The valid UTF-8 sequences in the slice are written as they are,
+/// and each invalid sequence is replaced with a single
+/// [`U+FFFD REPLACEMENT CHARACTER`](https://doc.rust-lang.org/std/char/constant.REPLACEMENT_CHARACTER.html)
+/// (`'�'`).
+/// Invalid sequences are determined the same way that
+/// [`String::from_utf8_lossy`] does it,
+/// where a truncated multi-byte sequence is replaced with a single `'�'`,
+/// and every other invalid byte is replaced with its own `'�'`.
+///
+/// With the alternate flag (eg: `{:#}`), each byte of the invalid sequences
+/// is written as an uppercase `\xXX` escape instead,
+/// like the `Debug` impl of `core::str::Utf8Chunks` does.
+///
+/// This type only implements Display formatting,
+/// `&[u8]`s can be Debug formatted on their own to print the numeric value of the bytes.
+///
+/// To write a `&CStr`, you can wrap the bytes that it returns from its `to_bytes` method.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(const_mut_refs)]
+///
+/// use const_format::Utf8Lossy;
+/// use const_format::{concatc, formatc};
+///
+/// const VALID: &[u8] = "hello wörld".as_bytes();
+/// const INVALID: &[u8] = b"foo\xFFbar\xE2\x82";
+///
+/// assert_eq!(concatc!(Utf8Lossy(VALID)), "hello wörld");
+/// assert_eq!(formatc!("{}", Utf8Lossy(INVALID)), "foo�bar�");
+/// assert_eq!(formatc!("{:#}", Utf8Lossy(INVALID)), r"foo\xFFbar\xE2\x82");
+///
+/// ```
+///
+/// [`String::from_utf8_lossy`]:
+/// https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf8_lossy
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "fmt")))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Utf8Lossy<'a>(pub &'a [u8]);
+
+impl_fmt! {
+    impl['a,] Utf8Lossy<'a>;
+
+    /// Writes the bytes as lossily decoded UTF-8,
+    /// escaping the invalid bytes if the alternate flag is set.
+    pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let bytes = self.0;
+        let mut run_start = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let (is_valid, len) = utf8_sequence(bytes, i);
+            if !is_valid {
+                try_!(f.write_str(valid_utf8_range(bytes, run_start, i)));
+                if f.flags().is_alternate() {
+                    __for_range! {j in i..i + len =>
+                        try_!(f.write_str("\\x"));
+                        try_!(f.write_ascii_repeated(HEX_DIGITS[(bytes[j] >> 4) as usize], 1));
+                        try_!(f.write_ascii_repeated(HEX_DIGITS[(bytes[j] & 0xF) as usize], 1));
+                    }
+                } else {
+                    try_!(f.write_str("\u{FFFD}"));
+                }
+                run_start = i + len;
+            }
+            i += len;
+        }
+
+        f.write_str(valid_utf8_range(bytes, run_start, bytes.len()))
+    }
+}
+
+/// Whether `bytes[i..]` starts with a valid UTF-8 sequence,
+/// and the length of the sequence (or the invalid prefix of it).
+const fn utf8_sequence(bytes: &[u8], i: usize) -> (bool, usize) {
+    // The range of the second byte, which is narrower for some leading bytes
+    // to reject overlong encodings, surrogates, and chars past `char::MAX`.
+    let (len, second_lo, second_hi) = match bytes[i] {
+        0x00..=0x7F => return (true, 1),
+        0xC2..=0xDF => (2, 0x80, 0xBF),
+        0xE0 => (3, 0xA0, 0xBF),
+        0xE1..=0xEC | 0xEE..=0xEF => (3, 0x80, 0xBF),
+        0xED => (3, 0x80, 0x9F),
+        0xF0 => (4, 0x90, 0xBF),
+        0xF1..=0xF3 => (4, 0x80, 0xBF),
+        0xF4 => (4, 0x80, 0x8F),
+        _ => return (false, 1),
+    };
+
+    let mut j = 1;
+    while j < len {
+        let (lo, hi) = if j == 1 {
+            (second_lo, second_hi)
+        } else {
+            (0x80, 0xBF)
+        };
+        if i + j >= bytes.len() || bytes[i + j] < lo || bytes[i + j] > hi {
+            return (false, j);
+        }
+        j += 1;
+    }
+    (true, len)
+}
+
+/// Gets `&bytes[start..end]` as a `&str`,
+/// `start..end` must be a valid UTF-8 range of `bytes`.
+const fn valid_utf8_range(bytes: &[u8], start: usize, end: usize) -> &str {
+    // safety: the caller ensures that `start..end` is in bounds and is valid UTF-8
+    unsafe {
+        let range = core::slice::from_raw_parts(bytes.as_ptr().add(start), end - start);
+        core::str::from_utf8_unchecked(range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::fmt::{FormattingFlags, StrWriter};
+
+    fn assert_display(bytes: &[u8], expected: &str) {
+        assert_display_with(FormattingFlags::NEW, bytes, expected);
+    }
+
+    fn assert_alternate(bytes: &[u8], expected: &str) {
+        assert_display_with(FormattingFlags::NEW.set_alternate(true), bytes, expected);
+    }
+
+    fn assert_display_with(flags: FormattingFlags, bytes: &[u8], expected: &str) {
+        let writer: &mut StrWriter = &mut StrWriter::new([0u8; 256]);
+        Utf8Lossy(bytes)
+            .const_display_fmt(&mut writer.make_formatter(flags))
+            .unwrap();
+        assert_eq!(writer.as_str(), expected, "{:?}", bytes);
+    }
+
+    #[test]
+    fn valid_utf8() {
+        for s in ["", "a", "hello", "wörld", "効率", "\u{10FFFF}🦀", "\0\x7F"] {
+            assert_display(s.as_bytes(), s);
+        }
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        assert_display(b"\xFF", "�");
+        assert_display(b"foo\xFFbar", "foo�bar");
+        assert_display(b"\x80\x80", "��");
+        // overlong encoding
+        assert_display(b"\xC0\xAF", "��");
+        assert_display(b"\xE0\x80\x80", "���");
+        // surrogate
+        assert_display(b"\xED\xA0\x80", "���");
+        // past `char::MAX`
+        assert_display(b"\xF4\x90\x80\x80", "����");
+        assert_display(b"\xF5abc", "�abc");
+        // truncated sequences
+        assert_display(b"\xE2\x82", "�");
+        assert_display(b"\xE2\x82foo", "�foo");
+        assert_display(b"\xF0\x9F\xA6", "�");
+        assert_display(b"a\xF0\x9F\xA6\x80b\xF0\x9Fc", "a🦀b�c");
+    }
+
+    #[test]
+    fn escaped_invalid_utf8() {
+        for s in ["", "hello", "wörld", "\u{10FFFF}🦀"] {
+            assert_alternate(s.as_bytes(), s);
+        }
+
+        assert_alternate(b"\xFF", r"\xFF");
+        assert_alternate(b"foo\x80bar", r"foo\x80bar");
+        assert_alternate(b"\xC0\xAF", r"\xC0\xAF");
+        assert_alternate(b"\xED\xA0\x80", r"\xED\xA0\x80");
+        assert_alternate(b"\xE2\x82foo", r"\xE2\x82foo");
+        assert_alternate(b"a\xF0\x9F\xA6\x80b\xF0\x9Fc", r"a🦀b\xF0\x9Fc");
+    }
+
+    #[test]
+    fn derived_traits() {
+        use arrayvec::ArrayString;
+        use core::fmt::Write;
+
+        let lossy = Utf8Lossy(b"a\xFF");
+
+        let mut string = ArrayString::<[u8; 64]>::new();
+        write!(string, "{:?}", lossy).unwrap();
+        assert_eq!(string.as_str(), "Utf8Lossy([97, 255])");
+
+        assert_eq!(lossy, Utf8Lossy(b"a\xFF"));
+        assert_ne!(lossy, Utf8Lossy(b"a"));
+    }
+}
@@ -0,0 +1,50 @@
+use cfmt_a::Error;
+
+#[test]
+fn display_and_debug() {
+    let cases = [
+        (
+            Error::NotEnoughSpace,
+            "NotEnoughSpace",
+            "There was not enough space in the buffer to write the formatted output",
+        ),
+        (
+            Error::NotAscii,
+            "NotAscii",
+            "Attempted to write non-ascii text",
+        ),
+        (
+            Error::NotOnCharBoundary,
+            "NotOnCharBoundary",
+            "Attempted to index a byte that's not on a char boundary.",
+        ),
+    ];
+
+    for (err, debug, display) in cases.iter() {
+        assert_eq!(format!("{:?}", err), *debug);
+        assert_eq!(format!("{}", err), *display);
+    }
+}
+
+#[test]
+#[cfg(feature = "core_error")]
+fn error_trait() {
+    use cfmt_a::StrWriter;
+
+    fn write_into(writer: &mut StrWriter<[u8]>) -> Result<(), Box<dyn std::error::Error>> {
+        writer.as_mut().write_str("hello")?;
+        Ok(())
+    }
+
+    let writer: &mut StrWriter<[u8]> = &mut StrWriter::new([0; 8]);
+    write_into(writer).unwrap();
+    assert_eq!(writer.as_str(), "hello");
+
+    let err = write_into(writer).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "There was not enough space in the buffer to write the formatted output",
+    );
+    assert_eq!(err.downcast_ref::<Error>(), Some(&Error::NotEnoughSpace));
+    assert_eq!(writer.as_str(), "hello");
+}
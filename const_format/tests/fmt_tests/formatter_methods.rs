@@ -1,7 +1,7 @@
 use cfmt_a::{
     ascii_str,
-    fmt::{ComputeStrLength, Formatter, FormattingFlags},
-    AsciiStr,
+    fmt::{Alignment, ComputeStrLength, Error, Formatter, FormattingFlags},
+    try_, AsciiStr,
 };
 
 mod debug_methods;
@@ -115,6 +115,80 @@ fn write_integers() {
     }
 }
 
+#[test]
+fn write_int_generic() {
+    fn inner(mut outer: Formatter<'_>) {
+        // The flags passed to `write_int` override the ones of the Formatter
+        let flags = outer.flags();
+        let mut fmt =
+            outer.make_formatter(FormattingFlags::NEW.set_alternate(!flags.is_alternate()));
+
+        append_str!(fmt,",";
+            fmt.write_int(17_u8, flags).unwrap();
+            fmt.write_int(18_u16, flags).unwrap();
+            fmt.write_int(19_u32, flags).unwrap();
+            fmt.write_int(20_u64, flags).unwrap();
+            fmt.write_int(21_u128, flags).unwrap();
+            fmt.write_int(22_usize, flags).unwrap();
+            fmt.write_int(-23_i8, flags).unwrap();
+            fmt.write_int(-24_i16, flags).unwrap();
+            fmt.write_int(-25_i32, flags).unwrap();
+            fmt.write_int(-26_i64, flags).unwrap();
+            fmt.write_int(-27_i128, flags).unwrap();
+            fmt.write_int(-28_isize, flags).unwrap();
+            fmt.write_int(u128::MAX, flags).unwrap();
+            fmt.write_int(i64::MIN, flags).unwrap();
+        );
+    }
+
+    let all_flags = [
+        FormattingFlags::NEW,
+        FormattingFlags::NEW.set_hexadecimal(),
+        FormattingFlags::NEW.set_binary(),
+        FormattingFlags::NEW.set_alternate(true).set_hexadecimal(),
+        FormattingFlags::NEW.set_alternate(true).set_binary(),
+    ];
+
+    for &flags in all_flags.iter() {
+        let mut expected = String::new();
+        {
+            let mut buffer = [0u8; 4096];
+            let mut len = 0;
+            let mut fmt = Formatter::from_custom(&mut buffer, &mut len, flags);
+            append_str!(fmt,",";
+                fmt.write_u8_debug(17_u8).unwrap();
+                fmt.write_u16_debug(18_u16).unwrap();
+                fmt.write_u32_debug(19_u32).unwrap();
+                fmt.write_u64_debug(20_u64).unwrap();
+                fmt.write_u128_debug(21_u128).unwrap();
+                fmt.write_usize_debug(22_usize).unwrap();
+                fmt.write_i8_debug(-23_i8).unwrap();
+                fmt.write_i16_debug(-24_i16).unwrap();
+                fmt.write_i32_debug(-25_i32).unwrap();
+                fmt.write_i64_debug(-26_i64).unwrap();
+                fmt.write_i128_debug(-27_i128).unwrap();
+                fmt.write_isize_debug(-28_isize).unwrap();
+                fmt.write_u128_debug(u128::MAX).unwrap();
+                fmt.write_i64_debug(i64::MIN).unwrap();
+            );
+            expected.push_str(std::str::from_utf8(&buffer[..len]).unwrap());
+        }
+
+        write_with_flag(flags, &expected, &inner);
+    }
+
+    write_with_flag(
+        FormattingFlags::NEW.set_hexadecimal(),
+        &format!(
+            "11,12,13,14,15,16,E9,FFE8,FFFFFFE7,FFFFFFFFFFFFFFE6,{:X},{:X},{:X},8000000000000000,",
+            -27_i128,
+            -28_isize,
+            u128::MAX,
+        ),
+        &inner,
+    );
+}
+
 #[test]
 fn write_str_methods() {
     const A_FOO: AsciiStr = ascii_str!("hello\n\x1F bar\t\rbaz");
@@ -143,6 +217,372 @@ fn write_str_methods() {
     write_with_flag(FormattingFlags::NEW, expected, &inner);
 }
 
+#[test]
+fn write_char() {
+    fn inner(mut fmt: Formatter<'_>) {
+        append_str!(fmt,";";
+            fmt.write_char('a').unwrap();
+            fmt.write_char('\0').unwrap();
+            fmt.write_char('\n').unwrap();
+            fmt.write_char('ñ').unwrap();
+            fmt.write_char('鉄').unwrap();
+            fmt.write_char('😀').unwrap();
+            fmt.write_char(char::MAX).unwrap();
+        );
+    }
+
+    write_with_flag(FormattingFlags::NEW, "a;\0;\n;ñ;鉄;😀;\u{10FFFF};", &inner);
+}
+
+#[test]
+fn write_char_not_enough_space() {
+    use cfmt_a::fmt::Error;
+
+    for &c in &['a', 'ñ', '鉄', '😀'] {
+        let c_len = c.len_utf8();
+        for &prefix_len in &[0, 3, 7] {
+            let mut buffer = [0u8; 12];
+            let buffer = &mut buffer[..prefix_len + c_len];
+            let mut len = 0;
+            let mut fmt = Formatter::from_custom(buffer, &mut len, FormattingFlags::NEW);
+
+            fmt.write_ascii_repeated(b'-', prefix_len).unwrap();
+            fmt.write_char(c).unwrap();
+            assert!(matches!(fmt.write_char(c), Err(Error::NotEnoughSpace)));
+            assert!(matches!(fmt.write_char('a'), Err(Error::NotEnoughSpace)));
+
+            let mut expected = "-".repeat(prefix_len);
+            expected.push(c);
+            assert_eq!(std::str::from_utf8(&buffer[..len]).unwrap(), expected);
+        }
+
+        // There's space for all but the last byte of the char,
+        // nothing must be written in that case.
+        let mut buffer = [0u8; 12];
+        let buffer = &mut buffer[..c_len + 1];
+        let mut len = 0;
+        let mut fmt = Formatter::from_custom(buffer, &mut len, FormattingFlags::NEW);
+
+        fmt.write_ascii_repeated(b'-', 2).unwrap();
+        assert!(matches!(fmt.write_char(c), Err(Error::NotEnoughSpace)));
+        assert_eq!(len, 2);
+        assert!(buffer[2..].iter().all(|&b| b == 0));
+    }
+}
+
+#[test]
+fn write_str_aligned() {
+    fn inner(mut fmt: Formatter<'_>) {
+        append_str!(fmt,";";
+            fmt.write_str_aligned("foo", 6, Alignment::Left, ' ').unwrap();
+            fmt.write_str_aligned("foo", 6, Alignment::Center, ' ').unwrap();
+            fmt.write_str_aligned("foo", 7, Alignment::Center, '-').unwrap();
+            fmt.write_str_aligned("foo", 6, Alignment::Right, ' ').unwrap();
+            fmt.write_str_aligned("", 3, Alignment::Right, '_').unwrap();
+            fmt.write_str_aligned("foo", 3, Alignment::Left, '_').unwrap();
+            fmt.write_str_aligned("foo", 3, Alignment::Center, '_').unwrap();
+            fmt.write_str_aligned("foo", 0, Alignment::Right, '_').unwrap();
+            fmt.write_str_aligned("foobar", 2, Alignment::Left, '_').unwrap();
+            fmt.write_str_aligned("foobar", 2, Alignment::Center, '_').unwrap();
+            fmt.write_str_aligned("foobar", 2, Alignment::Right, '_').unwrap();
+            fmt.write_str_aligned("鉄錆", 4, Alignment::Left, 'ñ').unwrap();
+            fmt.write_str_aligned("鉄錆", 5, Alignment::Center, '效').unwrap();
+            fmt.write_str_aligned("鉄錆", 3, Alignment::Right, '😀').unwrap();
+        );
+    }
+
+    let expected = "\
+        foo   ; foo  ;--foo--;   foo;___;foo;foo;foo;\
+        foobar;foobar;foobar;鉄錆ññ;效鉄錆效效;😀鉄錆;\
+    ";
+
+    let mut std_expected = String::new();
+    for arg in [
+        format!("{:<6}", "foo"),
+        format!("{:^6}", "foo"),
+        format!("{:-^7}", "foo"),
+        format!("{:>6}", "foo"),
+        format!("{:_>3}", ""),
+        format!("{:_<3}", "foo"),
+        format!("{:_^3}", "foo"),
+        format!("{:_>0}", "foo"),
+        format!("{:_<2}", "foobar"),
+        format!("{:_^2}", "foobar"),
+        format!("{:_>2}", "foobar"),
+        format!("{:ñ<4}", "鉄錆"),
+        format!("{:效^5}", "鉄錆"),
+        format!("{:😀>3}", "鉄錆"),
+    ]
+    .iter()
+    {
+        std_expected.push_str(arg);
+        std_expected.push(';');
+    }
+    assert_eq!(expected, std_expected);
+
+    write_with_flag(FormattingFlags::NEW, expected, &inner);
+}
+
+#[test]
+fn write_str_aligned_not_enough_space() {
+    use cfmt_a::fmt::Error;
+
+    // (string, width, fill, bytes of space)
+    for &(string, width, fill, space) in &[
+        ("foo", 6, ' ', 5),
+        ("foo", 2, ' ', 2),
+        ("鉄", 3, '😀', 10),
+        ("鉄錆", 2, '-', 5),
+    ] {
+        for &align in &[Alignment::Left, Alignment::Center, Alignment::Right] {
+            let mut buffer = [0u8; 16];
+            let buffer = &mut buffer[..space + 1];
+            let mut len = 0;
+            let mut fmt = Formatter::from_custom(buffer, &mut len, FormattingFlags::NEW);
+
+            fmt.write_str("|").unwrap();
+            assert!(matches!(
+                fmt.write_str_aligned(string, width, align, fill),
+                Err(Error::NotEnoughSpace)
+            ));
+            assert_eq!(len, 1);
+            assert!(buffer[1..].iter().all(|&b| b == 0));
+        }
+    }
+}
+
+#[test]
+fn write_str_aligned_huge_width() {
+    use cfmt_a::fmt::{ComputeStrLength, Error};
+
+    for &(string, width, fill) in &[
+        ("foo", usize::MAX, ' '),
+        ("foo", usize::MAX, '😀'),
+        ("", usize::MAX / 2 + 1, 'ñ'),
+        ("鉄錆", usize::MAX / 4 + 2, '😀'),
+    ] {
+        for &align in &[Alignment::Left, Alignment::Center, Alignment::Right] {
+            let mut buffer = [0u8; 16];
+            let mut len = 0;
+            let mut fmt = Formatter::from_custom(&mut buffer, &mut len, FormattingFlags::NEW);
+            assert!(matches!(
+                fmt.write_str_aligned(string, width, align, fill),
+                Err(Error::NotEnoughSpace)
+            ));
+            assert_eq!(len, 0);
+
+            // The length of the padded string overflows a usize
+            if fill.len_utf8() != 1 {
+                let mut computer = ComputeStrLength::new();
+                assert!(matches!(
+                    computer
+                        .make_formatter(FormattingFlags::NEW)
+                        .write_str_aligned(string, width, align, fill),
+                    Err(Error::NotEnoughSpace)
+                ));
+                assert_eq!(computer.len(), 0);
+            }
+        }
+    }
+
+    // The length is computed without writing the padding one char at a time
+    let mut computer = ComputeStrLength::new();
+    computer
+        .make_formatter(FormattingFlags::NEW)
+        .write_str_aligned("foo", usize::MAX, Alignment::Center, ' ')
+        .unwrap();
+    assert_eq!(computer.len(), usize::MAX);
+}
+
+#[test]
+fn write_separated_ints() {
+    fn inner(mut outer: Formatter<'_>) {
+        // The flags passed to `write_separated_ints` override the ones of the Formatter
+        let flags = outer.flags();
+        let mut fmt =
+            outer.make_formatter(FormattingFlags::NEW.set_alternate(!flags.is_alternate()));
+
+        append_str!(fmt,";";
+            fmt.write_separated_ints::<u8>(&[], ",", flags).unwrap();
+            fmt.write_separated_ints(&[17u32], ",", flags).unwrap();
+            fmt.write_separated_ints(&[17u32, 18, 19], ",", flags).unwrap();
+            fmt.write_separated_ints(&[-1i64, 0, i64::MAX], ", ", flags).unwrap();
+            fmt.write_separated_ints(&[3usize, 5], "", flags).unwrap();
+        );
+    }
+
+    write_with_flag(
+        FormattingFlags::NEW,
+        ";17;17,18,19;-1, 0, 9223372036854775807;35;",
+        &inner,
+    );
+    write_with_flag(
+        FormattingFlags::NEW.set_hexadecimal(),
+        ";11;11,12,13;FFFFFFFFFFFFFFFF, 0, 7FFFFFFFFFFFFFFF;35;",
+        &inner,
+    );
+    write_with_flag(
+        FormattingFlags::NEW.set_alternate(true).set_binary(),
+        ";0b10001;0b10001,0b10010,0b10011;\
+         0b1111111111111111111111111111111111111111111111111111111111111111, 0b0, \
+         0b111111111111111111111111111111111111111111111111111111111111111;0b110b101;",
+        &inner,
+    );
+}
+
+#[test]
+fn write_separated_ints_not_enough_space() {
+    use cfmt_a::fmt::Error;
+
+    for &space in &[0, 1, 5, 7] {
+        let mut buffer = [0u8; 16];
+        let buffer = &mut buffer[..space + 1];
+        let mut len = 0;
+        let mut fmt = Formatter::from_custom(buffer, &mut len, FormattingFlags::NEW);
+
+        fmt.write_str("|").unwrap();
+        assert!(matches!(
+            fmt.write_separated_ints(&[10u8, 20, 30], ",", FormattingFlags::NEW),
+            Err(Error::NotEnoughSpace)
+        ));
+        assert_eq!(len, 1);
+        assert!(buffer[1..].iter().all(|&b| b == 0));
+    }
+
+    let mut buffer = [0u8; 9];
+    let mut len = 0;
+    let mut fmt = Formatter::from_custom(&mut buffer, &mut len, FormattingFlags::NEW);
+    fmt.write_str("|").unwrap();
+    fmt.write_separated_ints(&[10u8, 20, 30], ",", FormattingFlags::NEW)
+        .unwrap();
+    assert_eq!(&buffer[..len], b"|10,20,30");
+}
+
+#[test]
+fn flag_accessors() {
+    fn inner(mut fmt: Formatter<'_>) {
+        let flags = fmt.flags();
+        assert_eq!(fmt.alternate(), flags.is_alternate());
+
+        let alt = fmt.make_formatter(flags.set_alternate(true));
+        assert!(alt.alternate());
+        assert!(alt.flags().is_alternate());
+
+        let not_alt = fmt.make_formatter(flags.set_alternate(false).set_hexadecimal());
+        assert!(!not_alt.alternate());
+        assert!(!not_alt.flags().is_alternate());
+
+        fmt.write_str(if fmt.alternate() { "alt" } else { "reg" })
+            .unwrap();
+    }
+
+    write_with_flag(FormattingFlags::NEW, "reg", &inner);
+    write_with_flag(FormattingFlags::NEW.set_alternate(true), "alt", &inner);
+    write_with_flag(FormattingFlags::NEW.set_binary(), "reg", &inner);
+    write_with_flag(FormattingFlags::NEW.set_max_depth(Some(0)), "reg", &inner);
+}
+
+#[test]
+fn set_flags() {
+    fn inner(mut fmt: Formatter<'_>) {
+        let flags = fmt.flags();
+
+        let prev = fmt.set_flags(flags.set_alternate(true).set_binary());
+        assert_eq!(prev, flags);
+        assert!(fmt.alternate());
+        fmt.write_u8_debug(5).unwrap();
+        fmt.write_str(" ").unwrap();
+
+        assert_eq!(fmt.set_flags(prev), flags.set_alternate(true).set_binary());
+        assert_eq!(fmt.flags(), flags);
+        fmt.write_u8_debug(5).unwrap();
+    }
+
+    write_with_flag(FormattingFlags::NEW, "0b101 5", &inner);
+    write_with_flag(FormattingFlags::NEW.set_hexadecimal(), "0b101 5", &inner);
+}
+
+#[test]
+fn fields_with_different_radixes() {
+    struct Pair {
+        hex: u32,
+        bin: u32,
+        dec: u32,
+    }
+
+    const fn debug_pair(this: &Pair, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut f = f.debug_struct("Pair");
+
+        let fmt_hex = f.field("hex");
+        let hex = fmt_hex.flags().set_hexadecimal();
+        try_!(fmt_hex.make_formatter(hex).write_u32_debug(this.hex));
+
+        let fmt_bin = f.field("bin");
+        let prev = fmt_bin.set_flags(fmt_bin.flags().set_binary());
+        try_!(fmt_bin.write_u32_debug(this.bin));
+        let _ = fmt_bin.set_flags(prev);
+
+        try_!(f.field("dec").write_u32_debug(this.dec));
+        f.finish()
+    }
+
+    let inner = |mut fmt: Formatter<'_>| {
+        debug_pair(
+            &Pair {
+                hex: 255,
+                bin: 5,
+                dec: 12,
+            },
+            &mut fmt,
+        )
+        .unwrap();
+    };
+
+    write_with_flag(
+        FormattingFlags::NEW,
+        "Pair { hex: FF, bin: 101, dec: 12 }",
+        &inner,
+    );
+    write_with_flag(
+        FormattingFlags::NEW.set_alternate(true),
+        "Pair {\n    hex: 0xFF,\n    bin: 0b101,\n    dec: 12,\n}",
+        &inner,
+    );
+}
+
+#[test]
+fn formatting_flags_eq_and_debug() {
+    let all_flags = [
+        FormattingFlags::NEW,
+        FormattingFlags::NEW.set_hexadecimal(),
+        FormattingFlags::NEW.set_binary(),
+        FormattingFlags::NEW.set_alternate(true),
+        FormattingFlags::NEW.set_alternate(true).set_hexadecimal(),
+        FormattingFlags::NEW.set_alternate(true).set_binary(),
+        FormattingFlags::NEW.set_max_depth(Some(0)),
+        FormattingFlags::NEW.set_max_depth(Some(3)),
+        FormattingFlags::NEW
+            .set_alternate(true)
+            .set_max_depth(Some(3)),
+    ];
+
+    for (i, &left) in all_flags.iter().enumerate() {
+        for (j, &right) in all_flags.iter().enumerate() {
+            assert_eq!(left.const_eq(&right), i == j, "{:?} {:?}", left, right);
+            assert_eq!(left.const_eq(&right), left == right);
+        }
+
+        let inner = |mut fmt: Formatter<'_>| left.const_debug_fmt(&mut fmt).unwrap();
+
+        write_with_flag(FormattingFlags::NEW, &format!("{:?}", left), &inner);
+        write_with_flag(
+            FormattingFlags::NEW.set_alternate(true),
+            &format!("{:#?}", left),
+            &inner,
+        );
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 fn remove_margin(s: &str) -> String {
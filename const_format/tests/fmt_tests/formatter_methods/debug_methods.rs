@@ -327,3 +327,317 @@ fn formatting_set() {
         set.const_debug_fmt(&mut fmt).unwrap();
     })
 }
+
+////////////////////////////////////////////////////////////////////////////////
+
+struct Map {
+    entries: &'static [(&'static str, &'static [u32])],
+    rec: Option<&'static Map>,
+}
+
+impl_fmt! {
+    impl Map;
+
+    pub const fn const_debug_fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut fmt = fmt.debug_map();
+        let mut i = 0;
+        while i < self.entries.len() {
+            let (key, value) = self.entries[i];
+            try_!(fmt.key().write_str_debug(key));
+            try_!(PWrapper(value).const_debug_fmt(fmt.value()));
+            i += 1;
+        }
+        if let Some(x) = self.rec {
+            try_!(fmt.key().write_str_debug("rec"));
+            try_!(x.const_debug_fmt(fmt.value()));
+        }
+        fmt.finish()
+    }
+}
+
+impl std::fmt::Debug for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut f = f.debug_map();
+        f.entries(self.entries.iter().map(|(k, v)| (k, v)));
+        if let Some(x) = self.rec {
+            f.entry(&"rec", x);
+        }
+        f.finish()
+    }
+}
+
+#[test]
+fn formatting_map() {
+    const MAP: Map = Map {
+        entries: &[("foo", &[3, 5]), ("bar", &[])],
+        rec: Some(&Map {
+            entries: &[("baz", &[8])],
+            rec: Some(&Map {
+                entries: &[],
+                rec: None,
+            }),
+        }),
+    };
+
+    let inner = |mut fmt: Formatter<'_>| MAP.const_debug_fmt(&mut fmt).unwrap();
+
+    let expected = r#"{"foo": [3, 5], "bar": [], "rec": {"baz": [8], "rec": {}}}"#;
+    assert_eq!(format!("{:?}", MAP), expected);
+    write_with_flag(FormattingFlags::NEW, expected, &inner);
+
+    let expected = remove_margin(
+        r#"
+        {
+            "foo": [
+                3,
+                5,
+            ],
+            "bar": [],
+            "rec": {
+                "baz": [
+                    8,
+                ],
+                "rec": {},
+            },
+        }"#,
+    );
+    assert_eq!(format!("{:#?}", MAP), expected);
+    write_with_flag(FormattingFlags::NEW.set_alternate(true), &expected, &inner);
+
+    const EMPTY: Map = Map {
+        entries: &[],
+        rec: None,
+    };
+    let inner = |mut fmt: Formatter<'_>| EMPTY.const_debug_fmt(&mut fmt).unwrap();
+    assert_eq!(format!("{:#?}", EMPTY), "{}");
+    write_with_flag(FormattingFlags::NEW, "{}", &inner);
+    write_with_flag(FormattingFlags::NEW.set_alternate(true), "{}", &inner);
+}
+
+#[test]
+fn formatting_map_max_depth() {
+    const MAP: Map = Map {
+        entries: &[("foo", &[3])],
+        rec: Some(&Map {
+            entries: &[],
+            rec: None,
+        }),
+    };
+
+    let inner = |mut fmt: Formatter<'_>| MAP.const_debug_fmt(&mut fmt).unwrap();
+
+    let cases: &[(Option<usize>, &str)] = &[
+        (Some(0), "..."),
+        (Some(1), r#"{"foo": ..., "rec": ...}"#),
+        (Some(2), r#"{"foo": [3], "rec": {}}"#),
+    ];
+    for &(max_depth, expected) in cases {
+        let flags = FormattingFlags::NEW.set_max_depth(max_depth);
+        write_with_flag(flags, expected, &inner);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+struct Node {
+    id: u32,
+    pair: (u8, u8),
+    children: &'static [Node],
+}
+
+impl_fmt! {
+    impl Node;
+
+    pub const fn const_debug_fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut fmt = fmt.debug_struct("Node");
+        try_!(fmt.field("id").write_u32_debug(self.id));
+        {
+            let mut fmt = fmt.field("pair").debug_tuple("Pair");
+            try_!(fmt.field().write_u8_debug(self.pair.0));
+            try_!(fmt.field().write_u8_debug(self.pair.1));
+            try_!(fmt.finish());
+        }
+        {
+            let mut fmt = fmt.field("children").debug_list();
+            let mut i = 0;
+            while i < self.children.len() {
+                try_!(self.children[i].const_debug_fmt(fmt.entry()));
+                i += 1;
+            }
+            try_!(fmt.finish());
+        }
+        fmt.finish()
+    }
+}
+
+#[test]
+fn formatting_max_depth() {
+    const TREE: Node = Node {
+        id: 0,
+        pair: (1, 2),
+        children: &[
+            Node {
+                id: 3,
+                pair: (4, 5),
+                children: &[],
+            },
+            Node {
+                id: 6,
+                pair: (7, 8),
+                children: &[Node {
+                    id: 9,
+                    pair: (10, 11),
+                    children: &[],
+                }],
+            },
+        ],
+    };
+
+    let inner = |mut fmt: Formatter<'_>| TREE.const_debug_fmt(&mut fmt).unwrap();
+
+    let cases: &[(Option<usize>, &str)] = &[
+        (Some(0), "..."),
+        (Some(1), "Node { id: 0, pair: ..., children: ... }"),
+        (
+            Some(2),
+            "Node { id: 0, pair: Pair(1, 2), children: [..., ...] }",
+        ),
+        (
+            Some(3),
+            "Node { id: 0, pair: Pair(1, 2), children: [\
+                Node { id: 3, pair: ..., children: ... }, \
+                Node { id: 6, pair: ..., children: ... }\
+            ] }",
+        ),
+        (
+            Some(4),
+            "Node { id: 0, pair: Pair(1, 2), children: [\
+                Node { id: 3, pair: Pair(4, 5), children: [] }, \
+                Node { id: 6, pair: Pair(7, 8), children: [...] }\
+            ] }",
+        ),
+    ];
+
+    let unlimited = "Node { id: 0, pair: Pair(1, 2), children: [\
+        Node { id: 3, pair: Pair(4, 5), children: [] }, \
+        Node { id: 6, pair: Pair(7, 8), children: [\
+            Node { id: 9, pair: Pair(10, 11), children: [] }\
+        ] }\
+    ] }";
+
+    for &(max_depth, expected) in cases {
+        let flags = FormattingFlags::NEW.set_max_depth(max_depth);
+        write_with_flag(flags, expected, &inner);
+    }
+    for max_depth in [None, Some(6), Some(100)].iter() {
+        let flags = FormattingFlags::NEW.set_max_depth(*max_depth);
+        write_with_flag(flags, unlimited, &inner);
+    }
+
+    let expected = remove_margin(
+        "
+        Node {
+            id: 0,
+            pair: Pair(
+                1,
+                2,
+            ),
+            children: [
+                Node {
+                    id: 3,
+                    pair: ...,
+                    children: ...,
+                },
+                Node {
+                    id: 6,
+                    pair: ...,
+                    children: ...,
+                },
+            ],
+        }\
+    ",
+    );
+
+    let flags = FormattingFlags::NEW
+        .set_alternate(true)
+        .set_max_depth(Some(3));
+    write_with_flag(flags, &expected, &inner);
+}
+
+#[test]
+fn max_depth_through_make_formatter() {
+    struct Outer {
+        inner: Inner,
+    }
+
+    struct Inner {
+        x: u32,
+        ys: &'static [u32],
+    }
+
+    impl_fmt! {
+        impl Outer;
+
+        const fn const_debug_fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+            let mut fmt = fmt.debug_struct("Outer");
+            // passing flags without a max depth
+            let flags = FormattingFlags::NEW.set_hexadecimal();
+            try_!(self.inner.const_debug_fmt(&mut fmt.field("inner").make_formatter(flags)));
+            fmt.finish()
+        }
+    }
+
+    impl_fmt! {
+        impl Inner;
+
+        const fn const_debug_fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+            let mut fmt = fmt.debug_struct("Inner");
+            try_!(fmt.field("x").write_int(self.x, FormattingFlags::NEW));
+            try_!(PWrapper(self.ys).const_debug_fmt(fmt.field("ys")));
+            fmt.finish()
+        }
+    }
+
+    const OUTER: Outer = Outer {
+        inner: Inner {
+            x: 767,
+            ys: &[10, 11],
+        },
+    };
+
+    let inner = |mut fmt: Formatter<'_>| OUTER.const_debug_fmt(&mut fmt).unwrap();
+
+    let cases: &[(Option<usize>, &str)] = &[
+        (Some(0), "..."),
+        (Some(1), "Outer { inner: ... }"),
+        (Some(2), "Outer { inner: Inner { x: 767, ys: ... } }"),
+        (Some(3), "Outer { inner: Inner { x: 767, ys: [A, B] } }"),
+        (None, "Outer { inner: Inner { x: 767, ys: [A, B] } }"),
+    ];
+
+    for &(max_depth, expected) in cases {
+        let flags = FormattingFlags::NEW.set_max_depth(max_depth);
+        write_with_flag(flags, expected, &inner);
+    }
+
+    // A reborrow can lower the max depth, but not raise it
+    let lowered = |mut fmt: Formatter<'_>| {
+        let flags = fmt.flags().set_max_depth(Some(1));
+        OUTER
+            .const_debug_fmt(&mut fmt.make_formatter(flags))
+            .unwrap()
+    };
+    write_with_flag(FormattingFlags::NEW, "Outer { inner: ... }", &lowered);
+
+    let raised = |mut fmt: Formatter<'_>| {
+        let flags = fmt.flags().set_max_depth(Some(3));
+        OUTER
+            .const_debug_fmt(&mut fmt.make_formatter(flags))
+            .unwrap()
+    };
+    write_with_flag(
+        FormattingFlags::NEW.set_max_depth(Some(1)),
+        "Outer { inner: ... }",
+        &raised,
+    );
+}
@@ -6,12 +6,14 @@ use cfmt_a::{
 };
 
 use core::{
-    cmp::Ordering,
+    cmp::{Ordering, Reverse},
+    fmt::Alignment,
     marker::{PhantomData, PhantomPinned},
-    num::NonZeroU8,
+    num::{FpCategory, NonZeroU8, Saturating, Wrapping},
     ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
     ptr::NonNull,
     sync::atomic::Ordering as AtomicOrdering,
+    time::Duration,
 };
 
 const FLAGS: &[FormattingFlags] = &[
@@ -55,6 +57,12 @@ macro_rules! test_fmt {
     });
 }
 
+#[test]
+fn bool_impls() {
+    test_fmt! {bool; (true, "true", "true") (false, "false", "false") }
+    test_fmt! {&[bool]; (&[true, false][..], "[true, false]", "[\n    true,\n    false,\n]") }
+}
+
 #[test]
 fn array_impls() {
     test_fmt! {&[u8];
@@ -83,6 +91,39 @@ fn array_impls() {
     }
 }
 
+#[test]
+fn array_display_impls() {
+    use cfmt_a::formatc;
+
+    const STRS: [&str; 3] = ["foo", "\n", "ñandú"];
+    const CHARS: [char; 4] = ['b', 'a', '\t', '个'];
+    const CHAR_SLICE: &[char] = &['b', 'a'];
+    const EMPTY: [&str; 0] = [];
+
+    assert_eq!(formatc!("{}", STRS), "foo\nñandú");
+    assert_eq!(formatc!("{}", CHARS), "ba\t个");
+    assert_eq!(formatc!("[{}]", EMPTY), "[]");
+
+    assert_eq!(formatc!("{}", CHAR_SLICE), "ba");
+
+    // the alternate flag doesn't change the output
+    assert_eq!(formatc!("{:#}", STRS), "foo\nñandú");
+
+    // Debug formatting is still bracketed
+    assert_eq!(formatc!("{:?}", STRS), r#"["foo", "\n", "ñandú"]"#);
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+    PWrapper(&STRS[..])
+        .const_display_fmt(&mut writer.make_formatter(FormattingFlags::NEW))
+        .unwrap();
+    let mut str_len = ComputeStrLength::new();
+    PWrapper(&STRS[..])
+        .const_display_fmt(&mut str_len.make_formatter(FormattingFlags::NEW))
+        .unwrap();
+    assert_eq!(writer.as_str(), STRS.concat());
+    assert_eq!(str_len.len(), writer.len());
+}
+
 #[test]
 fn range_impls() {
     test_fmt! {Range<usize>; (11..64, "11..64", "0xB..0x40") }
@@ -93,11 +134,59 @@ fn range_impls() {
     test_fmt! {RangeToInclusive<usize>; (..=64, "..=64", "..=0x40") }
 }
 
+#[test]
+fn integer_range_impls() {
+    test_fmt! {Range<u32>;
+        (0u32..10, "0..10", "0x0..0xA")
+        (5u32..5, "5..5", "0x5..0x5")
+    }
+    test_fmt! {Range<i8>; (-128i8..127, "-128..127", "0x80..0x7F") }
+    test_fmt! {Range<u128>;
+        (0u128..u128::MAX, "0..340282366920938463463374607431768211455", "0x0..0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF")
+    }
+    test_fmt! {RangeInclusive<i8>;
+        (0i8..=5, "0..=5", "0x0..=0x5")
+        (3i8..=2, "3..=2", "0x3..=0x2")
+    }
+    test_fmt! {RangeInclusive<i64>;
+        (-5i64..=-1, "-5..=-1", "0xFFFFFFFFFFFFFFFB..=0xFFFFFFFFFFFFFFFF")
+    }
+}
+
+#[test]
+fn range_consts() {
+    use cfmt_a::formatc;
+
+    const RANGE: Range<u32> = 0..10;
+    const INCLUSIVE: RangeInclusive<i8> = 0..=5;
+    const EMPTY: Range<i16> = 5..5;
+    const EMPTY_INCLUSIVE: RangeInclusive<usize> = 3..=2;
+    assert_eq!(
+        formatc!(
+            "{:?} {:?} {:?} {:?}",
+            RANGE,
+            INCLUSIVE,
+            EMPTY,
+            EMPTY_INCLUSIVE
+        ),
+        format!(
+            "{:?} {:?} {:?} {:?}",
+            RANGE, INCLUSIVE, EMPTY, EMPTY_INCLUSIVE
+        ),
+    );
+    assert_eq!(formatc!("{:?}", 0u32..10), "0..10");
+    assert_eq!(formatc!("{:?}", 0i8..=5), "0..=5");
+    assert_eq!(formatc!("{:?}", 0..10), "0..10");
+    assert_eq!(formatc!("{:?}", 0..=10), "0..=10");
+    assert_eq!(formatc!("{:x}", -3..0), "FFFFFFFD..0");
+}
+
 #[test]
 fn options() {
     test_fmt! {Option<&str>;
         (None::<&str>, "None", "None")
         (Some("hello\n"), "Some(\"hello\\n\")", "Some(\n    \"hello\\n\",\n)")
+        (Some("a\"b\n\x01"), r#"Some("a\"b\n\x01")"#, "Some(\n    \"a\\\"b\\n\\x01\",\n)")
     }
     test_fmt! {Option<u8>;
         (None::<u8>, "None", "None")
@@ -123,6 +212,64 @@ fn options() {
     }
 }
 
+#[test]
+fn wrapping_and_saturating() {
+    test_fmt! {Wrapping<u8>;
+        (Wrapping(5u8), "5", "0x5")
+        (Wrapping(255u8), "255", "0xFF")
+    }
+    test_fmt! {Wrapping<i32>;
+        (Wrapping(-3i32), "-3", "0xFFFFFFFD")
+        (Wrapping(i32::MAX), "2147483647", "0x7FFFFFFF")
+    }
+    test_fmt! {Saturating<u64>;
+        (Saturating(0u64), "0", "0x0")
+        (Saturating(u64::MAX), "18446744073709551615", "0xFFFFFFFFFFFFFFFF")
+    }
+    test_fmt! {Saturating<i16>;
+        (Saturating(i16::MIN), "-32768", "0x8000")
+    }
+
+    use cfmt_a::formatc;
+
+    // matches the output of the standard library
+    const WRAPPING: Wrapping<u8> = Wrapping(5u8.wrapping_add(255));
+    const SATURATING: Saturating<i16> = Saturating(i16::MIN.saturating_sub(10));
+    assert_eq!(
+        formatc!("{0} {0:?} {1} {1:?}", WRAPPING, SATURATING),
+        format!("{0} {0:?} {1} {1:?}", WRAPPING, SATURATING),
+    );
+    assert_eq!(formatc!("{0:x} {0:#b}", Wrapping(10u32)), "A 0b1010");
+}
+
+#[test]
+fn reverse() {
+    test_fmt! {Reverse<u32>;
+        (Reverse(5u32), "Reverse(5)", "Reverse(\n    0x5,\n)")
+    }
+    test_fmt! {Reverse<i8>;
+        (Reverse(-1i8), "Reverse(-1)", "Reverse(\n    0xFF,\n)")
+    }
+    test_fmt! {Reverse<&str>;
+        (Reverse("a\"b"), r#"Reverse("a\"b")"#, "Reverse(\n    \"a\\\"b\",\n)")
+    }
+    test_fmt! {Reverse<bool>;
+        (Reverse(true), "Reverse(true)", "Reverse(\n    true,\n)")
+    }
+
+    use cfmt_a::formatc;
+
+    // matches the output of the standard library
+    assert_eq!(
+        formatc!("{:?}", Reverse(5u32)),
+        format!("{:?}", Reverse(5u32))
+    );
+    assert_eq!(
+        formatc!("{:#?}", Reverse('c')),
+        format!("{:#?}", Reverse('c'))
+    );
+}
+
 #[test]
 fn pointers() {
     test_fmt! {*const u8; (core::ptr::null(), "<pointer>", "<pointer>") }
@@ -149,9 +296,39 @@ fn miscelaneous_enums() {
         AtomicOrdering;
         (AtomicOrdering::Relaxed, "Relaxed", "Relaxed")
         (AtomicOrdering::Release, "Release", "Release")
+        (AtomicOrdering::Acquire, "Acquire", "Acquire")
+        (AtomicOrdering::AcqRel, "AcqRel", "AcqRel")
+        (AtomicOrdering::SeqCst, "SeqCst", "SeqCst")
+    }
+    test_fmt! {
+        FpCategory;
+        (FpCategory::Nan, "Nan", "Nan")
+        (FpCategory::Infinite, "Infinite", "Infinite")
+        (FpCategory::Zero, "Zero", "Zero")
+        (FpCategory::Subnormal, "Subnormal", "Subnormal")
+        (FpCategory::Normal, "Normal", "Normal")
+    }
+    test_fmt! {
+        Alignment;
+        (Alignment::Left, "Left", "Left")
+        (Alignment::Right, "Right", "Right")
+        (Alignment::Center, "Center", "Center")
     }
 }
 
+#[test]
+fn miscelaneous_enums_formatc() {
+    use cfmt_a::formatc;
+
+    assert_eq!(formatc!("{:?}", Ordering::Less), "Less");
+    assert_eq!(
+        formatc!("{:?} {:?}", Ordering::Equal, Ordering::Greater),
+        "Equal Greater"
+    );
+    assert_eq!(formatc!("{:?}", FpCategory::Subnormal), "Subnormal");
+    assert_eq!(formatc!("{:#?}", Alignment::Center), "Center");
+}
+
 #[test]
 fn chars() {
     test_fmt! {
@@ -169,3 +346,40 @@ fn chars() {
         ('\u{100000}', "'\u{100000}'", "'\u{100000}'")
     }
 }
+
+#[test]
+fn duration_impl() {
+    let mut string = String::new();
+
+    macro_rules! duration_case {
+        ($($duration:expr),* $(,)?) => {$({
+            string.clear();
+            write!(string, "{:?}", $duration).unwrap();
+            let expected = string.as_str();
+
+            test_fmt! {
+                Duration;
+                ($duration, expected, expected)
+            }
+        })*};
+    }
+
+    use std::fmt::Write;
+
+    duration_case! {
+        Duration::from_secs(0),
+        Duration::from_secs(5),
+        Duration::from_millis(1500),
+        Duration::from_millis(100),
+        Duration::from_millis(1),
+        Duration::from_micros(1500),
+        Duration::from_micros(999_999),
+        Duration::from_nanos(1),
+        Duration::from_nanos(999),
+        Duration::from_nanos(1_000),
+        Duration::from_nanos(1_000_001),
+        Duration::new(1, 1),
+        Duration::new(10, 120_000_000),
+        Duration::new(u64::MAX, 999_999_999),
+    }
+}
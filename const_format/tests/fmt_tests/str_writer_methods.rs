@@ -439,6 +439,40 @@ fn remaining_capacity_test() {
     assert_eq!(str_writer.remaining_capacity(), CAP - 5);
 }
 
+#[test]
+fn accessors_return_written_prefix() {
+    let str_writer: &mut StrWriter = &mut StrWriter::new([b'_'; 64]);
+
+    assert_eq!(str_writer.as_bytes(), b"");
+    assert_eq!(str_writer.as_str(), "");
+
+    str_writer.as_mut().write_str("hello").unwrap();
+    str_writer.as_mut().write_str("ñ鉄").unwrap();
+
+    for (bytes, str) in [
+        (str_writer.as_bytes(), str_writer.as_str()),
+        (str_writer.as_bytes_alt(), str_writer.as_str_alt()),
+    ] {
+        assert_eq!(bytes.len(), str_writer.len());
+        assert_eq!(str.len(), str_writer.len());
+        assert_eq!(bytes, "helloñ鉄".as_bytes());
+        assert_eq!(str, "helloñ鉄");
+    }
+    assert_eq!(&str_writer.buffer()[str_writer.len()..], &[b'_'; 54][..]);
+
+    let mut writer = str_writer.as_mut();
+    writer.truncate(5).unwrap();
+    assert_eq!(writer.as_bytes().len(), writer.len());
+    assert_eq!(writer.as_str().len(), writer.len());
+    assert_eq!(writer.as_str(), "hello");
+    assert_eq!(writer.as_str_alt(), "hello");
+
+    writer.write_u8_display(255).unwrap();
+    assert_eq!(writer.as_bytes(), b"hello255");
+    assert_eq!(writer.as_str(), "hello255");
+    assert_eq!(writer.as_str().len(), writer.len());
+}
+
 #[test]
 fn truncation() {
     let str_writer: &mut StrWriter = &mut StrWriter::new([0; 4096]);
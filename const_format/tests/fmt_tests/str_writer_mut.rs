@@ -172,3 +172,53 @@ fn as_bytes() {
         assert_eq!(writer.remaining_capacity(), CAP - i - 1);
     }
 }
+
+#[test]
+fn not_enough_space_is_atomic() {
+    let mut len = 0;
+    let mut buffer = [0u8; 6];
+    let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+
+    writer.write_str("abcd").unwrap();
+    assert_eq!(writer.remaining_capacity(), 2);
+
+    // a 4 byte char doesn't fit in the 2 remaining bytes
+    assert_eq!(writer.write_char('😀'), Err(Error::NotEnoughSpace));
+    assert_eq!(writer.write_str("😀"), Err(Error::NotEnoughSpace));
+    assert_eq!(
+        writer.write_str_range("😀", 0..4),
+        Err(Error::NotEnoughSpace)
+    );
+    assert_eq!(writer.write_char_debug('😀'), Err(Error::NotEnoughSpace));
+
+    assert_eq!(writer.as_str(), "abcd");
+    assert_eq!(writer.remaining_capacity(), 2);
+    assert_eq!(len, 4);
+    assert_eq!(buffer, *b"abcd\0\0");
+}
+
+#[test]
+fn core_fmt_write() {
+    use core::fmt::Write;
+
+    let mut len = 0;
+    let mut buffer = [0u8; 12];
+    let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+
+    core::write!(writer, "{}|{:?}|", 5u8, 'ñ').unwrap();
+    assert_eq!(writer.as_str(), "5|'ñ'|");
+
+    // inherent methods and `write!` write to the same buffer
+    writer.write_str("ab").unwrap();
+    core::write!(writer, "{:x}", 255).unwrap();
+    assert_eq!(writer.as_str(), "5|'ñ'|abff");
+
+    // the 4 byte char doesn't fit in the 1 remaining byte
+    assert_eq!(core::write!(writer, "{}", '😀'), Err(core::fmt::Error));
+    assert_eq!(writer.as_str(), "5|'ñ'|abff");
+    assert_eq!(writer.remaining_capacity(), 1);
+
+    core::write!(writer, "!").unwrap();
+    assert_eq!(writer.as_str(), "5|'ñ'|abff!");
+    assert_eq!(len, 12);
+}
@@ -15,6 +15,9 @@ mod fmt_tests {
     #[cfg(not(feature = "only_new_tests"))]
     mod display_formatting;
 
+    #[cfg(not(feature = "only_new_tests"))]
+    mod error_tests;
+
     #[cfg(not(feature = "only_new_tests"))]
     mod formatted_writing;
 
@@ -1,7 +1,7 @@
 #![allow(unreachable_code)]
 
 use cfmt_b::for_examples::{Point3, Unit};
-use cfmt_b::{assertc, assertc_eq, assertc_ne, call_debug_fmt};
+use cfmt_b::{assertc, assertc_eq, assertc_ne, call_debug_fmt, FormattingFlags};
 
 struct Foo;
 
@@ -74,6 +74,12 @@ const _: () = {
 
     assertc_eq!(Unit, Unit);
 
+    assertc_eq!(FormattingFlags::NEW, FormattingFlags::NEW.set_decimal());
+    assertc_eq!(
+        FormattingFlags::NEW.set_alternate(true).set_binary(),
+        FormattingFlags::NEW.set_binary().set_alternate(true),
+    );
+
     assertc_eq!(0u8, 0u8);
     assertc_eq!("foo", "foo", "hello");
     assertc_eq!(Some("foo"), Some("foo"), "hello {}", {
@@ -90,6 +96,12 @@ const _: () = {
     assertc_ne!(POINT, OTHER_POINT);
     assertc_ne!(OTHER_POINT, POINT);
 
+    assertc_ne!(FormattingFlags::NEW, FormattingFlags::NEW.set_hexadecimal());
+    assertc_ne!(
+        FormattingFlags::NEW,
+        FormattingFlags::NEW.set_alternate(true)
+    );
+
     assertc_ne!(0u8, 3u8);
     assertc_ne!("foo", "bar", "hello");
     assertc_ne!(Some("foo"), Some("bar"), "hello {}", {
@@ -1,6 +1,6 @@
 #![allow(unreachable_code)]
 
-use cfmt_b::{assertcp, assertcp_eq, assertcp_ne};
+use cfmt_b::{assertcp, assertcp_eq, assertcp_ne, panic_fmt};
 
 struct Foo;
 
@@ -75,11 +75,18 @@ const _: () = {
     assertcp_eq!(' ', ' ', "world{foo}", foo = 1u8);
     assertcp_eq!("hello", "hello", "world{X}");
 
+    assertcp_eq!(X, 123u8, fmt = "x");
+    assertcp_eq!(X, 123u8, fmt = "#x",);
+    assertcp_eq!(X, 123u8, fmt = "b", "world");
+    assertcp_eq!(X, 123u8, fmt = "?", "world{}", 1u8);
+
     ////////////////////////////////////////////////////////////////////////////////
     ////        assertcp_ne
 
     assertcp_ne!("hello", "helo");
     assertcp_ne!(0u8, 1u8, "world");
+    assertcp_ne!(0u8, 1u8, fmt = "#b");
+    assertcp_ne!(0u8, 1u8, fmt = "x", "world{X}");
     assertcp_ne!(
         {
             impl Foo {
@@ -109,3 +116,41 @@ const _: () = {
         },
     );
 };
+
+const LIMIT: u32 = 10;
+
+const fn checked_value(n: u32) -> u32 {
+    if n <= LIMIT {
+        n
+    } else {
+        panic_fmt!("bad value: it must be at most {}", LIMIT)
+    }
+}
+
+#[test]
+fn panic_fmt_not_evaluated() {
+    const VALUE: u32 = checked_value(3);
+    assert_eq!(VALUE, 3);
+    assert_eq!(checked_value(LIMIT), LIMIT);
+
+    // `panic_fmt` can be used wherever any type is expected
+    const NAME: &str = match VALUE {
+        3 => "three",
+        _ => panic_fmt!("unexpected {VALUE}, {:?}", "quoted"),
+    };
+    assert_eq!(NAME, "three");
+}
+
+#[test]
+#[should_panic(expected = "bad value: it must be at most 10")]
+fn panic_fmt_at_runtime() {
+    checked_value(std::hint::black_box(11));
+}
+
+#[test]
+#[should_panic(expected = "foo 3 \"bar\" ñ true")]
+fn panic_fmt_formatting() {
+    if std::hint::black_box(true) {
+        panic_fmt!("foo {} {:?} {c} {}", 3u8, "bar", true, c = 'ñ');
+    }
+}
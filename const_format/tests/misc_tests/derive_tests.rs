@@ -146,3 +146,399 @@ fn enum_formatting() {
         ",
     );
 }
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+struct RenamedFields {
+    #[cdeb(rename = "renamed_x")]
+    x: u32,
+    y: bool,
+    #[cdeb(rename = "Z")]
+    z: u32,
+}
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+enum RenamedVariants {
+    #[cdeb(rename = "renamed_braced")]
+    Braced {
+        #[cdeb(rename = "a b")]
+        x: u32,
+        y: u32,
+    },
+    #[cdeb(rename = "Tuple")]
+    Tupled(u32),
+    #[cdeb(rename = "")]
+    Unit,
+    NotRenamed,
+}
+
+#[test]
+fn renamed_formatting() {
+    const fn inner(f: &mut Formatter<'_>) -> Result<(), Error> {
+        try_!(writec!(
+            f,
+            "{:?}\n",
+            RenamedFields {
+                x: 3,
+                y: true,
+                z: 5
+            }
+        ));
+        try_!(writec!(
+            f,
+            "{:?}\n",
+            RenamedVariants::Braced { x: 8, y: 13 }
+        ));
+        try_!(writec!(f, "{:#?}\n", RenamedVariants::Tupled(21)));
+        try_!(writec!(f, "[{:?}]\n", RenamedVariants::Unit));
+        try_!(writec!(f, "{:?}", RenamedVariants::NotRenamed));
+        Ok(())
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 1024]);
+
+    inner(&mut writer.make_formatter(FormattingFlags::NEW)).unwrap();
+
+    assert_eq!(
+        writer.as_str(),
+        "\
+            RenamedFields { renamed_x: 3, y: true, Z: 5 }\n\
+            renamed_braced { a b: 8, y: 13 }\n\
+            Tuple(\n    21,\n)\n\
+            []\n\
+            NotRenamed\
+        ",
+    );
+
+    // renaming only changes the printed name, not the name used in code
+    let RenamedFields { x, y, z } = RenamedFields {
+        x: 1,
+        y: false,
+        z: 2,
+    };
+    assert_eq!((x, y, z), (1, false, 2));
+    match (RenamedVariants::Braced { x: 3, y: 4 }) {
+        RenamedVariants::Braced { x, y } => assert_eq!((x, y), (3, 4)),
+        _ => unreachable!(),
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(as_int)]
+#[repr(u16)]
+enum Status {
+    Ok = 200,
+    NotFound = 404,
+    // implicit discriminants continue from the previous variant
+    Gone = 410,
+    LengthRequired,
+}
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(as_int)]
+enum FieldLess {
+    Unit,
+    Tupled(),
+    Braced {},
+}
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(as_int)]
+enum Signed {
+    Negative = -100,
+    Zero = 0,
+    Max = isize::MAX,
+}
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(as_int)]
+#[repr(u64)]
+enum Unsigned {
+    Big = u64::MAX,
+}
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+struct Response {
+    status: Status,
+}
+
+#[test]
+fn as_int_formatting() {
+    const fn inner(f: &mut Formatter<'_>) -> Result<(), Error> {
+        try_!(writec!(
+            f,
+            "{:?} {:?} {:?} {:?}\n",
+            Status::Ok,
+            Status::NotFound,
+            Status::Gone,
+            Status::LengthRequired,
+        ));
+        try_!(writec!(
+            f,
+            "{:?} {:?} {:?}\n",
+            FieldLess::Unit,
+            FieldLess::Tupled(),
+            FieldLess::Braced {},
+        ));
+        try_!(writec!(
+            f,
+            "{:?} {:?} {:?}\n",
+            Signed::Negative,
+            Signed::Zero,
+            Signed::Max
+        ));
+        try_!(writec!(f, "{:?} {:x?}\n", Unsigned::Big, Status::NotFound));
+        try_!(writec!(
+            f,
+            "{:#?}",
+            Response {
+                status: Status::NotFound
+            }
+        ));
+        Ok(())
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 1024]);
+
+    inner(&mut writer.make_formatter(FormattingFlags::NEW)).unwrap();
+
+    assert_eq!(
+        writer.as_str(),
+        "\
+            200 404 410 411\n\
+            0 1 2\n\
+            -100 0 9223372036854775807\n\
+            18446744073709551615 194\n\
+            Response {\n    status: 404,\n}\
+        ",
+    );
+
+    // the printed integers are the same as casting the variants
+    assert_eq!(Status::LengthRequired as u16, 411);
+    assert_eq!(Signed::Negative as isize, -100);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(transparent)]
+struct Meters(u32);
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(transparent)]
+struct Wrapper {
+    #[allow(dead_code)]
+    #[cdeb(ignore)]
+    id: u32,
+    inner: Braced,
+}
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(transparent)]
+struct WrapperList(#[cdeb(is_a(array))] [Meters; 2]);
+
+#[test]
+fn transparent_formatting() {
+    const BRACED: Braced = Braced {
+        x: 3,
+        y: Some("hello"),
+        z: (5, 8),
+        a: true,
+    };
+    const WRAPPER: Wrapper = Wrapper {
+        id: 13,
+        inner: BRACED,
+    };
+
+    assert_eq!(cfmt_b::formatc!("{:?}", Meters(100)), "100");
+    assert_eq!(cfmt_b::formatc!("{:x?}", Meters(100)), "64");
+    assert_eq!(
+        cfmt_b::formatc!("{:?}", WRAPPER),
+        cfmt_b::formatc!("{:?}", BRACED),
+    );
+    assert_eq!(
+        cfmt_b::formatc!("{:#?}", WRAPPER),
+        cfmt_b::formatc!("{:#?}", BRACED),
+    );
+    assert_eq!(
+        cfmt_b::formatc!("{:?}", WRAPPER),
+        r#"Braced { x: 3, y: Some("hello"), a: true }"#,
+    );
+    assert_eq!(
+        cfmt_b::formatc!("{:?}", WrapperList([Meters(3), Meters(5)])),
+        "[3, 5]",
+    );
+}
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+struct Position(u32, &'static str);
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(named_fields)]
+struct NamedPosition(u32, &'static str);
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(named_fields)]
+struct RenamedPosition(
+    #[cdeb(rename = "line")] u32,
+    #[allow(dead_code)]
+    #[cdeb(ignore)]
+    u32,
+    &'static str,
+);
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(named_fields)]
+enum NamedEnum {
+    Tupled(u8, #[cdeb(rename = "second")] u8),
+    Braced { x: u8 },
+    Unit,
+}
+
+#[test]
+fn named_fields_formatting() {
+    assert_eq!(
+        cfmt_b::formatc!("{:?}", Position(3, "foo")),
+        r#"Position(3, "foo")"#,
+    );
+    assert_eq!(
+        cfmt_b::formatc!("{:?}", NamedPosition(3, "foo")),
+        r#"NamedPosition { 0: 3, 1: "foo" }"#,
+    );
+    assert_eq!(
+        cfmt_b::formatc!("{:#?}", NamedPosition(3, "foo")),
+        "NamedPosition {\n    0: 3,\n    1: \"foo\",\n}",
+    );
+    assert_eq!(
+        cfmt_b::formatc!("{:?}", RenamedPosition(3, 5, "foo")),
+        r#"RenamedPosition { line: 3, 2: "foo" }"#,
+    );
+    assert_eq!(
+        cfmt_b::formatc!(
+            "{:?} {:?} {:?}",
+            NamedEnum::Tupled(3, 5),
+            NamedEnum::Braced { x: 8 },
+            NamedEnum::Unit
+        ),
+        "Tupled { 0: 3, second: 5 } Braced { x: 8 } Unit",
+    );
+}
+
+#[derive(Debug, ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+struct Outer {
+    name: &'static str,
+    inner: Inner,
+    #[cdeb(is_a(array))]
+    list: [Inner; 2],
+    opt: Option<u8>,
+    empty: [u8; 0],
+    unit: Unit2,
+}
+
+#[derive(Debug, ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+struct Inner {
+    x: u32,
+    pair: Pair,
+}
+
+#[derive(Debug, ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+struct Pair(u32, &'static str);
+
+#[derive(Debug, ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+struct Unit2;
+
+#[test]
+fn alternate_matches_std() {
+    const OUTER: Outer = Outer {
+        name: "outer",
+        inner: Inner {
+            x: 3,
+            pair: Pair(5, "five"),
+        },
+        list: [
+            Inner {
+                x: 8,
+                pair: Pair(13, "thirteen"),
+            },
+            Inner {
+                x: 21,
+                pair: Pair(34, ""),
+            },
+        ],
+        opt: Some(55),
+        empty: [],
+        unit: Unit2,
+    };
+
+    assert_eq!(cfmt_b::formatc!("{:#?}", OUTER), format!("{:#?}", OUTER));
+    assert_eq!(cfmt_b::formatc!("{:#x?}", OUTER), format!("{:#X?}", OUTER));
+    assert_eq!(
+        cfmt_b::formatc!("{:#?}", OUTER.inner.pair),
+        format!("{:#?}", OUTER.inner.pair)
+    );
+
+    // the trailing comma is only written by the alternate mode
+    assert_eq!(cfmt_b::formatc!("{:?}", OUTER), format!("{:?}", OUTER));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+struct WithPhantom {
+    x: u8,
+    marker: PhantomData<Dummy>,
+}
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+struct GenericPhantom<T>(u8, PhantomData<T>);
+
+#[test]
+fn phantom_data_fields() {
+    const WITH: WithPhantom = WithPhantom {
+        x: 3,
+        marker: PhantomData,
+    };
+    assert_eq!(
+        cfmt_b::formatc!("{:?}", WITH),
+        "WithPhantom { x: 3, marker: PhantomData }"
+    );
+
+    const GENERIC: GenericPhantom<Dummy> = GenericPhantom(5, PhantomData);
+    assert_eq!(
+        cfmt_b::formatc!("{:?}", GENERIC),
+        "GenericPhantom(5, PhantomData)"
+    );
+    assert_eq!(
+        cfmt_b::formatc!("{:#?}", GENERIC),
+        "GenericPhantom(\n    5,\n    PhantomData,\n)"
+    );
+
+    // `PhantomData` of unsized types
+    assert_eq!(
+        cfmt_b::formatc!("{:?}", PhantomData::<[Dummy]>),
+        "PhantomData"
+    );
+}
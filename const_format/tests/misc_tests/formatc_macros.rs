@@ -70,6 +70,149 @@ fn positional_and_named_arguments() {
     );
 }
 
+#[test]
+fn implicit_and_explicit_positional_arguments() {
+    macro_rules! std_assert {
+        ($fmt:literal $(, $arg:expr)* $(,)?) => {
+            fmt_assert!(($fmt $(, $arg)*), format!($fmt $(, $arg)*))
+        };
+    }
+
+    // The example from the `std::fmt` docs.
+    fmt_assert!(("{1} {} {0} {}", 1u8, 2u8), "2 1 1 2");
+    std_assert!("{1} {} {0} {}", 1u8, 2u8);
+
+    // The implicit counter only advances on `{}` arguments,
+    // regardless of the explicit indices before them.
+    fmt_assert!(("{} {1} {}", 3u8, 5u8), "3 5 5");
+    std_assert!("{} {1} {}", 3u8, 5u8);
+    std_assert!("{0} {0} {} {}", 3u8, 5u8);
+    std_assert!("{2} {1} {0} {} {} {}", 3u8, 5u8, 8u8);
+    std_assert!("{} {2} {} {0:?} {}", 3u8, "5", '8');
+    std_assert!("{:b} {1:?} {:?} {0}", 10u8, 12u8);
+
+    // Named arguments don't advance the implicit counter.
+    fmt_assert!(("{} {a} {} {a}", 3u8, 5u8, a = 8u8), "3 8 5 8");
+    fmt_assert!(("{a} {} {1} {}", 3u8, 5u8, a = 8u8), "8 3 5 5");
+}
+
+#[test]
+fn unicode_and_raw_identifiers() {
+    // Unicode named arguments
+    fmt_assert!(("{café}", café = 1u8), format!("{café}", café = 1u8));
+    fmt_assert!(("{ñandú}-{鉄錆:?}", ñandú = "a", 鉄錆 = "b"), r#"a-"b""#);
+
+    // Raw named arguments are referred to without the `r#` prefix, like in std
+    fmt_assert!(("{type}", r#type = 2u8), format!("{type}", r#type = 2u8));
+    fmt_assert!(("{match}{fn}", r#match = 3u8, r#fn = 5u8), "35");
+    fmt_assert!(("{foo}{0}", 8u8, r#foo = 13u8), "138");
+
+    #[allow(non_upper_case_globals)]
+    {
+        const r#struct: u8 = 21;
+        const CAFÉ: &str = "34";
+        assert_eq!(formatcp!("{struct}_{CAFÉ}"), format!("{struct}_{CAFÉ}"));
+    }
+}
+
+#[test]
+fn reused_arguments() {
+    // Positional arguments used multiple times, with different formatting.
+    fmt_assert!(
+        ("{0}|{0:?}|{0}|{1:x}|{1}|{1:#b}|{0:?}", "fo\"o", 10u8),
+        r#"fo"o|"fo\"o"|fo"o|A|10|0b1010|"fo\"o""#,
+    );
+
+    // Named arguments used multiple times, with different formatting.
+    fmt_assert!(
+        ("{s}{n}{s:?}{n:x}{s}{n:?}", s = "bar", n = 255u8),
+        r#"bar255"bar"FFbar255"#,
+    );
+
+    // Mixing implicit, explicit, and named reuse of the same arguments.
+    fmt_assert!(
+        ("{}-{0}-{}-{1}-{s}-{0}-{s}", "a", 3u8, s = "b"),
+        "a-a-3-3-b-a-b",
+    );
+
+    // Reusing const fn call arguments.
+    const fn compute_str() -> &'static str {
+        "computed"
+    }
+    fmt_assert!(
+        ("{0},{0},{1:?},{1:?}", compute_str(), compute_str().len()),
+        "computed,computed,8,8",
+    );
+}
+
+#[test]
+fn many_arguments() {
+    // There's no limit on the amount of arguments besides the compiler's own limits.
+    fmt_assert! {
+        (
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},\
+             {},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},",
+            0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8,
+            10u8, 11u8, 12u8, 13u8, 14u8, 15u8, 16u8, 17u8, 18u8, 19u8,
+            20u8, 21u8, 22u8, 23u8, 24u8, 25u8, 26u8, 27u8, 28u8, 29u8,
+            30u8, 31u8, 32u8, 33u8, 34u8, 35u8, 36u8, 37u8, 38u8, 39u8,
+            40u8, 41u8, 42u8, 43u8, 44u8, 45u8, 46u8, 47u8, 48u8, 49u8,
+        ),
+        "0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,21,22,23,24,\
+         25,26,27,28,29,30,31,32,33,34,35,36,37,38,39,40,41,42,43,44,45,46,47,48,49,",
+    }
+
+    fmt_assert! {
+        (
+            "{49}{48}{47}{46}{45}{44}{43}{42}{41}{40}{39}{38}{37}{36}{35}{34}{33}{32}{31}{30}{29}{28}{27}{26}{25}\
+             {24}{23}{22}{21}{20}{19}{18}{17}{16}{15}{14}{13}{12}{11}{10}{9}{8}{7}{6}{5}{4}{3}{2}{1}{0}",
+            0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8,
+            10u8, 11u8, 12u8, 13u8, 14u8, 15u8, 16u8, 17u8, 18u8, 19u8,
+            20u8, 21u8, 22u8, 23u8, 24u8, 25u8, 26u8, 27u8, 28u8, 29u8,
+            30u8, 31u8, 32u8, 33u8, 34u8, 35u8, 36u8, 37u8, 38u8, 39u8,
+            40u8, 41u8, 42u8, 43u8, 44u8, 45u8, 46u8, 47u8, 48u8, 49u8,
+        ),
+        "49484746454443424140393837363534333231302928272625\
+         2423222120191817161514131211109876543210",
+    }
+
+    const CONCATENATED: &str = cfmt_b::concatcp! {
+        0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8,
+        10u8, 11u8, 12u8, 13u8, 14u8, 15u8, 16u8, 17u8, 18u8, 19u8,
+        20u8, 21u8, 22u8, 23u8, 24u8, 25u8, 26u8, 27u8, 28u8, 29u8,
+        30u8, 31u8, 32u8, 33u8, 34u8, 35u8, 36u8, 37u8, 38u8, 39u8,
+        40u8, 41u8, 42u8, 43u8, 44u8, 45u8, 46u8, 47u8, 48u8, 49u8,
+    };
+    assert_eq!(
+        CONCATENATED,
+        "0123456789101112131415161718192021222324\
+         25262728293031323334353637383940414243444546474849",
+    );
+}
+
+#[test]
+fn const_fn_arguments() {
+    const fn compute_id() -> u32 {
+        3 + 5
+    }
+    const fn name(upper: bool) -> &'static str {
+        if upper {
+            "FOO"
+        } else {
+            "foo"
+        }
+    }
+
+    fmt_assert!(("{}", compute_id()), "8");
+    fmt_assert!(("{}-{:?}", name(false), name(true)), r#"foo-"FOO""#);
+    fmt_assert!(
+        ("{0}{N}{0:x}", compute_id() * 2, N = name(compute_id() == 8)),
+        "16FOO10"
+    );
+
+    assert_eq!(cfmt_b::concatcp!(compute_id(), name(true)), "8FOO");
+}
+
 // Display formatting is already tested in the `shared_cp_macro_tests` module
 #[test]
 fn debug_formatting() {
@@ -220,6 +363,259 @@ fn binary_and_hex_formatting() {
     binary_hex_test_case!(i128, s);
 }
 
+#[test]
+fn alternate_display_formatting() {
+    macro_rules! std_assert {
+        ($fmt:literal $(, $arg:expr)* $(,)?) => {
+            fmt_assert!(($fmt $(, $arg)*), format!($fmt $(, $arg)*))
+        };
+    }
+
+    // Like in std, the alternate flag doesn't add a radix prefix to Display formatted integers,
+    // since they don't use one.
+    fmt_assert!(("{:#}", 255u8), "255");
+    std_assert!("{:#}", 255u8);
+    std_assert!("{0:#}_{0}", -128i8);
+    std_assert!("{:#}", u128::MAX);
+    std_assert!("{:#}", i64::MIN);
+    std_assert!("{:#}{:#}{:#}", "foo", 'ñ', true);
+
+    // The alternate flag only adds one to the hexadecimal and binary formatters
+    fmt_assert!(("{0:#}_{0:#x}_{0:#b}", 10u8), "10_0xA_0b1010");
+    fmt_assert!(("{0:#?}_{0:#x?}", 255u8), "255_0xFF");
+}
+
+#[test]
+fn alternate_debug_strings() {
+    macro_rules! std_assert {
+        ($fmt:literal $(, $arg:expr)* $(,)?) => {
+            fmt_assert!(($fmt $(, $arg)*), format!($fmt $(, $arg)*))
+        };
+    }
+
+    // Like in std, the alternate flag doesn't make strings span multiple lines
+    fmt_assert!(("{:#?}", "a\nb"), r#""a\nb""#);
+    std_assert!("{:#?}", "a\nb");
+    std_assert!("{:#?}{:#?}", "", "\t\"quoted\"\r\n\\");
+    std_assert!("{0:#?}{0:?}", "ñ鉄 é");
+    std_assert!("{:#?}{:#?}{:#?}", '\n', '\'', 'ñ');
+}
+
+#[cfg(feature = "fmt")]
+#[test]
+fn option_str_debug() {
+    const QUOTED: Option<&str> = Some("a\"b");
+    const NONE: Option<&str> = None;
+
+    // The string inside the `Some` is escaped like it is on its own
+    assert_eq!(formatc!("{:?}", QUOTED), r#"Some("a\"b")"#);
+    assert_eq!(formatc!("{:?}", NONE), "None");
+    assert_eq!(
+        formatc!("{:?}", Some("say \"hi\"\n\tbye\\")),
+        format!("{:?}", Some("say \"hi\"\n\tbye\\")),
+    );
+    assert_eq!(
+        formatc!("{:#?}", Some("a\"b\nc")),
+        format!("{:#?}", Some("a\"b\nc")),
+    );
+}
+
+#[test]
+fn flags_from_constant() {
+    use cfmt_b::FormattingFlags;
+
+    const NEW: FormattingFlags = FormattingFlags::NEW;
+    const HEX: FormattingFlags = FormattingFlags::NEW.set_hexadecimal();
+    const ALT_BIN: FormattingFlags = FormattingFlags::NEW.set_binary().set_alternate(true);
+
+    fmt_assert!(("{0:@NEW}_{0:@NEW?}", "foo"), r#"foo_"foo""#);
+    fmt_assert!(("{0:@HEX}_{0:@HEX?}", 255u8), "255_FF");
+    fmt_assert!(("{0:@ALT_BIN?}_{0:#b?}", 5u8), "0b101_0b101");
+    fmt_assert!(("{A:@HEX?}_{B:@ALT_BIN?}", A = 10u8, B = 2u8), "A_0b10");
+
+    #[cfg(feature = "fmt")]
+    {
+        let mut s = ArrayString::<[u8; 64]>::new();
+        write!(s, "{:#?}", [3u8, 5u8]).unwrap();
+
+        const ALT: FormattingFlags = FormattingFlags::NEW.set_alternate(true);
+        assert_eq!(formatc!("{:@ALT?}", [3u8, 5u8]), s.as_str());
+    }
+}
+
+#[test]
+fn bool_formatting() {
+    macro_rules! std_assert {
+        ($fmt:literal $(, $arg:expr)* $(,)?) => {
+            fmt_assert!(($fmt $(, $arg)*), format!($fmt $(, $arg)*))
+        };
+    }
+
+    // Like in std, bools are Debug formatted the same as they're Display formatted
+    fmt_assert!(("{}-{:?}", true, true), "true-true");
+    fmt_assert!(("{}-{:?}", false, false), "false-false");
+    std_assert!("{}{:?}{}{:?}", true, true, false, false);
+    std_assert!("{:#?}{:x?}{:#x?}", true, false, true);
+}
+
+#[test]
+fn bool_labels() {
+    const ON: bool = true;
+
+    fmt_assert!(("{:bool(on,off)}", true), "on");
+    fmt_assert!(("{:bool(on,off)}", false), "off");
+    fmt_assert!(("{0:bool(yes,no)}_{0}_{0:?}", false), "no_false_false");
+    fmt_assert!(("[{:bool(,)}]", true), "[]");
+    fmt_assert!(("{:bool( a , b )}", false), " b ");
+    fmt_assert!(("{ON:bool(enabled,disabled)}"), "enabled");
+    fmt_assert!(("{x:bool(1,0)}{y:bool(1,0)}", x = 3 < 2, y = 2 < 3), "01");
+}
+
+#[test]
+fn digit_grouping() {
+    assert_eq!(formatcp!("{:,}", 1234567u32), "1,234,567");
+    assert_eq!(formatcp!("{:group(_)}", 1234567u32), "1_234_567");
+    assert_eq!(formatcp!("{:group( )}", 12345678u64), "12 345 678");
+    assert_eq!(formatcp!("{:group(·)}", 1000u16), "1·000");
+
+    // numbers with at most three digits don't have separators
+    assert_eq!(
+        formatcp!("{:,}|{:,}|{:,}|{:,}", 0u8, 7u8, 255u8, 999u16),
+        "0|7|255|999"
+    );
+    assert_eq!(formatcp!("{:,}|{:,}", 1000u16, 100000u32), "1,000|100,000");
+
+    // the sign isn't grouped with the digits
+    assert_eq!(formatcp!("{:,}|{:,}", -1i8, -128i8), "-1|-128");
+    assert_eq!(formatcp!("{:,}", -1000i16), "-1,000");
+    assert_eq!(formatcp!("{:group(_)}", -123456i32), "-123_456");
+    assert_eq!(
+        formatcp!("{:,}", i128::MIN),
+        "-170,141,183,460,469,231,731,687,303,715,884,105,728"
+    );
+    assert_eq!(
+        formatcp!("{:,}", u128::MAX),
+        "340,282,366,920,938,463,463,374,607,431,768,211,455"
+    );
+
+    // only integers are grouped
+    assert_eq!(formatcp!("{:,}{:,}{:,}", "12345", '1', true), "123451true");
+
+    // the same argument with and without grouping
+    assert_eq!(
+        formatcp!("{0} {0:,} {0:group(.)} {0:x}", 65536u32),
+        "65536 65,536 65.536 10000"
+    );
+    assert_eq!(
+        cfmt_b::concatcp!((65536u32, ","), "/", (65536u32, "group(_)")),
+        "65,536/65_536"
+    );
+
+    // grouping from a constant also applies to debug formatted decimal integers
+    {
+        use cfmt_b::FormattingFlags;
+
+        const COMMAS: FormattingFlags = FormattingFlags::NEW.set_digit_grouping(Some(','));
+        const HEX: FormattingFlags = COMMAS.set_hexadecimal();
+
+        assert_eq!(
+            formatcp!("{0:@COMMAS}|{0:@COMMAS?}", 1234567u32),
+            "1,234,567|1,234,567"
+        );
+        assert_eq!(formatcp!("{:@HEX?}", 1234567u32), "12D687");
+        assert_eq!(formatcp!("{:@HEX}", 1234567u32), "1,234,567");
+    }
+}
+
+#[test]
+#[cfg(feature = "const_generics")]
+fn digit_grouping_padded() {
+    use cfmt_b::{pad_end, pad_start};
+
+    // the separators count towards the width
+    const N: u32 = 1234567;
+    assert_eq!(pad_start!(formatcp!("{:,}", N), 12, ' '), "   1,234,567");
+    assert_eq!(pad_end!(formatcp!("{:group(_)}", N), 10, '.'), "1_234_567.");
+    assert_eq!(pad_start!(formatcp!("{:,}", N), 9, ' '), "1,234,567");
+    assert_eq!(pad_start!(formatcp!("{:,}", 12u8), 5, '0'), "00012");
+}
+
+#[test]
+fn scientific() {
+    assert_eq!(
+        formatcp!("{:e}|{:E}|{:e}|{:e}", 1200u32, 1200u32, 1u8, 10u8),
+        "1.2e3|1.2E3|1e0|1e1"
+    );
+    assert_eq!(formatcp!("{:e}", 123456789u32), "1.23456789e8");
+    assert_eq!(formatcp!("{:e}|{:#e}", 15u8, 15u8), "1.5e1|1.5e1");
+
+    // zero
+    assert_eq!(
+        formatcp!("{:e}|{:.0e}|{:.2e}", 0u8, 0u8, 0i64),
+        "0e0|0e0|0.00e0"
+    );
+
+    // the precision pads the mantissa with zeros, or rounds it
+    assert_eq!(formatcp!("{:.2e}|{:.3e}", 12345u32, 5u8), "1.23e4|5.000e0");
+    assert_eq!(formatcp!("{:.1e}|{:.0e}", 1251u32, 9u8), "1.3e3|9e0");
+
+    // ties round to even
+    assert_eq!(
+        formatcp!("{:.1e}|{:.1e}|{:.0e}|{:.0e}", 1250u32, 1350u32, 25u8, 35u8),
+        "1.2e3|1.4e3|2e1|4e1"
+    );
+    assert_eq!(formatcp!("{:.0e}", 251u16), "3e2");
+
+    // rounding that carries into the exponent
+    assert_eq!(
+        formatcp!(
+            "{:.1e}|{:.1e}|{:.0e}|{:.2e}",
+            9990u32,
+            9999u32,
+            95u8,
+            99951u32
+        ),
+        "1.0e4|1.0e4|1e2|1.00e5"
+    );
+
+    // the sign is written before the mantissa
+    assert_eq!(
+        formatcp!("{:e}|{:.3e}|{:.0e}", -1234i32, -9999i16, -5i8),
+        "-1.234e3|-9.999e3|-5e0"
+    );
+    assert_eq!(
+        formatcp!("{:e}", i128::MIN),
+        "-1.70141183460469231731687303715884105728e38"
+    );
+    assert_eq!(
+        formatcp!("{:E}", u128::MAX),
+        "3.40282366920938463463374607431768211455E38"
+    );
+
+    // only integers are written in scientific notation
+    assert_eq!(formatcp!("{:e}{:e}{:e}", "12345", '1', true), "123451true");
+
+    assert_eq!(
+        cfmt_b::concatcp!((1500u32, "e"), "/", (1500u32, ".2E")),
+        "1.5e3/1.50E3"
+    );
+
+    // scientific notation from a constant also applies to debug formatted decimal integers,
+    // and takes precedence over digit grouping
+    {
+        use cfmt_b::{FormattingFlags, ScientificNotation};
+
+        const SCI: FormattingFlags = FormattingFlags::NEW
+            .set_scientific(Some(ScientificNotation::UPPER.set_precision(Some(1))));
+        const HEX: FormattingFlags = SCI.set_hexadecimal();
+        const GROUPED: FormattingFlags = SCI.set_digit_grouping(Some(','));
+
+        assert_eq!(formatcp!("{0:@SCI}|{0:@SCI?}", 98765u32), "9.9E4|9.9E4");
+        assert_eq!(formatcp!("{:@HEX?}", 98765u32), "181CD");
+        assert_eq!(formatcp!("{:@GROUPED}", 98765u32), "9.9E4");
+    }
+}
+
 #[test]
 fn other_tests() {
     assert_eq!(formatcp!("{0:?}-{0:x?}-{0:b?}", ""), r#"""-""-"""#);
@@ -293,3 +689,484 @@ fn access_formatter() {
         "[(), ()] ; [(), ()]"
     );
 }
+
+#[test]
+#[cfg(feature = "fmt")]
+fn long_arrays() {
+    const BYTES: [u8; 100] = {
+        let mut arr = [0u8; 100];
+        let mut i = 0;
+        while i < arr.len() {
+            arr[i] = (i * 3) as u8;
+            i += 1;
+        }
+        arr
+    };
+    const STRS: [&str; 40] = ["foo"; 40];
+
+    assert_eq!(formatc!("{:?}", BYTES), format!("{:?}", BYTES));
+    assert_eq!(formatc!("{:x?}", BYTES), format!("{:X?}", BYTES));
+    assert_eq!(formatc!("{:#?}", BYTES), format!("{:#?}", BYTES));
+    assert_eq!(formatc!("{:?}", STRS), format!("{:?}", STRS));
+    assert_eq!(formatc!("{:#?}", STRS), format!("{:#?}", STRS));
+}
+
+#[test]
+fn format_arg_count() {
+    use cfmt_b::format_arg_count;
+
+    const EMPTY: usize = format_arg_count!("");
+    assert_eq!(EMPTY, 0);
+
+    // positional arguments
+    assert_eq!(format_arg_count!("{}"), 1);
+    assert_eq!(format_arg_count!("{} {:?} {:x}"), 3);
+    assert_eq!(format_arg_count!("{0} {0:?} {1:b}"), 2);
+    assert_eq!(format_arg_count!("{} {0}"), 1);
+    assert_eq!(format_arg_count!("{1} {} {}"), 2);
+
+    // named arguments
+    assert_eq!(format_arg_count!("{x}"), 1);
+    assert_eq!(format_arg_count!("{x} {yy:?} {x:b}"), 2);
+
+    // mixed arguments
+    assert_eq!(format_arg_count!("{} {x} {}"), 3);
+    assert_eq!(format_arg_count!("{x} {0} {} {x} {y}"), 3);
+    assert_eq!(format_arg_count!(concat!("{} {x}", r#" {y} {}"#)), 4);
+
+    // the count matches the amount of arguments that formatcp requires
+    const N: usize = format_arg_count!("{} {x} {0}");
+    assert_eq!(N, 2);
+    assert_eq!(formatcp!("{} {x} {0}", 3u8, x = "foo"), "3 foo 3");
+}
+
+#[test]
+fn col_width() {
+    use cfmt_b::col_width;
+
+    const EMPTY: usize = col_width!([]);
+    assert_eq!(EMPTY, 0);
+
+    assert_eq!(col_width!([0u8]), 1);
+    assert_eq!(col_width!([1u8, 10u16, 100u32]), 3);
+    assert_eq!(col_width!([100u32, 10u16, 1u8,]), 3);
+    assert_eq!(col_width!([5u8, -128i8, 42i64]), 4);
+    assert_eq!(col_width!([u64::MAX, i64::MIN, 0u8]), 20);
+    assert_eq!(col_width!([u128::MAX]), 39);
+
+    const A: u16 = 333;
+    const B: u16 = 22;
+    const W: usize = col_width!([A, B, A + B]);
+    assert_eq!(W, 3);
+
+    // mixed types
+    assert_eq!(col_width!(["", 'a', false, 7u8]), 5);
+    assert_eq!(col_width!(["foo", "hello", true]), 5);
+    // measured in chars, not bytes
+    assert_eq!(col_width!(["鉄錆", "ab", 'ñ']), 2);
+    assert_eq!(col_width!(["鉄錆", 100u8]), 3);
+}
+
+#[test]
+fn wrap_in() {
+    use cfmt_b::wrap_in;
+
+    const NAME: &str = "config";
+    const SECTION: &str = wrap_in!("[", NAME, "]");
+    assert_eq!(SECTION, "[config]");
+
+    // asymmetric
+    assert_eq!(wrap_in!("(", "a, b", ")"), "(a, b)");
+    assert_eq!(wrap_in!("<!-- ", "comment", " -->",), "<!-- comment -->");
+    assert_eq!(wrap_in!("", NAME, ""), "config");
+    assert_eq!(wrap_in!("[", "", "]"), "[]");
+    assert_eq!(wrap_in!("«", "ñ", "»"), "«ñ»");
+    assert_eq!(wrap_in!('{', 3u8, '}'), "{3}");
+    assert_eq!(wrap_in!("[", wrap_in!("(", NAME, ")"), "]"), "[(config)]");
+
+    // symmetric
+    assert_eq!(wrap_in!("\"", NAME), "\"config\"");
+    assert_eq!(wrap_in!("'", "quoted",), "'quoted'");
+    assert_eq!(wrap_in!("__", "init"), "__init__");
+    assert_eq!(wrap_in!("", NAME), "config");
+    assert_eq!(wrap_in!("|", ""), "||");
+    assert_eq!(wrap_in!('*', SECTION), "*[config]*");
+}
+
+#[test]
+fn pluralize() {
+    use cfmt_b::pluralize;
+
+    const N: usize = 5;
+    const ITEMS: &str = pluralize!(N, "item", "items");
+    assert_eq!(ITEMS, "5 items");
+
+    assert_eq!(pluralize!(0u8, "item", "items"), "0 items");
+    assert_eq!(pluralize!(1u8, "item", "items"), "1 item");
+    assert_eq!(pluralize!(3u8, "item", "items"), "3 items");
+    assert_eq!(pluralize!(1i64, "child", "children",), "1 child");
+    assert_eq!(pluralize!(-1i64, "child", "children"), "-1 children");
+    assert_eq!(pluralize!(N - 4, "child", "children"), "1 child");
+
+    assert_eq!(pluralize!(0i32, "box"), "0 boxes");
+    assert_eq!(pluralize!(1i32, "box"), "1 box");
+    assert_eq!(pluralize!(2i32, "box"), "2 boxes");
+    assert_eq!(pluralize!(1i32, "bus",), "1 bus");
+    assert_eq!(pluralize!(7i32, "bus"), "7 buses");
+    assert_eq!(pluralize!(2i32, "waltz"), "2 waltzes");
+    assert_eq!(pluralize!(2i32, "match"), "2 matches");
+    assert_eq!(pluralize!(2i32, "dish"), "2 dishes");
+    assert_eq!(pluralize!(2i32, "month"), "2 months");
+    assert_eq!(pluralize!(0i32, "cat"), "0 cats");
+    assert_eq!(pluralize!(1i32, "cat"), "1 cat");
+    assert_eq!(pluralize!(100u128, "cat"), "100 cats");
+}
+
+#[test]
+fn ordinal() {
+    use cfmt_b::ordinal;
+
+    const N: u32 = 21;
+    const PLACE: &str = ordinal!(N);
+    assert_eq!(PLACE, "21st");
+
+    assert_eq!(ordinal!(0u8), "0th");
+    assert_eq!(ordinal!(1u8), "1st");
+    assert_eq!(ordinal!(2u8), "2nd");
+    assert_eq!(ordinal!(3u8), "3rd");
+    assert_eq!(ordinal!(4u8), "4th");
+    assert_eq!(ordinal!(10u8), "10th");
+    assert_eq!(ordinal!(N - 20), "1st");
+
+    // the teens always get the `th` suffix
+    assert_eq!(ordinal!(11u8), "11th");
+    assert_eq!(ordinal!(12u16), "12th");
+    assert_eq!(ordinal!(13u32), "13th");
+    assert_eq!(ordinal!(111u64), "111th");
+    assert_eq!(ordinal!(212i16), "212th");
+    assert_eq!(ordinal!(1013usize), "1013th");
+
+    assert_eq!(ordinal!(22u8), "22nd");
+    assert_eq!(ordinal!(101u8), "101st");
+    assert_eq!(ordinal!(123i32,), "123rd");
+    assert_eq!(ordinal!(255u8), "255th");
+    assert_eq!(ordinal!(-2i8), "-2nd");
+    assert_eq!(ordinal!(-111i64), "-111th");
+    assert_eq!(
+        ordinal!(i128::MIN),
+        "-170141183460469231731687303715884105728th"
+    );
+    assert_eq!(
+        ordinal!(u128::MAX),
+        "340282366920938463463374607431768211455th"
+    );
+}
+
+#[test]
+fn abbreviate_number() {
+    use cfmt_b::abbreviate_number;
+
+    const N: u64 = 1_500_000;
+    const ABBREVIATED: &str = abbreviate_number!(N);
+    assert_eq!(ABBREVIATED, "1.5M");
+
+    // below a thousand
+    assert_eq!(abbreviate_number!(0), "0");
+    assert_eq!(abbreviate_number!(7), "7");
+    assert_eq!(abbreviate_number!(999), "999");
+
+    // the boundaries of each suffix
+    assert_eq!(abbreviate_number!(1_000), "1K");
+    assert_eq!(abbreviate_number!(999_999), "999.9K");
+    assert_eq!(abbreviate_number!(1_000_000), "1M");
+    assert_eq!(abbreviate_number!(999_999_999), "999.9M");
+    assert_eq!(abbreviate_number!(1_000_000_000), "1G");
+    assert_eq!(abbreviate_number!(999_999_999_999), "999.9G");
+    assert_eq!(abbreviate_number!(1_000_000_000_000), "1T");
+
+    // the decimal place is truncated, and omitted when it's zero
+    assert_eq!(abbreviate_number!(1_099), "1K");
+    assert_eq!(abbreviate_number!(1_100), "1.1K");
+    assert_eq!(abbreviate_number!(1_999), "1.9K");
+    assert_eq!(abbreviate_number!(10_000), "10K");
+    assert_eq!(abbreviate_number!(10_050), "10K");
+    assert_eq!(abbreviate_number!(123_456_789), "123.4M");
+    assert_eq!(abbreviate_number!(2_500_000_000), "2.5G");
+
+    // T is the largest suffix
+    assert_eq!(abbreviate_number!(1_000_000_000_000_000), "1000T");
+    assert_eq!(abbreviate_number!(u64::MAX), "18446744T");
+}
+
+#[test]
+fn to_fixed_point() {
+    use cfmt_b::to_fixed_point;
+
+    const CENTS: u32 = 12345;
+    const PRICE: &str = to_fixed_point!(CENTS, 2);
+    assert_eq!(PRICE, "123.45");
+
+    assert_eq!(to_fixed_point!(0u32, 2), "0.00");
+    assert_eq!(to_fixed_point!(100u32, 2), "1.00");
+    assert_eq!(to_fixed_point!(12345u32, 0), "12345");
+    assert_eq!(to_fixed_point!(12345u32, 1), "1234.5");
+    assert_eq!(to_fixed_point!(12345u32, 5), "0.12345");
+
+    // the fractional part is padded with leading zeroes
+    assert_eq!(to_fixed_point!(5u32, 2), "0.05");
+    assert_eq!(to_fixed_point!(5u32, 6), "0.000005");
+    assert_eq!(to_fixed_point!(1005u32, 3), "1.005");
+    assert_eq!(to_fixed_point!(1050u32, 3), "1.050");
+
+    // negative numbers
+    assert_eq!(to_fixed_point!(-5i32, 2), "-0.05");
+    assert_eq!(to_fixed_point!(-12345i32, 2), "-123.45");
+    assert_eq!(to_fixed_point!(-12345i32, 0), "-12345");
+
+    // the extremes
+    assert_eq!(to_fixed_point!(u8::MAX, 3), "0.255");
+    assert_eq!(to_fixed_point!(i8::MIN, 1), "-12.8");
+    assert_eq!(
+        to_fixed_point!(u128::MAX, 38),
+        "3.40282366920938463463374607431768211455"
+    );
+    assert_eq!(
+        to_fixed_point!(i128::MAX, 38),
+        "1.70141183460469231731687303715884105727"
+    );
+    assert_eq!(
+        to_fixed_point!(i128::MIN, 38),
+        "-1.70141183460469231731687303715884105728"
+    );
+    assert_eq!(
+        to_fixed_point!(i128::MIN, 0),
+        "-170141183460469231731687303715884105728"
+    );
+}
+
+#[test]
+#[cfg(feature = "net")]
+fn ip_addresses() {
+    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    let mut string = ArrayString::<[u8; 64]>::new();
+
+    macro_rules! same_as_std {
+        ($($expr:expr),* $(,)?) => (
+            $({
+                const ADDR: IpAddr = $expr;
+
+                for_both!{
+                    (ADDR)
+                    (match ADDR { IpAddr::V4(x) => x, IpAddr::V6(_) => Ipv4Addr::UNSPECIFIED })
+                    (match ADDR { IpAddr::V6(x) => x, IpAddr::V4(_) => Ipv6Addr::UNSPECIFIED })
+                }
+            })*
+        )
+    }
+
+    macro_rules! for_both {
+        ($(($addr:expr))*) => (
+            $(
+                string.clear();
+                write!(string, "{}", $addr).unwrap();
+                assert_eq!(formatcp!("{}", $addr), string.as_str());
+                assert_eq!(cfmt_b::concatcp!($addr), string.as_str());
+
+                #[cfg(feature = "fmt")]
+                assert_eq!(formatc!("{}", $addr), string.as_str());
+
+                string.clear();
+                write!(string, "{:?}", $addr).unwrap();
+                assert_eq!(formatcp!("{:?}", $addr), string.as_str());
+
+                #[cfg(feature = "fmt")]
+                assert_eq!(formatc!("{:?}", $addr), string.as_str());
+            )*
+        )
+    }
+
+    same_as_std! {
+        IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
+        IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
+        IpAddr::V4(Ipv4Addr::new(255, 255, 255, 255)),
+        IpAddr::V4(Ipv4Addr::new(10, 99, 100, 9)),
+        IpAddr::V4(Ipv4Addr::new(192, 168, 1, 254)),
+        IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+        IpAddr::V6(Ipv6Addr::LOCALHOST),
+        IpAddr::V6(Ipv6Addr::new(1, 0, 0, 0, 0, 0, 0, 0)),
+        IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)),
+        IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 1, 0, 0, 0, 1)),
+        IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 1, 0, 0, 1)),
+        IpAddr::V6(Ipv6Addr::new(0x2001, 0, 0, 1, 0, 0, 0, 0)),
+        IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 1, 1, 1, 1, 1)),
+        IpAddr::V6(Ipv6Addr::new(0, 0xa, 0, 0xbc, 0, 0xdef, 0, 0x1234)),
+        IpAddr::V6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0x202, 0xb3ff, 0xfe1e, 0x8329)),
+        IpAddr::V6(Ipv6Addr::new(0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff)),
+        IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0xc000, 0x0201)),
+        IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0xc000, 0x0201)),
+        IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0xffff, 0, 0xc000, 0x0201)),
+    }
+
+    const LOCALHOST: &str = formatcp!("http://{}:{}/", Ipv4Addr::LOCALHOST, 8080u16);
+    assert_eq!(LOCALHOST, "http://127.0.0.1:8080/");
+
+    const V6: &str = formatcp!("[{}]", Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+    assert_eq!(V6, "[2001:db8::1]");
+}
+
+#[test]
+fn durations() {
+    use core::time::Duration;
+
+    let mut string = ArrayString::<[u8; 64]>::new();
+
+    macro_rules! same_as_std {
+        ($($expr:expr),* $(,)?) => (
+            $({
+                const DURATION: Duration = $expr;
+
+                string.clear();
+                write!(string, "{:?}", DURATION).unwrap();
+                assert_eq!(formatcp!("{:?}", DURATION), string.as_str());
+
+                #[cfg(feature = "fmt")]
+                assert_eq!(formatc!("{:?}", DURATION), string.as_str());
+            })*
+        )
+    }
+
+    same_as_std! {
+        Duration::from_nanos(0),
+        Duration::from_nanos(100),
+        Duration::from_nanos(999),
+        Duration::from_nanos(1_000),
+        Duration::from_micros(1500),
+        Duration::from_micros(999_999),
+        Duration::from_millis(1),
+        Duration::from_millis(1500),
+        Duration::from_nanos(1_000_001),
+        Duration::from_secs(5),
+        Duration::new(1, 1),
+        Duration::new(10, 120_000_000),
+        Duration::new(u64::MAX, 999_999_999),
+    }
+
+    const TIMEOUT: &str = formatcp!("timeout={:?}", Duration::from_micros(1500));
+    assert_eq!(TIMEOUT, "timeout=1.5ms");
+
+    assert_eq!(formatcp!("{:?}", Duration::from_nanos(100)), "100ns");
+    assert_eq!(formatcp!("{:?}", Duration::from_nanos(0)), "0ns");
+}
+
+#[test]
+fn is_valid_ident() {
+    use cfmt_b::is_valid_ident;
+
+    const VALID: bool = is_valid_ident!("foo_bar");
+    assert!(VALID);
+    assert!(is_valid_ident!("FooBar2"));
+    assert!(is_valid_ident!("__"));
+    assert!(is_valid_ident!("鉄"));
+    assert!(is_valid_ident!(r"raw_literal"));
+    assert!(is_valid_ident!("union"));
+
+    // keywords
+    assert!(!is_valid_ident!("type"));
+    assert!(!is_valid_ident!("async"));
+    assert!(!is_valid_ident!("Self"));
+    assert!(is_valid_ident!("r#type"));
+    assert!(is_valid_ident!("r#async"));
+    assert!(!is_valid_ident!("r#Self"));
+
+    // invalid strings
+    assert!(!is_valid_ident!(""));
+    assert!(!is_valid_ident!("_"));
+    assert!(!is_valid_ident!("r#"));
+    assert!(!is_valid_ident!("0x"));
+    assert!(!is_valid_ident!("foo bar"));
+    assert!(!is_valid_ident!("foo::bar"));
+    assert!(!is_valid_ident!("a\nb"));
+}
+
+#[test]
+fn interp_env() {
+    use cfmt_b::interp_env;
+
+    const NAME: &str = interp_env!("${CARGO_PKG_NAME}");
+    assert_eq!(NAME, env!("CARGO_PKG_NAME"));
+
+    assert_eq!(
+        interp_env!("name=${CARGO_PKG_NAME}:${CARGO_PKG_VERSION_MAJOR}.${CARGO_PKG_VERSION_MINOR}"),
+        concat!(
+            "name=",
+            env!("CARGO_PKG_NAME"),
+            ":",
+            env!("CARGO_PKG_VERSION_MAJOR"),
+            ".",
+            env!("CARGO_PKG_VERSION_MINOR"),
+        ),
+    );
+
+    // no substitutions
+    assert_eq!(interp_env!(""), "");
+    assert_eq!(interp_env!("{} {{}} {0}"), "{} {{}} {0}");
+
+    // escapes
+    assert_eq!(interp_env!("$$"), "$");
+    assert_eq!(interp_env!("$$$$"), "$$");
+    assert_eq!(interp_env!("$${CARGO_PKG_NAME}"), "${CARGO_PKG_NAME}");
+    assert_eq!(
+        interp_env!("$$${CARGO_PKG_NAME}$$"),
+        concat!("$", env!("CARGO_PKG_NAME"), "$"),
+    );
+
+    // the text around substitutions keeps its escapes
+    assert_eq!(
+        interp_env!("\t\"${CARGO_PKG_NAME}\"\u{F1}\n"),
+        concat!("\t\"", env!("CARGO_PKG_NAME"), "\"ñ\n"),
+    );
+    assert_eq!(
+        interp_env!(r#"\n"${CARGO_PKG_NAME}"#),
+        concat!(r#"\n""#, env!("CARGO_PKG_NAME")),
+    );
+}
+
+#[test]
+fn debug_formatting_of_sliced_strs() {
+    use cfmt_b::{str_get, str_index};
+
+    // Not using `'` here, because `{:?}` escapes it in strings, unlike std.
+    const S: &str = "a\"b\n鉄錆ñ";
+
+    const INDEXED_P: &str = formatcp!("{:?}|{:?}|{0}", str_index!(S, 0..3), str_index!(S, 4..));
+    assert_eq!(
+        INDEXED_P,
+        format!("{:?}|{:?}|{0}", &S[0..3], &S[4..]).as_str()
+    );
+
+    #[cfg(feature = "fmt")]
+    {
+        const INDEXED: &str = formatc!(
+            "{:?}|{:?}|{:?}|{:?}",
+            str_index!(S, 0..3),
+            str_index!(S, 4..),
+            str_get!(S, 7..),
+            str_get!(S, 5..),
+        );
+        assert_eq!(
+            INDEXED,
+            format!(
+                "{:?}|{:?}|{:?}|{:?}",
+                &S[0..3],
+                &S[4..],
+                S.get(7..),
+                S.get(5..)
+            )
+            .as_str(),
+        );
+
+        const INDEXED_ALT: &str = formatc!("{:#?}", str_index!(S, 2..4));
+        assert_eq!(INDEXED_ALT, format!("{:#?}", &S[2..4]).as_str());
+    }
+}
@@ -0,0 +1,102 @@
+use cfmt_b::{
+    fmt::{FormattingFlags, NumberFormatting},
+    ScientificNotation,
+};
+
+#[test]
+fn merge_takes_set_fields_from_override() {
+    const BASE: FormattingFlags = FormattingFlags::NEW.set_max_depth(Some(3));
+    const OVER: FormattingFlags = FormattingFlags::NEW.set_binary();
+    const MERGED: FormattingFlags = FormattingFlags::merge(BASE, OVER);
+
+    assert_eq!(MERGED.num_fmt(), NumberFormatting::Binary);
+    assert_eq!(MERGED.max_depth(), Some(3));
+    assert!(!MERGED.is_alternate());
+
+    let grouped = FormattingFlags::NEW.set_digit_grouping(Some('_'));
+    assert_eq!(
+        FormattingFlags::merge(grouped, OVER).digit_grouping(),
+        Some('_')
+    );
+    assert_eq!(
+        FormattingFlags::merge(OVER, grouped).digit_grouping(),
+        Some('_')
+    );
+    assert_eq!(
+        FormattingFlags::merge(grouped, FormattingFlags::NEW.set_digit_grouping(None))
+            .digit_grouping(),
+        None
+    );
+
+    let sci = FormattingFlags::NEW.set_scientific(Some(ScientificNotation::LOWER));
+    assert_eq!(
+        FormattingFlags::merge(sci, OVER).scientific(),
+        Some(ScientificNotation::LOWER)
+    );
+    assert_eq!(
+        FormattingFlags::merge(FormattingFlags::NEW.set_scientific(None), sci).scientific(),
+        Some(ScientificNotation::LOWER)
+    );
+    assert_eq!(
+        FormattingFlags::merge(sci, FormattingFlags::NEW.set_scientific(None)).scientific(),
+        None
+    );
+    assert_ne!(
+        sci,
+        FormattingFlags::NEW.set_scientific(Some(ScientificNotation::LOWER.set_precision(Some(0))))
+    );
+
+    // the override takes precedence over fields that are set in both
+    let base = FormattingFlags::NEW
+        .set_hexadecimal()
+        .set_alternate(true)
+        .set_max_depth(Some(1));
+    let over = FormattingFlags::NEW
+        .set_decimal()
+        .set_alternate(false)
+        .set_max_depth(None);
+    let merged = FormattingFlags::merge(base, over);
+    assert_eq!(merged, over);
+    assert_eq!(merged.num_fmt(), NumberFormatting::Decimal);
+    assert_eq!(merged.max_depth(), None);
+    assert!(!merged.is_alternate());
+}
+
+#[test]
+fn merge_unset_fields() {
+    let base = FormattingFlags::NEW.set_alternate(true).set_hexadecimal();
+
+    assert_eq!(FormattingFlags::merge(base, FormattingFlags::NEW), base);
+    assert_eq!(FormattingFlags::merge(FormattingFlags::NEW, base), base);
+    assert_eq!(
+        FormattingFlags::merge(FormattingFlags::NEW, FormattingFlags::NEW),
+        FormattingFlags::NEW,
+    );
+
+    // setting a field to its default value still overrides the base
+    let over = FormattingFlags::NEW.set_alternate(false);
+    assert_eq!(over, FormattingFlags::NEW);
+    let merged = FormattingFlags::merge(base, over);
+    assert!(!merged.is_alternate());
+    assert_eq!(merged.num_fmt(), NumberFormatting::Hexadecimal);
+
+    // the fields set in either argument stay set after merging
+    let layered = FormattingFlags::merge(FormattingFlags::merge(base, over), FormattingFlags::NEW);
+    assert_eq!(layered, merged);
+    let layered = FormattingFlags::merge(FormattingFlags::NEW.set_max_depth(Some(0)), merged);
+    assert_eq!(layered, merged.set_max_depth(Some(0)));
+    assert_eq!(layered.num_fmt(), NumberFormatting::Hexadecimal);
+    assert!(!layered.is_alternate());
+}
+
+#[test]
+fn set_fields_dont_affect_equality_or_debug() {
+    let set = FormattingFlags::NEW.set_decimal().set_max_depth(None);
+    assert_eq!(set, FormattingFlags::NEW);
+    assert!(set.const_eq(&FormattingFlags::NEW));
+    assert_eq!(
+        format!("{:?}", set),
+        "FormattingFlags { num_fmt: Decimal, is_alternate: false, max_depth: None, \
+         digit_grouping: None, scientific: None }",
+    );
+}
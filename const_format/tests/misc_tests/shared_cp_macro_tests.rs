@@ -90,3 +90,78 @@ fn other_tests() {
         assert_eq!(EMPTY, "");
     }
 }
+
+#[test]
+fn nested_concatcp() {
+    const A: &str = "foo";
+    const B: u8 = 10;
+
+    // two levels
+    assert_eq!(concatcp!(concatcp!(A, B), "-"), "foo10-");
+    assert_eq!(concatcp!("-", concatcp!(A, B)), "-foo10");
+    assert_eq!(concatcp!(concatcp!(), concatcp!()), "");
+
+    // three levels
+    const NESTED: &str = concatcp!(
+        concatcp!(concatcp!(A, '/', B), "|", concatcp!(true, -3i8)),
+        concatcp!(concatcp!(), ';'),
+        concatcp!(concatcp!('ñ', 'a'), concatcp!("b")),
+    );
+    assert_eq!(NESTED, "foo/10|true-3;ñab");
+
+    // the inner macro's output is a `&'static str`, usable as a constant
+    const INNER: &str = concatcp!(concatcp!(A, B), concatcp!(B, A));
+    assert_eq!(concatcp!(INNER, INNER), "foo1010foofoo1010foo");
+
+    // nesting `formatcp` and `concatcp` within each other
+    assert_eq!(
+        formatcp!(
+            "{}:{:?}",
+            concatcp!(A, concatcp!(B)),
+            concatcp!(concatcp!(B, A))
+        ),
+        r#"foo10:"10foo""#,
+    );
+    assert_eq!(
+        concatcp!(formatcp!("{}{}", concatcp!(A), B), formatcp!("{A}")),
+        "foo10foo",
+    );
+
+    #[cfg(feature = "fmt")]
+    {
+        const NESTED_C: &str = concatc!(concatcp!(concatcp!(A, B), concatc!(B, A)), "!");
+        assert_eq!(NESTED_C, "foo1010foo!");
+    }
+}
+
+#[test]
+fn concatcp_formatting_specs() {
+    const N: u16 = 0xFF;
+
+    // mixing plain and formatted arguments
+    assert_eq!(concatcp!((0xFFu16, "#x"), "-", 10u8), "0xFF-10");
+    assert_eq!(
+        concatcp!("[", (N, "x"), "|", (N, "b"), "|", (N, "#b"), "]"),
+        formatcp!("[{0:x}|{0:b}|{0:#b}]", N),
+    );
+    assert_eq!(concatcp!((N, "")), concatcp!(N));
+    assert_eq!(concatcp!((N, "?"), (-1i8, "#?")), "255-1");
+    assert_eq!(concatcp!(("foo\n", "?"), "foo\n"), "\"foo\\n\"foo\n");
+    assert_eq!(concatcp!(('\'', "?"), (true, "?")), r#"'\''true"#);
+
+    // `{:bool(on,off)}` formatting
+    assert_eq!(
+        concatcp!((true, "bool(on,off)"), "/", (false, "bool(on,off)")),
+        "on/off"
+    );
+
+    // trailing comma, raw string specs, and expressions with commas
+    assert_eq!(concatcp!((N, "x",), (N, r"#x")), "FF0xFF");
+    assert_eq!(
+        concatcp!((core::convert::identity::<u16>(N), "b")),
+        "11111111"
+    );
+
+    // a parenthesized expression isn't a formatting spec
+    assert_eq!(concatcp!(("foo"), ('a')), "fooa");
+}
@@ -1,7 +1,7 @@
 // Don't need the tests for this macro to be thorough,
 // since this uses a lot of the same machinery as `formatcp` and `formatc`
 
-use cfmt_b::fmt::{Error, Formatter, FormattingFlags, StrWriter};
+use cfmt_b::fmt::{ComputeStrLength, Error, Formatter, FormattingFlags, StrWriter};
 use cfmt_b::{try_, writec};
 
 struct Foo {
@@ -58,6 +58,29 @@ fn repeated_positional_args() {
     );
 }
 
+#[test]
+fn reused_args_are_evaluated_once() {
+    let mut evaluated = 0u32;
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 256]);
+    writec!(
+        writer,
+        "{0},{0:?},{n},{0:x},{n:?},{n}",
+        {
+            evaluated += 1;
+            "hello"
+        },
+        n = {
+            evaluated += 10;
+            100u8
+        },
+    )
+    .unwrap();
+
+    assert_eq!(writer.as_str(), "hello,\"hello\",100,\"hello\",100,100");
+    assert_eq!(evaluated, 11);
+}
+
 #[test]
 fn write_from_consts() {
     const FOO: Foo = Foo {
@@ -170,3 +193,229 @@ fn access_formatter() {
         "
     );
 }
+
+#[test]
+fn compute_length() {
+    const fn inner(
+        writer: &mut StrWriter,
+        computer: &mut ComputeStrLength,
+        foo: &Foo,
+    ) -> Result<(), Error> {
+        try_!(writec!(writer, "{0:?}{1}{0:x}", foo.x, foo.y));
+        try_!(writec!(writer, "{:#?}", [foo.y, foo.y]));
+
+        try_!(writec!(computer, "{0:?}{1}{0:x}", foo.x, foo.y));
+        try_!(writec!(computer, "{:#?}", [foo.y, foo.y]));
+        Ok(())
+    }
+
+    const LEN: usize = {
+        let mut computer = ComputeStrLength::new();
+        cfmt_b::unwrap!(writec!(computer, "{:?}-{}", "hello\nworld", 100u8));
+        computer.len()
+    };
+    let writer: &mut StrWriter = &mut StrWriter::new([0; LEN]);
+    writec!(writer, "{:?}-{}", "hello\nworld", 100u8).unwrap();
+    assert_eq!(writer.as_str(), r#""hello\nworld"-100"#);
+    assert_eq!(writer.len(), LEN);
+
+    let foo = Foo { x: 300, y: "ñ\"" };
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 128]);
+    let computer = &mut ComputeStrLength::new();
+    inner(writer, computer, &foo).unwrap();
+    assert_eq!(
+        writer.as_str(),
+        "300ñ\"12C[\n    \"ñ\\\"\",\n    \"ñ\\\"\",\n]"
+    );
+    assert_eq!(writer.len(), computer.len());
+}
+
+#[test]
+#[cfg(feature = "heapless")]
+fn heapless_string() {
+    use cfmt_b::fmt::HeaplessStringWriter;
+
+    let foo = Foo { x: 3, y: "hello" };
+
+    let mut string = heapless::String::<32>::new();
+    string.push_str("start:").unwrap();
+
+    writec!(string, "{};{:?}", foo.x, foo.y).unwrap();
+    assert_eq!(string, r#"start:3;"hello""#);
+
+    writec!(&mut string, ";{:#x}", 255u8).unwrap();
+    assert_eq!(string, r#"start:3;"hello";0xFF"#);
+
+    {
+        let string = &mut string;
+        writec!(string, ";{x:b}", x = 5u8).unwrap();
+    }
+    assert_eq!(string, r#"start:3;"hello";0xFF;101"#);
+
+    // Fills the string up to its capacity
+    writec!(string, "{}", "-".repeat(8).as_str()).unwrap();
+    assert_eq!(string.len(), 32);
+
+    // Arguments that don't fit aren't written at all
+    assert_eq!(writec!(string, "{}", "a"), Err(Error::NotEnoughSpace));
+    assert_eq!(writec!(string, "{}", 'ñ'), Err(Error::NotEnoughSpace));
+    assert_eq!(string, r#"start:3;"hello";0xFF;101--------"#);
+
+    // Arguments before the one that didn't fit are written
+    let mut string = heapless::String::<4>::new();
+    assert_eq!(writec!(string, "ab{}", "鉄"), Err(Error::NotEnoughSpace));
+    assert_eq!(string, "ab");
+
+    let mut writer = HeaplessStringWriter::new(&mut string);
+    let mut fmt = writer.make_formatter(FormattingFlags::NEW);
+    fmt.write_str("cd").unwrap();
+    assert_eq!(fmt.write_str("e"), Err(Error::NotEnoughSpace));
+    drop(writer);
+    assert_eq!(string, "abcd");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn std_string() {
+    use cfmt_b::fmt::StdStringWriter;
+
+    let foo = Foo { x: 3, y: "hello" };
+
+    let mut string = String::from("start:");
+
+    writec!(string, "{};{:?}", foo.x, foo.y).unwrap();
+    assert_eq!(string, r#"start:3;"hello""#);
+
+    writec!(&mut string, ";{:#x}", 255u8).unwrap();
+    assert_eq!(string, r#"start:3;"hello";0xFF"#);
+
+    {
+        let string = &mut string;
+        writec!(string, ";{x:b}", x = 5u8).unwrap();
+    }
+    assert_eq!(string, r#"start:3;"hello";0xFF;101"#);
+
+    // The string grows as needed
+    let long = "ñ-".repeat(1000);
+    writec!(string, "|{}|{:?}|", long.as_str(), [u64::MAX; 100]).unwrap();
+    assert_eq!(
+        string,
+        format!(
+            r#"start:3;"hello";0xFF;101|{}|{:?}|"#,
+            long,
+            [u64::MAX; 100]
+        )
+    );
+
+    // Writing into an empty string with no capacity
+    let mut string = String::new();
+    writec!(string, "{}{}", 'ñ', long.as_str()).unwrap();
+    assert_eq!(string, format!("ñ{}", long));
+
+    // Formatters can only write up to the reserved capacity
+    let mut string = String::with_capacity(2);
+    let mut writer = StdStringWriter::new(&mut string);
+    let mut fmt = writer.make_formatter(FormattingFlags::NEW);
+    fmt.write_str("ab").unwrap();
+    assert_eq!(fmt.write_str("cd"), Err(Error::NotEnoughSpace));
+    writer.reserve(2);
+    let mut fmt = writer.make_formatter(FormattingFlags::NEW);
+    fmt.write_str("cd").unwrap();
+    drop(writer);
+    assert_eq!(string, "abcd");
+}
+
+#[test]
+fn formatc_writer_prefix() {
+    use cfmt_b::formatc_writer;
+
+    const PREFIX: StrWriter<[u8; 24]> = formatc_writer!(24, "{}-{:x}:", "id", 255u8);
+    assert_eq!(PREFIX.r().as_str(), "id-FF:");
+    assert_eq!(PREFIX.r().len(), 6);
+    assert_eq!(PREFIX.r().capacity(), 24);
+
+    let mut writer = PREFIX;
+    writec!(writer, "{:?}", "foo").unwrap();
+    writec!(writer, " {}", 3u8).unwrap();
+    assert_eq!(writer.r().as_str(), r#"id-FF:"foo" 3"#);
+
+    // The constant is unaffected by writing to the copy
+    assert_eq!(PREFIX.r().as_str(), "id-FF:");
+
+    // Filling the writer up to its capacity
+    const FULL: StrWriter<[u8; 4]> = formatc_writer!(4, "{N}{N}", N = 10u8);
+    let mut full = FULL;
+    assert_eq!(full.r().as_str(), "1010");
+    assert_eq!(writec!(full, "a"), Err(Error::NotEnoughSpace));
+
+    const EMPTY: StrWriter<[u8; 0]> = formatc_writer!(0, "");
+    assert_eq!(EMPTY.r().as_str(), "");
+
+    // Custom formatting
+    const CUSTOM: StrWriter<[u8; 8]> = formatc_writer!(8, "<{}>", |fmt| fmt.write_str("ab"));
+    assert_eq!(CUSTOM.r().as_str(), "<ab>");
+}
+
+#[test]
+fn writecln_macro() {
+    use cfmt_b::writecln;
+
+    const fn inner(writer: &mut StrWriter, n: u32) -> Result<(), Error> {
+        writer.clear();
+        try_!(writecln!(writer, "first line"));
+        try_!(writecln!(writer, "n = {}, {n:?}", n,));
+        Ok(())
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 40]);
+
+    inner(writer, 3).unwrap();
+    assert_eq!(writer.as_str(), "first line\nn = 3, 3\n");
+
+    // the newline-only form
+    writer.clear();
+    writecln!(writer).unwrap();
+    writecln!(writer,).unwrap();
+    assert_eq!(writer.as_str(), "\n\n");
+
+    // errors are propagated
+    let small: &mut StrWriter = &mut StrWriter::new([0; 11]);
+    assert_eq!(inner(small, 3), Err(Error::NotEnoughSpace));
+    assert_eq!(small.as_str(), "first line\n");
+
+    let full: &mut StrWriter = &mut StrWriter::new([0; 0]);
+    assert_eq!(writecln!(full), Err(Error::NotEnoughSpace));
+}
+
+#[test]
+fn cond_fmt_macro() {
+    use cfmt_b::cond_fmt;
+
+    const fn inner<const DEBUG: bool>(writer: &mut StrWriter, n: u32) -> Result<(), Error> {
+        writer.clear();
+        try_!(writec!(writer, "start"));
+        cond_fmt!(writer, DEBUG, " (n = {}, {n:?})", n);
+        cond_fmt!(writer, n > 5, " big");
+        cond_fmt!(writer, true, " end",);
+        Ok(())
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 40]);
+
+    inner::<false>(writer, 3).unwrap();
+    assert_eq!(writer.as_str(), "start end");
+
+    inner::<true>(writer, 3).unwrap();
+    assert_eq!(writer.as_str(), "start (n = 3, 3) end");
+
+    inner::<true>(writer, 8).unwrap();
+    assert_eq!(writer.as_str(), "start (n = 8, 8) big end");
+
+    // errors are propagated, skipping all the writes after them
+    let small: &mut StrWriter = &mut StrWriter::new([0; 13]);
+    assert_eq!(inner::<true>(small, 8), Err(Error::NotEnoughSpace));
+    assert_eq!(small.as_str(), "start (n = 8");
+
+    inner::<false>(small, 8).unwrap();
+    assert_eq!(small.as_str(), "start big end");
+}
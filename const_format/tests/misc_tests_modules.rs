@@ -3,6 +3,9 @@
 extern crate const_format as cfmt_b;
 extern crate self as const_format;
 
+#[cfg(feature = "heapless")]
+extern crate heapless_crate as heapless;
+
 // Making sure that `const_format` points at this test crate.
 pub const NOT_CF: usize = 13;
 pub const _ASSERT_NOT_CF: [(); 13] = [(); const_format::NOT_CF];
@@ -34,11 +37,13 @@ mod misc_tests {
     #[cfg(not(feature = "only_new_tests"))]
     mod formatc_macros;
 
+    #[cfg(feature = "fmt")]
+    mod formatting_flags_tests;
+
     #[cfg(feature = "fmt")]
     #[cfg(not(feature = "only_new_tests"))]
     mod impl_fmt_macro_tests;
 
-    #[cfg(feature = "assertc")]
     #[cfg(not(feature = "only_new_tests"))]
     mod shared_cp_macro_tests;
 
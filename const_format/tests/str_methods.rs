@@ -1,9 +1,121 @@
 mod str_methods_modules {
+    #[cfg(feature = "const_generics")]
+    mod align_table;
+
+    #[cfg(feature = "const_generics")]
+    mod binary_padded;
+
+    #[cfg(feature = "const_generics")]
+    mod capitalize;
+
+    mod char_to_str;
+
+    mod checksum;
+
+    mod clamp_len;
+
+    mod colorize;
+
+    mod common_affix;
+
+    #[cfg(feature = "const_generics")]
+    mod concat_range;
+
+    mod count_char;
+
+    mod cstr;
+
     #[cfg(feature = "const_generics")]
     mod conv_ascii_case;
 
+    #[cfg(feature = "const_generics")]
+    mod deaccent;
+
+    #[cfg(feature = "const_generics")]
+    mod dedup_whitespace;
+
+    #[cfg(feature = "const_generics")]
+    mod escape_debug;
+
+    #[cfg(feature = "const_generics")]
+    mod escape_html;
+
+    #[cfg(feature = "const_generics")]
+    mod grid;
+
+    #[cfg(feature = "const_generics")]
+    mod hex_decode;
+
+    #[cfg(feature = "const_generics")]
+    mod hex_dump;
+
+    mod index_conversion;
+
+    #[cfg(feature = "const_generics")]
+    mod ident_case;
+
+    #[cfg(feature = "const_generics")]
+    mod json_escape;
+
+    #[cfg(feature = "const_generics")]
+    mod mask_middle;
+
+    #[cfg(feature = "const_generics")]
+    mod newlines;
+
+    mod parse_bool;
+
+    #[cfg(feature = "const_generics")]
+    mod raw_literal;
+
+    mod repeat_to_width;
+
+    mod replace_range;
+
+    #[cfg(feature = "const_generics")]
+    mod slugify;
+
+    mod str_match;
+
+    mod str_min_max;
+
+    mod str_lines;
+
+    #[cfg(feature = "const_generics")]
+    mod str_pad;
+
     #[cfg(feature = "const_generics")]
     mod str_replace;
 
+    #[cfg(feature = "const_generics")]
+    mod str_reverse;
+
     mod str_splice;
+
+    #[cfg(feature = "const_generics")]
+    mod str_splitn;
+
+    #[cfg(feature = "const_generics")]
+    mod strip_ansi;
+
+    #[cfg(feature = "const_generics")]
+    mod strip_comments;
+
+    mod strip_bom;
+
+    #[cfg(feature = "const_generics")]
+    mod swap_case;
+
+    #[cfg(feature = "const_generics")]
+    mod title_from_snake;
+
+    mod truncate_words;
+
+    mod word_count;
+
+    #[cfg(feature = "const_generics")]
+    mod wrap_text;
+
+    #[cfg(feature = "const_generics")]
+    mod zip_join;
 }
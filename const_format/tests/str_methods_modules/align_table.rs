@@ -0,0 +1,83 @@
+use const_format::__str_methods::{align_table_bytes, AlignTableArgs};
+use const_format::align_table;
+
+macro_rules! assert_case {
+    ($cols:expr, $table:expr, $separator:expr, $output:expr $(,)*) => {{
+        const TABLE: &[[&str; $cols]] = &$table;
+        const OUT: &str = $output;
+        const ARGS: AlignTableArgs<$cols> = AlignTableArgs::new(TABLE, $separator);
+
+        assert_eq!(ARGS.out_len, OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&align_table_bytes::<$cols, { ARGS.out_len }>(&ARGS)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!(align_table!(TABLE, $separator), OUT);
+    }};
+}
+
+#[test]
+fn test_align_table() {
+    assert_case! {2, [], " ", ""}
+    assert_case! {0, [[], []], " ", "\n"}
+    assert_case! {1, [["a"]], " ", "a"}
+    assert_case! {1, [["a"], [""], ["abc"]], " ", "  a\n   \nabc"}
+    assert_case! {2, [["a", "b"]], "", "ab"}
+    assert_case! {2, [["a", "b"]], " | ", "a | b"}
+
+    // ragged content
+    assert_case! {
+        3,
+        [["a", "bbbb", "cc"], ["aaa", "b", "c"], ["aa", "bb", "cccc"]],
+        " ",
+        "  a bbbb   cc\naaa    b    c\n aa   bb cccc",
+    }
+
+    // empty cells
+    assert_case! {
+        3,
+        [["", "x", ""], ["yy", "", ""], ["", "", "z"]],
+        "|",
+        "  |x| \nyy| | \n  | |z",
+    }
+    assert_case! {2, [["", ""], ["", ""]], ",", ",\n,"}
+
+    // widths are measured in chars
+    assert_case! {2, [["ñandú", "鉄"], ["ab", "錆錆"]], "-", "ñandú- 鉄\n   ab-錆錆"}
+    assert_case! {2, [["€", "a"], ["", "b"]], " ◆ ", "€ ◆ a\n  ◆ b"}
+}
+
+#[test]
+fn compare_with_std() {
+    const TABLE: [[&str; 3]; 3] = [
+        ["key", "value", "description"],
+        ["verbose", "false", ""],
+        ["jobs", "16", "number of threads"],
+    ];
+
+    let widths: Vec<usize> = (0..3)
+        .map(|c| {
+            TABLE
+                .iter()
+                .map(|row| row[c].chars().count())
+                .max()
+                .unwrap()
+        })
+        .collect();
+
+    let expected = TABLE
+        .iter()
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, &width)| format!("{:>width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join(" : ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert_eq!(align_table!(TABLE, " : "), expected);
+}
@@ -0,0 +1,83 @@
+use const_format::__str_methods::{binary_padded_bytes, BinaryPaddedArgs};
+use const_format::pmr::PConvWrapper;
+use const_format::to_binary_padded;
+
+macro_rules! assert_case {
+    ($value:expr, $bits:expr, $output:expr $(,)*) => {{
+        const OUT: &str = $output;
+        const ARGS: BinaryPaddedArgs =
+            BinaryPaddedArgs::new(PConvWrapper($value).to_integer(), $bits);
+
+        assert!(ARGS.fits);
+        assert_eq!(ARGS.bits, OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&binary_padded_bytes::<{ ARGS.bits }>(&ARGS)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!(to_binary_padded!($value, $bits), OUT);
+    }};
+}
+
+#[test]
+fn exact_fit() {
+    assert_case! {0u8, 0, ""}
+    assert_case! {1u8, 1, "1"}
+    assert_case! {0b101u8, 3, "101"}
+    assert_case! {u8::MAX, 8, "11111111"}
+    assert_case! {0x8000u16, 16, "1000000000000000"}
+
+    assert_eq!(to_binary_padded!(u128::MAX, 128), "1".repeat(128));
+}
+
+#[test]
+fn negative() {
+    assert_case! {-1i8, 8, "11111111"}
+    assert_case! {-2i8, 8, "11111110"}
+    assert_case! {i8::MIN, 8, "10000000"}
+    assert_case! {-1i16, 16, "1111111111111111"}
+
+    assert_eq!(to_binary_padded!(-1i128, 128), "1".repeat(128));
+    assert_eq!(to_binary_padded!(-5i32, 32), format!("{:b}", -5i32));
+    assert_eq!(
+        to_binary_padded!(i128::MIN, 128),
+        format!("{:b}", i128::MIN)
+    );
+    assert_eq!(to_binary_padded!(-1i8, 9), "011111111");
+}
+
+#[test]
+fn padded() {
+    assert_case! {0u8, 1, "0"}
+    assert_case! {0u8, 4, "0000"}
+    assert_case! {5u8, 8, "00000101"}
+    assert_case! {1u16, 5, "00001"}
+    assert_eq!(to_binary_padded!(0u128, 128), "0".repeat(128));
+
+    assert_eq!(
+        to_binary_padded!(12345u32, 32),
+        format!("{:032b}", 12345u32)
+    );
+    assert_eq!(
+        to_binary_padded!(1u128 << 100, 101),
+        format!("{:0101b}", 1u128 << 100)
+    );
+}
+
+#[test]
+fn overflow() {
+    macro_rules! assert_doesnt_fit {
+        ($value:expr, $bits:expr) => {
+            assert!(!BinaryPaddedArgs::new(PConvWrapper($value).to_integer(), $bits).fits);
+        };
+    }
+
+    assert_doesnt_fit!(1u8, 0);
+    assert_doesnt_fit!(5u8, 2);
+    assert_doesnt_fit!(256u16, 8);
+    assert_doesnt_fit!(u128::MAX, 127);
+    assert_doesnt_fit!(0u8, 129);
+    assert_doesnt_fit!(-1i8, 7);
+    assert_doesnt_fit!(i8::MIN, 7);
+}
@@ -0,0 +1,41 @@
+use const_format::capitalize;
+
+#[test]
+fn capitalize_ascii() {
+    assert_eq!(capitalize!("hello"), "Hello");
+    assert_eq!(capitalize!("hello world"), "Hello world");
+    assert_eq!(capitalize!("a"), "A");
+    assert_eq!(capitalize!("z_index"), "Z_index");
+
+    // the rest of the string is left unchanged
+    assert_eq!(capitalize!("hELLO wORLD"), "HELLO wORLD");
+    assert_eq!(capitalize!("snake_case_name"), "Snake_case_name");
+}
+
+#[test]
+fn capitalize_unchanged() {
+    assert_eq!(capitalize!(""), "");
+    assert_eq!(capitalize!("Hello"), "Hello");
+    assert_eq!(capitalize!("HELLO"), "HELLO");
+
+    // leading non-letters
+    assert_eq!(capitalize!("42 apples"), "42 apples");
+    assert_eq!(capitalize!(" hello"), " hello");
+    assert_eq!(capitalize!("_hello"), "_hello");
+    assert_eq!(capitalize!("¿qué?"), "¿qué?");
+
+    // only ASCII letters are uppercased
+    assert_eq!(capitalize!("éclair"), "éclair");
+    assert_eq!(capitalize!("ñandú"), "ñandú");
+    assert_eq!(capitalize!("😀 face"), "😀 face");
+}
+
+#[test]
+fn capitalize_constants() {
+    const NAME: &str = "retry count";
+    const LABEL: &str = capitalize!(NAME);
+    assert_eq!(LABEL, "Retry count");
+
+    const MULTIBYTE: &str = capitalize!("añó");
+    assert_eq!(MULTIBYTE, "Añó");
+}
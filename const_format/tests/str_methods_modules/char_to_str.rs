@@ -0,0 +1,33 @@
+use const_format::__str_methods::CharToStrArgs;
+use const_format::char_to_str;
+
+macro_rules! assert_case {
+    ($($char:expr),* $(,)*) => {$({
+        const C: char = $char;
+        const ARGS: CharToStrArgs = CharToStrArgs::new(C);
+        const OUT: &str = char_to_str!(C);
+
+        let mut buffer = [0u8; 4];
+        let expected: &str = C.encode_utf8(&mut buffer);
+
+        assert_eq!(ARGS.len, expected.len());
+        assert_eq!(&ARGS.encoded[..ARGS.len], expected.as_bytes());
+
+        assert_eq!(OUT, expected);
+    })*};
+}
+
+#[test]
+fn test_char_to_str() {
+    // 1 byte
+    assert_case! {'\0', '\n', 'A', '~', '\x7F'}
+
+    // 2 bytes
+    assert_case! {'\u{80}', 'ñ', 'Ω', '\u{7FF}'}
+
+    // 3 bytes
+    assert_case! {'\u{800}', '€', '鉄', '\u{FFFF}'}
+
+    // 4 bytes
+    assert_case! {'\u{10000}', '😀', '\u{10FFFF}'}
+}
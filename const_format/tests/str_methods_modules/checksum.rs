@@ -0,0 +1,55 @@
+use const_format::__str_methods::{crc32, fnv1a};
+use const_format::{crc32, fnv1a, formatcp};
+
+macro_rules! assert_case {
+    ($input:expr, $crc32:expr, $fnv1a:expr $(,)*) => {{
+        const IN: &str = $input;
+        const CRC32: u32 = crc32(IN.as_bytes());
+        const FNV1A: u64 = fnv1a(IN.as_bytes());
+
+        assert_eq!(CRC32, $crc32, "input: {:?}", IN);
+        assert_eq!(FNV1A, $fnv1a, "input: {:?}", IN);
+        assert_eq!(crc32!(IN), $crc32);
+        assert_eq!(crc32!(IN.as_bytes()), $crc32);
+        assert_eq!(fnv1a!(IN), $fnv1a);
+        assert_eq!(fnv1a!(IN.as_bytes()), $fnv1a);
+    }};
+}
+
+#[test]
+fn test_known_vectors() {
+    assert_case! {"", 0, 0xcbf29ce484222325}
+    assert_case! {"a", 0xE8B7BE43, 0xaf63dc4c8601ec8c}
+    assert_case! {"foobar", 0x9EF61F95, 0x85944171f73967e8}
+    assert_case! {"123456789", 0xCBF43926, 0x06d5573923c6cdfc}
+    assert_case! {
+        "The quick brown fox jumps over the lazy dog",
+        0x414FA339,
+        0xf3f9b7f5e7e47110,
+    }
+}
+
+#[test]
+fn test_non_utf8_bytes() {
+    const BYTES: &[u8] = &[0xFF, 0x00, 0x80, 0xFE];
+    assert_eq!(crc32!(BYTES), crc32(BYTES));
+    assert_eq!(fnv1a!(BYTES), fnv1a(BYTES));
+
+    const PREFIX: &[u8] = &[0xFF, 0x00, 0x80];
+    assert_ne!(crc32!(BYTES), crc32!(PREFIX));
+    assert_ne!(fnv1a!(BYTES), fnv1a!(PREFIX));
+}
+
+#[cfg(feature = "const_generics")]
+#[test]
+fn test_byte_arrays() {
+    assert_eq!(crc32!(b"123456789"), 0xCBF43926);
+    assert_eq!(fnv1a!(b"foobar"), 0x85944171f73967e8);
+}
+
+#[test]
+fn test_in_formatting() {
+    const DATA: &str = "123456789";
+    const TAG: &str = formatcp!("{}:{:x}", DATA, crc32!(DATA));
+    assert_eq!(TAG, "123456789:CBF43926");
+}
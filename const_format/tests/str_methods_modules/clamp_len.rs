@@ -0,0 +1,59 @@
+use const_format::__str_methods::clamp_len;
+use const_format::clamp_len;
+
+#[test]
+fn clamp_len_fn() {
+    const S: &str = "aé効🦀";
+
+    let lens = [0, 1, 3, 6, 10, 10, 10];
+    for (max, &len) in lens.iter().enumerate() {
+        assert_eq!(clamp_len(S, max), len, "max: {}", max);
+    }
+
+    assert_eq!(clamp_len("", 0), 0);
+    assert_eq!(clamp_len("", 3), 0);
+    assert_eq!(clamp_len("abc", usize::MAX), 3);
+}
+
+#[test]
+fn clamp_len_truncated() {
+    const IN: &str = "hello world";
+    const OUT: &str = clamp_len!(IN, 5);
+    assert_eq!(OUT, "hello");
+    // the string is resliced, not copied
+    assert_eq!(OUT.as_ptr(), IN.as_ptr());
+
+    assert_eq!(clamp_len!(IN, 1), "h");
+    assert_eq!(clamp_len!(IN, 10), "hello worl");
+}
+
+#[test]
+fn clamp_len_multi_byte() {
+    assert_eq!(clamp_len!("ñandú", 1), "ñ");
+    assert_eq!(clamp_len!("ñandú", 4), "ñand");
+    assert_eq!(clamp_len!("ñandú", 5), "ñandú");
+    assert_eq!(clamp_len!("鉄錆鋼", 2), "鉄錆");
+    assert_eq!(clamp_len!("a🦀b🦀", 2), "a🦀");
+    assert_eq!(clamp_len!("a🦀b🦀", 3), "a🦀b");
+    // combining characters are separate chars
+    assert_eq!(clamp_len!("n\u{303}o", 1), "n");
+}
+
+#[test]
+fn clamp_len_within_limit() {
+    const IN: &str = "ñandú";
+    const OUT: &str = clamp_len!(IN, 5);
+    assert_eq!(OUT, IN);
+    assert_eq!(OUT.as_ptr(), IN.as_ptr());
+    assert_eq!(OUT.len(), IN.len());
+
+    assert_eq!(clamp_len!(IN, 100), IN);
+    assert_eq!(clamp_len!("", 3), "");
+}
+
+#[test]
+fn clamp_len_zero() {
+    assert_eq!(clamp_len!("hello", 0), "");
+    assert_eq!(clamp_len!("鉄", 0), "");
+    assert_eq!(clamp_len!("", 0), "");
+}
@@ -0,0 +1,63 @@
+use const_format::{colorize, Color};
+
+#[cfg(not(feature = "no_color"))]
+#[test]
+fn test_colors() {
+    assert_eq!(colorize!(Color::Black, "foo"), "\x1b[30mfoo\x1b[0m");
+    assert_eq!(colorize!(Color::Red, "foo"), "\x1b[31mfoo\x1b[0m");
+    assert_eq!(colorize!(Color::White, "foo"), "\x1b[37mfoo\x1b[0m");
+    assert_eq!(colorize!(Color::BrightBlack, "foo"), "\x1b[90mfoo\x1b[0m");
+    assert_eq!(colorize!(Color::BrightWhite, "foo"), "\x1b[97mfoo\x1b[0m");
+
+    assert_eq!(colorize!(Color::Blue, ""), "\x1b[34m\x1b[0m");
+    assert_eq!(colorize!(Color::Yellow, "鉄錆"), "\x1b[33m鉄錆\x1b[0m");
+}
+
+#[cfg(not(feature = "no_color"))]
+#[test]
+fn test_modifiers() {
+    assert_eq!(colorize!(Color::Red, "foo", bold), "\x1b[1;31mfoo\x1b[0m");
+    assert_eq!(
+        colorize!(Color::Red, "foo", underline),
+        "\x1b[4;31mfoo\x1b[0m"
+    );
+    assert_eq!(
+        colorize!(Color::Red, "foo", bold, underline),
+        "\x1b[1;4;31mfoo\x1b[0m"
+    );
+    assert_eq!(
+        colorize!(Color::Red, "foo", underline, bold,),
+        "\x1b[4;1;31mfoo\x1b[0m"
+    );
+}
+
+#[cfg(not(feature = "no_color"))]
+#[test]
+fn test_constants() {
+    const COLOR: Color = Color::Magenta;
+    const S: &str = "bar";
+    const OUT: &str = colorize!(COLOR, S, bold);
+
+    assert_eq!(OUT, "\x1b[1;35mbar\x1b[0m");
+}
+
+#[cfg(feature = "no_color")]
+#[test]
+fn test_no_color() {
+    const COLOR: Color = Color::Magenta;
+    const S: &str = "bar";
+    const OUT: &str = colorize!(COLOR, S, bold);
+
+    assert_eq!(OUT, "bar");
+    assert_eq!(colorize!(Color::Red, "foo"), "foo");
+    assert_eq!(colorize!(Color::Red, "foo", bold, underline), "foo");
+}
+
+#[cfg(feature = "const_generics")]
+#[test]
+fn strip_colorized() {
+    use const_format::strip_ansi;
+
+    const OUT: &str = strip_ansi!(colorize!(Color::Green, "hello", underline));
+    assert_eq!(OUT, "hello");
+}
@@ -0,0 +1,101 @@
+use const_format::__str_methods::CommonAffixArgs;
+use const_format::{common_prefix, common_suffix, str_prefix_len};
+
+fn std_common_prefix<'a>(left: &'a str, right: &str) -> &'a str {
+    let len = left
+        .char_indices()
+        .zip(right.chars())
+        .find(|&((_, l), r)| l != r)
+        .map_or(left.len().min(right.len()), |((i, _), _)| i);
+    &left[..len]
+}
+
+fn std_common_suffix<'a>(left: &'a str, right: &str) -> &'a str {
+    let len: usize = left
+        .chars()
+        .rev()
+        .zip(right.chars().rev())
+        .take_while(|(l, r)| l == r)
+        .map(|(l, _)| l.len_utf8())
+        .sum();
+    &left[left.len() - len..]
+}
+
+macro_rules! assert_case {
+    ($left:expr, $right:expr, $prefix:expr, $suffix:expr $(,)*) => {{
+        const L: &str = $left;
+        const R: &str = $right;
+        const PREFIX: &str = $prefix;
+        const SUFFIX: &str = $suffix;
+
+        assert_eq!(std_common_prefix(L, R), PREFIX);
+        assert_eq!(std_common_suffix(L, R), SUFFIX);
+
+        let prefix = CommonAffixArgs::prefix(L, R);
+        assert_eq!(&L[prefix.start..prefix.start + prefix.len], PREFIX);
+        let suffix = CommonAffixArgs::suffix(L, R);
+        assert_eq!(&L[suffix.start..suffix.start + suffix.len], SUFFIX);
+
+        assert_eq!(common_prefix!(L, R), PREFIX);
+        assert_eq!(common_suffix!(L, R), SUFFIX);
+        assert_eq!(str_prefix_len!(L, R), PREFIX.len());
+
+        // the arguments can be swapped
+        assert_eq!(common_prefix!(R, L), PREFIX);
+        assert_eq!(common_suffix!(R, L), SUFFIX);
+        assert_eq!(str_prefix_len!(R, L), PREFIX.len());
+    }};
+}
+
+#[test]
+fn test_identical() {
+    assert_case! {"", "", "", ""}
+    assert_case! {"a", "a", "a", "a"}
+    assert_case! {"hello", "hello", "hello", "hello"}
+    assert_case! {"鉄錆", "鉄錆", "鉄錆", "鉄錆"}
+}
+
+#[test]
+fn test_disjoint() {
+    assert_case! {"", "foo", "", ""}
+    assert_case! {"foo", "", "", ""}
+    assert_case! {"abc", "xyz", "", ""}
+    assert_case! {"鉄", "錆", "", ""}
+}
+
+#[test]
+fn test_partial() {
+    assert_case! {"foobar", "foobaz", "fooba", ""}
+    assert_case! {"foo", "foobar", "foo", ""}
+    assert_case! {"bar", "foobar", "", "bar"}
+    assert_case! {"/a/b/c.txt", "/a/d/c.txt", "/a/", "/c.txt"}
+    assert_case! {"xñy", "wñy", "", "ñy"}
+    assert_case! {"ñ€😀a😀", "ñ€😁a😀", "ñ€", "a😀"}
+}
+
+#[test]
+fn test_shared_bytes_of_char() {
+    // 'é' is `[0xC3, 0xA9]`, 'ê' is `[0xC3, 0xAA]`
+    assert_case! {"café", "cafê", "caf", ""}
+    // '€' is `[0xE2, 0x82, 0xAC]`, '₠' is `[0xE2, 0x82, 0xA0]`
+    assert_case! {"a€b", "a₠b", "a", "b"}
+    // '©' is `[0xC2, 0xA9]`
+    assert_case! {"é!", "©!", "", "!"}
+    assert_case! {"xé", "x©", "x", ""}
+}
+
+#[test]
+fn test_prefix_len_const() {
+    const BASE: &str = "/usr/lib/";
+    const PATH: &str = "/usr/lib/rustlib/etc";
+    const LEN: usize = str_prefix_len!(BASE, PATH);
+
+    let relative: [u8; PATH.len() - LEN] = *b"rustlib/etc";
+    assert_eq!(&PATH.as_bytes()[LEN..], &relative);
+
+    // the length is on a char boundary of both strings
+    const MULTIBYTE: usize = str_prefix_len!("日本語", "日本人");
+    assert_eq!(MULTIBYTE, "日本".len());
+    assert!("日本語".is_char_boundary(MULTIBYTE));
+    assert!("日本人".is_char_boundary(MULTIBYTE));
+}
@@ -0,0 +1,95 @@
+use const_format::__str_methods::{concat_range_bytes, ConcatRangeArgs, ConcatRangeArgsConv};
+use const_format::concat_range;
+
+macro_rules! assert_case {
+    ($range:expr, $template:expr, $separator:expr, $output:expr $(,)*) => {{
+        const OUT: &str = $output;
+        const ARGS: ConcatRangeArgs = ConcatRangeArgsConv($range, $template, $separator).conv();
+
+        assert!(!ARGS.has_unmatched_brace);
+        assert_eq!(ARGS.out_len, OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&concat_range_bytes::<{ ARGS.out_len }>(&ARGS)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!(concat_range!($range, $template, $separator), OUT);
+    }};
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn separated() {
+    assert_case! {0..5, "{}", ",", "0,1,2,3,4"}
+    assert_case! {0..1, "{}", ",", "0"}
+    assert_case! {3..3, "{}", ",", ""}
+    assert_case! {5..3, "{}", ",", ""}
+    assert_case! {9..12, "[{}]", ", ", "[9], [10], [11]"}
+
+    let expected = (95..105)
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    assert_eq!(concat_range!(95..105, "{}", ","), expected);
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn inclusive() {
+    assert_case! {0..=4, "{}", ",", "0,1,2,3,4"}
+    assert_case! {0..=0, "{}", ",", "0"}
+    assert_case! {3..=2, "{}", ",", ""}
+    assert_case! {5..=3, "{}", ",", ""}
+    assert_case! {9..=11, "[{}]", ", ", "[9], [10], [11]"}
+
+    assert_eq!(concat_range!(1..=3, "v{}"), "v1v2v3");
+}
+
+#[test]
+fn templates() {
+    assert_case! {0..3, "", "", ""}
+    assert_case! {0..3, "a", "", "aaa"}
+    assert_case! {0..3, "ñ", "-", "ñ-ñ-ñ"}
+    assert_case! {1..3, "item{}-", "", "item1-item2-"}
+    assert_case! {1..3, "{}{}", " ", "11 22"}
+    assert_case! {0..2, "{{}}", ",", "{},{}"}
+    assert_case! {0..2, "{{{}}}", ",", "{0},{1}"}
+    assert_case! {0..2, "}}{{", ",", "}{,}{"}
+    assert_case! {0..2, "{{{{}}", "", "{{}{{}"}
+    assert_case! {0..2, "{{{}", ",", "{0,{1"}
+    assert_case! {0..2, "({})", "{{", "(0){{(1)"}
+
+    assert_eq!(concat_range!(0..3, "v{}"), "v0v1v2");
+    assert_eq!(concat_range!(0..3, "v{}",), "v0v1v2");
+    assert_eq!(concat_range!(0..3, "v{}", "; ",), "v0; v1; v2");
+}
+
+#[test]
+fn large_indices() {
+    const START: usize = usize::MAX - 2;
+    assert_eq!(
+        concat_range!(START..usize::MAX, "{}", ","),
+        format!("{},{}", usize::MAX - 2, usize::MAX - 1),
+    );
+    assert_eq!(
+        concat_range!(START..=usize::MAX, "{}", ","),
+        format!("{},{},{}", usize::MAX - 2, usize::MAX - 1, usize::MAX),
+    );
+}
+
+#[test]
+fn unmatched_braces() {
+    macro_rules! assert_unmatched {
+        ($template:expr) => {
+            assert!(ConcatRangeArgs::new(0..2, $template, "").has_unmatched_brace);
+        };
+    }
+
+    assert_unmatched!("{");
+    assert_unmatched!("}");
+    assert_unmatched!("{} {");
+    assert_unmatched!("}{");
+    assert_unmatched!("{{}");
+    assert_unmatched!("{x}");
+}
@@ -0,0 +1,54 @@
+use const_format::__str_methods::{count_leading, count_trailing};
+use const_format::{count_leading, count_trailing};
+
+macro_rules! assert_case {
+    ($input:expr, $char:expr, $leading:expr, $trailing:expr $(,)*) => {{
+        const IN: &str = $input;
+        const C: char = $char;
+        const LEADING: usize = count_leading(IN, C);
+        const TRAILING: usize = count_trailing(IN, C);
+
+        assert_eq!(LEADING, $leading, "input: {:?}", IN);
+        assert_eq!(TRAILING, $trailing, "input: {:?}", IN);
+        assert_eq!(LEADING, IN.chars().take_while(|&c| c == C).count());
+        assert_eq!(TRAILING, IN.chars().rev().take_while(|&c| c == C).count());
+        assert_eq!(count_leading!(IN, C), $leading);
+        assert_eq!(count_trailing!(IN, C), $trailing);
+    }};
+}
+
+#[test]
+fn ascii_chars() {
+    assert_case! {"", ' ', 0, 0}
+    assert_case! {"foo", ' ', 0, 0}
+    assert_case! {"  foo ", ' ', 2, 1}
+    assert_case! {"\t\tfoo\t", '\t', 2, 1}
+    assert_case! {"--foo-bar---", '-', 2, 3}
+
+    // other chars interrupt the count
+    assert_case! {" \t foo \t ", ' ', 1, 1}
+}
+
+#[test]
+fn multi_byte_chars() {
+    assert_case! {"ñññfooñ", 'ñ', 3, 1}
+    assert_case! {"→→x→", '→', 2, 1}
+    assert_case! {"😀😀😀", '😀', 3, 3}
+    assert_case! {"🙂😀", '😀', 0, 1}
+
+    // chars that share their leading bytes with the counted char
+    assert_case! {"ñòñ", 'ñ', 1, 1}
+    assert_case! {"ññ", 'ò', 0, 0}
+
+    // a multi-byte char isn't matched by its ascii-looking bytes
+    assert_case! {"ñ", 'Ã', 0, 0}
+}
+
+#[test]
+fn entirely_the_char() {
+    assert_case! {" ", ' ', 1, 1}
+    assert_case! {"     ", ' ', 5, 5}
+    assert_case! {"ñ", 'ñ', 1, 1}
+    assert_case! {"ññññ", 'ñ', 4, 4}
+    assert_case! {"😀😀", '😀', 2, 2}
+}
@@ -0,0 +1,43 @@
+use const_format::__str_methods::CStrArgs;
+use const_format::cstr;
+
+use std::ffi::CStr;
+
+macro_rules! assert_case {
+    ($input:expr $(,)*) => {{
+        const IN: &str = $input;
+        const OUT: &[u8] = cstr!(IN);
+
+        assert_eq!(CStrArgs::new(IN).nul_pos, None);
+
+        assert_eq!(&OUT[..IN.len()], IN.as_bytes());
+        assert_eq!(OUT.len(), IN.len() + 1);
+
+        let cstr = CStr::from_bytes_with_nul(OUT).unwrap();
+        assert_eq!(cstr.to_str().unwrap(), IN);
+    }};
+}
+
+#[test]
+fn test_valid() {
+    assert_case! {""}
+    assert_case! {"a"}
+    assert_case! {"hello world"}
+    assert_case! {"\x01\x7F\n\t"}
+    assert_case! {"ñandú 鉄錆 😀"}
+
+    assert_eq!(cstr!("foo"), b"foo\0");
+}
+
+#[test]
+fn test_nul_position() {
+    const fn nul_pos(s: &'static str) -> Option<usize> {
+        CStrArgs::new(s).nul_pos
+    }
+
+    assert_eq!(nul_pos("\0"), Some(0));
+    assert_eq!(nul_pos("foo\0"), Some(3));
+    assert_eq!(nul_pos("foo\0bar"), Some(3));
+    assert_eq!(nul_pos("a\0b\0c"), Some(1));
+    assert_eq!(nul_pos("ñ\0"), Some(2));
+}
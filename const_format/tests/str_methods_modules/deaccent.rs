@@ -0,0 +1,62 @@
+use const_format::__str_methods::{deaccent_bytes, deaccent_len};
+use const_format::deaccent;
+
+macro_rules! assert_case {
+    ($input:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const LEN: usize = deaccent_len(IN);
+        const OUT_B: [u8; LEN] = deaccent_bytes::<LEN>(IN);
+
+        assert_eq!(std::str::from_utf8(&OUT_B).unwrap(), $output);
+        assert_eq!(deaccent!(IN), $output);
+    }};
+}
+
+#[test]
+fn latin1_letters() {
+    assert_case! {"", ""}
+    assert_case! {"hello", "hello"}
+    assert_case! {"ÀÁÂÃÄÅ àáâãäå", "AAAAAA aaaaaa"}
+    assert_case! {"Ç ç Ñ ñ Ý ý ÿ", "C c N n Y y y"}
+    assert_case! {"ÈÉÊË èéêë ÌÍÎÏ ìíîï", "EEEE eeee IIII iiii"}
+    assert_case! {"ÒÓÔÕÖØ òóôõöø ÙÚÛÜ ùúûü", "OOOOOO oooooo UUUU uuuu"}
+    assert_case! {"Ðð", "Dd"}
+}
+
+#[test]
+fn latin_extended_a_letters() {
+    assert_case! {"Āā Ăă Ąą", "Aa Aa Aa"}
+    assert_case! {"Čč Ďď Đđ Ěě Ğğ", "Cc Dd Dd Ee Gg"}
+    assert_case! {"Łł Ńń Őő Řř Śś Šš", "Ll Nn Oo Rr Ss Ss"}
+    assert_case! {"Ţţ Ťť Ůů Űű Ŵŵ Ÿ Źź Żż Žž", "Tt Tt Uu Uu Ww Y Zz Zz Zz"}
+    assert_case! {"İı ſ", "Ii s"}
+}
+
+#[test]
+fn length_changing() {
+    assert_case! {"Æsir æon", "AEsir aeon"}
+    assert_case! {"Straße", "Strasse"}
+    assert_case! {"Œuvre œil", "OEuvre oeil"}
+    assert_case! {"Þór þorn", "THor thorn"}
+    assert_case! {"Ĳssel ĳs", "IJssel ijs"}
+}
+
+#[test]
+fn sentences() {
+    assert_case! {"Crème brûlée à la française", "Creme brulee a la francaise"}
+    assert_case! {"Dvořák, Müller, Ångström", "Dvorak, Muller, Angstrom"}
+    assert_case! {"Zażółć gęślą jaźń", "Zazolc gesla jazn"}
+}
+
+#[test]
+fn unmapped_chars() {
+    // chars in the covered blocks without an ASCII equivalent
+    assert_case! {"2×3÷4 ĸ", "2×3÷4 ĸ"}
+    // chars outside of the covered blocks
+    assert_case! {"効率 😀 Ω ß", "効率 😀 Ω ss"}
+    assert_case! {"ǅ ș ț ẞ", "ǅ ș ț ẞ"}
+    // Latin-1 chars below U+00C0
+    assert_case! {"¡¿ © ° µ", "¡¿ © ° µ"}
+    // combining diacritics are kept
+    assert_case! {"e\u{301}", "e\u{301}"}
+}
@@ -0,0 +1,43 @@
+use const_format::dedup_whitespace;
+
+macro_rules! assert_case {
+    ($input:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const OUT: &str = dedup_whitespace!(IN);
+        assert_eq!(OUT, $output);
+        assert_eq!(OUT, IN.split_whitespace().collect::<Vec<_>>().join(" "));
+    }};
+}
+
+#[test]
+fn dedup_whitespace_basic() {
+    assert_case!("", "");
+    assert_case!("foo", "foo");
+    assert_case!("foo bar", "foo bar");
+    assert_case!("foo  bar", "foo bar");
+    assert_case!("a b  c   d", "a b c d");
+}
+
+#[test]
+fn dedup_whitespace_kinds() {
+    // tabs and newlines are replaced with a space
+    assert_case!("foo\tbar", "foo bar");
+    assert_case!("foo\nbar", "foo bar");
+    assert_case!("foo\r\nbar", "foo bar");
+    assert_case!("foo \t\n\r\x0B\x0C bar", "foo bar");
+    assert_case!("line one\n\n\tline two\n", "line one line two");
+
+    // unicode whitespace
+    assert_case!("foo\u{A0}bar\u{3000}\u{2028}baz", "foo bar baz");
+    assert_case!("\u{85}\u{1680}ñ\u{2009}鉄\u{205F}", "ñ 鉄");
+}
+
+#[test]
+fn dedup_whitespace_trimmed() {
+    assert_case!(" ", "");
+    assert_case!(" \t\n \r\n", "");
+    assert_case!("  foo", "foo");
+    assert_case!("foo  ", "foo");
+    assert_case!("\n\tfoo bar\t\n", "foo bar");
+    assert_case!("\u{3000}foo\u{3000}", "foo");
+}
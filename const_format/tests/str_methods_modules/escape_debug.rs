@@ -0,0 +1,89 @@
+use const_format::__str_methods::{escape_debug_bytes, escape_debug_len};
+use const_format::escape_debug;
+
+macro_rules! assert_case {
+    ($input:expr $(,)*) => {{
+        const STD_IN: &str = $input;
+        let expected = STD_IN.escape_debug().to_string();
+        assert_case! {STD_IN, &expected}
+    }};
+    ($input:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const L: usize = escape_debug_len(IN);
+        let output: &str = $output;
+
+        assert_eq!(IN.escape_debug().to_string(), output);
+        assert_eq!(L, output.len());
+
+        assert_eq!(
+            std::str::from_utf8(&escape_debug_bytes::<L>(IN)).unwrap(),
+            output,
+        );
+
+        assert_eq!(escape_debug!(IN), output);
+    }};
+}
+
+#[test]
+fn test_no_escapes() {
+    assert_case! {""}
+    assert_case! {"hello world"}
+    assert_case! {"/path/to/thing?a=b&c"}
+    assert_case! {"ñandú 鉄錆 效率的 😀"}
+}
+
+#[test]
+fn test_short_escapes() {
+    assert_case! {"\t", r"\t"}
+    assert_case! {"\r\n"}
+    assert_case! {"\0", r"\0"}
+    assert_case! {r"\", r"\\"}
+    assert_case! {"'", r"\'"}
+    assert_case! {"\"", r#"\""#}
+    assert_case! {"\tname:\t\"鉄錆\"\n"}
+    assert_case! {r#"C:\Users\'foo'"#}
+}
+
+#[test]
+fn test_control_chars() {
+    assert_case! {"\x01", r"\u{1}"}
+    assert_case! {"\x0F"}
+    assert_case! {"\x10", r"\u{10}"}
+    assert_case! {"\x1B[0m", r"\u{1b}[0m"}
+    assert_case! {"\x7F", r"\u{7f}"}
+    assert_case! {"\u{80}", r"\u{80}"}
+    assert_case! {"\u{9F}", r"\u{9f}"}
+    assert_case! {"\u{A1}\u{FF}", "\u{A1}\u{FF}"}
+    assert_case! {"a\x07b\u{85}c\x0Bd"}
+}
+
+// Non-ASCII chars that std escapes, but this macro doesn't.
+#[test]
+fn test_non_ascii_differences() {
+    assert_eq!("\u{A0}".escape_debug().to_string(), r"\u{a0}");
+    assert_eq!(escape_debug!("\u{A0}"), "\u{A0}");
+
+    assert_eq!("\u{200B}".escape_debug().to_string(), r"\u{200b}");
+    assert_eq!(escape_debug!("\u{200B}"), "\u{200B}");
+}
+
+#[test]
+fn test_all_ascii() {
+    const ALL_ASCII: &str = {
+        const BYTES: [u8; 128] = {
+            let mut bytes = [0u8; 128];
+            let mut i = 0;
+            while i < 128 {
+                bytes[i] = i as u8;
+                i += 1;
+            }
+            bytes
+        };
+        match std::str::from_utf8(&BYTES) {
+            Ok(s) => s,
+            Err(_) => panic!(),
+        }
+    };
+
+    assert_case! {ALL_ASCII}
+}
@@ -0,0 +1,80 @@
+use const_format::__str_methods::{escape_html_bytes, escape_html_len};
+use const_format::escape_html;
+
+macro_rules! assert_case {
+    ($input:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const OUT: &str = $output;
+
+        assert_eq!(escape_html_len(IN, false), OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&escape_html_bytes::<{ OUT.len() }>(IN, false)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!(escape_html!(IN), OUT);
+    }};
+    ($input:expr, non_ascii, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const OUT: &str = $output;
+
+        assert_eq!(escape_html_len(IN, true), OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&escape_html_bytes::<{ OUT.len() }>(IN, true)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!(escape_html!(IN, non_ascii), OUT);
+    }};
+}
+
+#[test]
+fn test_no_escapes() {
+    assert_case! {"", ""}
+    assert_case! {"hello world", "hello world"}
+    assert_case! {"a + b = c; #1 /path", "a + b = c; #1 /path"}
+    assert_case! {"鉄 café 😀", "鉄 café 😀"}
+}
+
+#[test]
+fn test_special_chars() {
+    assert_case! {"&", "&amp;"}
+    assert_case! {"<", "&lt;"}
+    assert_case! {">", "&gt;"}
+    assert_case! {"\"", "&quot;"}
+    assert_case! {"'", "&#39;"}
+
+    // already escaped text is escaped again
+    assert_case! {"&amp;", "&amp;amp;"}
+}
+
+#[test]
+fn test_mixed() {
+    assert_case! {
+        r#"<a href="/?x=1&y='2'">Tom & Jerry</a>"#,
+        "&lt;a href=&quot;/?x=1&amp;y=&#39;2&#39;&quot;&gt;Tom &amp; Jerry&lt;/a&gt;",
+    }
+    assert_case! {"<<>>", "&lt;&lt;&gt;&gt;"}
+    assert_case! {"鉄<錆>", "鉄&lt;錆&gt;"}
+}
+
+#[test]
+fn test_non_ascii() {
+    assert_case! {"", non_ascii, ""}
+    assert_case! {"hello", non_ascii, "hello"}
+
+    // chars of every utf8 length
+    assert_case! {"\u{80}", non_ascii, "&#x80;"}
+    assert_case! {"é", non_ascii, "&#xE9;"}
+    assert_case! {"鉄", non_ascii, "&#x9244;"}
+    assert_case! {"😀", non_ascii, "&#x1F600;"}
+    assert_case! {"\u{10FFFF}", non_ascii, "&#x10FFFF;"}
+
+    assert_case! {
+        "<Café> & 鉄😀'",
+        non_ascii,
+        "&lt;Caf&#xE9;&gt; &amp; &#x9244;&#x1F600;&#39;",
+    }
+}
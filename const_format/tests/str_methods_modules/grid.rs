@@ -0,0 +1,61 @@
+use const_format::__str_methods::{grid_bytes, GridArgs};
+use const_format::grid;
+
+macro_rules! assert_case {
+    ($values:expr, $cols:expr, $output:expr $(,)*) => {{
+        const VALUES: &[&str] = &$values;
+        const OUT: &str = $output;
+        const ARGS: GridArgs = GridArgs::new(VALUES, $cols);
+
+        assert_eq!(ARGS.out_len, OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&grid_bytes::<{ ARGS.out_len }>(&ARGS)).unwrap(),
+            OUT,
+        );
+    }};
+}
+
+#[test]
+fn test_grid_args() {
+    assert_case! {[], 1, ""}
+    assert_case! {[], 3, ""}
+    assert_case! {["a"], 1, "a"}
+    assert_case! {["a"], 3, "a"}
+    assert_case! {["a", "bc", "d"], 1, " a,\nbc,\n d"}
+    assert_case! {["a", "bc", "d"], 3, "a, bc, d"}
+    assert_case! {["a", "bc", "d", "efg"], 2, "a,  bc,\nd, efg"}
+
+    // the width is measured in chars
+    assert_case! {["ñ", "ab", "鉄錆", "c"], 2, " ñ, ab,\n鉄錆,  c"}
+}
+
+#[test]
+fn test_grid_ragged() {
+    assert_case! {["1", "2", "3"], 2, "1, 2,\n3"}
+    assert_case! {["1", "22", "333", "4", "5"], 2, "  1, 22,\n333,  4,\n  5"}
+    // the last row is only padded to the widths of the columns it has values in
+    assert_case! {["1", "2", "333", "4"], 3, "1, 2, 333,\n4"}
+    assert_case! {["1", "2", "3", "4", "5"], 4, "1, 2, 3, 4,\n5"}
+}
+
+#[test]
+fn test_grid_macro() {
+    assert_eq!(
+        grid!([1u8, 20u8, 300u16, 4u8, 50u8], cols = 2),
+        "  1, 20,\n300,  4,\n 50"
+    );
+    assert_eq!(grid!([], cols = 2), "");
+    assert_eq!(
+        grid!(['a', "bc", true, -1i8,], cols = 3),
+        " a, bc, true,\n-1"
+    );
+    assert_eq!(
+        grid!(["Ⅰ", "Ⅱ", "Ⅲ", "Ⅳ", "Ⅴ", "Ⅵ", "Ⅶ"], cols = 3),
+        "Ⅰ, Ⅱ, Ⅲ,\nⅣ, Ⅴ, Ⅵ,\nⅦ"
+    );
+
+    const COLS: usize = 4;
+    const VALUES: &str = grid!([10u32, 2u32, 3000u32, 4u32, 5u32, 600u32], cols = COLS);
+    assert_eq!(VALUES, "10,   2, 3000, 4,\n 5, 600");
+}
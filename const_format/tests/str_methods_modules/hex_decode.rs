@@ -0,0 +1,81 @@
+use const_format::__str_methods::{hex_decode_bytes, HexDecodeArgs, HexDecodeError};
+use const_format::hex_decode;
+
+fn std_hex_decode(s: &str, separators: &str) -> Vec<u8> {
+    let digits: Vec<u8> = s
+        .chars()
+        .filter(|c| !separators.contains(*c))
+        .map(|c| c.to_digit(16).unwrap() as u8)
+        .collect();
+
+    digits
+        .chunks(2)
+        .map(|pair| pair[0] << 4 | pair[1])
+        .collect()
+}
+
+macro_rules! assert_case {
+    ($input:expr, $separators:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const SEP: &str = $separators;
+        const OUT: &[u8] = $output;
+        const ARGS: &HexDecodeArgs = &HexDecodeArgs::new(IN, SEP);
+
+        assert_eq!(ARGS.error, None);
+        assert_eq!(ARGS.out_len, OUT.len());
+        assert_eq!(std_hex_decode(IN, SEP), OUT);
+
+        assert_eq!(&hex_decode_bytes::<{ ARGS.out_len }>(ARGS)[..], OUT);
+
+        assert_eq!(hex_decode!(IN, SEP), OUT);
+    }};
+}
+
+#[test]
+fn test_digits() {
+    assert_case! {"", "", &[]}
+    assert_case! {"00", "", &[0]}
+    assert_case! {"ff", "", &[255]}
+    assert_case! {"FF", "", &[255]}
+    assert_case! {"0123456789", "", &[0x01, 0x23, 0x45, 0x67, 0x89]}
+    assert_case! {"abcdefABCDEF", "", &[0xAB, 0xCD, 0xEF, 0xAB, 0xCD, 0xEF]}
+    assert_case! {"deadbeef", "", &[0xDE, 0xAD, 0xBE, 0xEF]}
+
+    assert_eq!(hex_decode!("c0ffee"), &[0xC0, 0xFF, 0xEE]);
+}
+
+#[test]
+fn test_separators() {
+    assert_case! {"", " ", &[]}
+    assert_case! {"   ", " ", &[]}
+    assert_case! {"de ad be ef", " ", &[0xDE, 0xAD, 0xBE, 0xEF]}
+    assert_case! {" d e a d ", " ", &[0xDE, 0xAD]}
+    assert_case! {"00:11-22_33", ":-_", &[0x00, 0x11, 0x22, 0x33]}
+    assert_case! {"00\n11\t22", " \n\t", &[0x00, 0x11, 0x22]}
+    assert_case! {"ab·cd→ef", "·→", &[0xAB, 0xCD, 0xEF]}
+    assert_case! {"ab😀cd", "😀", &[0xAB, 0xCD]}
+}
+
+#[test]
+fn test_errors() {
+    const fn error(s: &'static str, separators: &'static str) -> Option<HexDecodeError> {
+        HexDecodeArgs::new(s, separators).error
+    }
+
+    assert_eq!(error("a", ""), Some(HexDecodeError::OddLength(1)));
+    assert_eq!(error("abc", ""), Some(HexDecodeError::OddLength(3)));
+    assert_eq!(error("ab c", " "), Some(HexDecodeError::OddLength(3)));
+    assert_eq!(error("ab cd e", " "), Some(HexDecodeError::OddLength(5)));
+
+    assert_eq!(error("g0", ""), Some(HexDecodeError::InvalidChar(0)));
+    assert_eq!(error("0g", ""), Some(HexDecodeError::InvalidChar(1)));
+    assert_eq!(error("ab cd", ""), Some(HexDecodeError::InvalidChar(2)));
+    assert_eq!(error("ab:cd", " "), Some(HexDecodeError::InvalidChar(2)));
+    assert_eq!(error("0x00", ""), Some(HexDecodeError::InvalidChar(1)));
+    assert_eq!(error("abñcd", ""), Some(HexDecodeError::InvalidChar(2)));
+    // 'ĩ' is `[0xC4, 0xA9]`, while 'é' is `[0xC3, 0xA9]`
+    assert_eq!(error("abĩcd", "é"), Some(HexDecodeError::InvalidChar(2)));
+
+    // invalid chars take precedence over the number of digits
+    assert_eq!(error("abc?", ""), Some(HexDecodeError::InvalidChar(3)));
+}
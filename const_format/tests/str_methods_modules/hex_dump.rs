@@ -0,0 +1,94 @@
+use const_format::__str_methods::{hex_dump_bytes, hex_dump_len};
+use const_format::hex_dump;
+
+macro_rules! assert_case {
+    ($input:expr, $output:expr $(,)*) => {{
+        const IN: &[u8] = $input;
+        const OUT: &str = $output;
+
+        assert_eq!(hex_dump_len(IN), OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&hex_dump_bytes::<{ OUT.len() }>(IN)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!(hex_dump!(IN), OUT);
+    }};
+}
+
+#[test]
+fn test_short() {
+    assert_case! {b"", ""}
+    assert_case! {
+        b"\xde",
+        "00000000  de                                                |.|",
+    }
+    assert_case! {
+        b"\xde\xad\xbe\xef",
+        "00000000  de ad be ef                                       |....|",
+    }
+    assert_case! {
+        b"Hello, world\n",
+        "00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 0a           |Hello, world.|",
+    }
+    // the extra space is only written after the 8th byte
+    assert_case! {
+        b"01234567",
+        "00000000  30 31 32 33 34 35 36 37                           |01234567|",
+    }
+    assert_case! {
+        b"012345678",
+        "00000000  30 31 32 33 34 35 36 37  38                       |012345678|",
+    }
+}
+
+#[test]
+fn test_multiple_lines() {
+    assert_case! {
+        b"0123456789abcdef",
+        "00000000  30 31 32 33 34 35 36 37  38 39 61 62 63 64 65 66  |0123456789abcdef|",
+    }
+    assert_case! {
+        b"0123456789abcdef\x00\x7f\x80\xff ~",
+        concat!(
+            "00000000  30 31 32 33 34 35 36 37  38 39 61 62 63 64 65 66  |0123456789abcdef|\n",
+            "00000010  00 7f 80 ff 20 7e                                 |.... ~|",
+        ),
+    }
+
+    const LONG: &[u8; 300] = &{
+        let mut arr = [0u8; 300];
+        let mut i = 0;
+        while i < 300 {
+            arr[i] = i as u8;
+            i += 1;
+        }
+        arr
+    };
+    const DUMP: &str = hex_dump!(LONG);
+
+    let lines = DUMP.lines().collect::<Vec<&str>>();
+    assert_eq!(lines.len(), 19);
+    assert_eq!(
+        lines[2],
+        "00000020  20 21 22 23 24 25 26 27  28 29 2a 2b 2c 2d 2e 2f  | !\"#$%&'()*+,-./|",
+    );
+    assert_eq!(
+        lines[15],
+        "000000f0  f0 f1 f2 f3 f4 f5 f6 f7  f8 f9 fa fb fc fd fe ff  |................|",
+    );
+    assert_eq!(
+        lines[18],
+        "00000120  20 21 22 23 24 25 26 27  28 29 2a 2b              | !\"#$%&'()*+|",
+    );
+    assert_eq!(DUMP.len(), hex_dump_len(LONG));
+}
+
+#[test]
+fn test_str_input() {
+    assert_eq!(
+        hex_dump!("ñ!"),
+        "00000000  c3 b1 21                                          |..!|",
+    );
+}
@@ -0,0 +1,122 @@
+use const_format::__str_methods::{
+    camel_to_snake_bytes, camel_to_snake_len, snake_to_camel_bytes, snake_to_camel_len,
+};
+use const_format::{camel_to_snake, snake_to_camel};
+
+macro_rules! assert_camel {
+    ($input:expr, $lower:expr, $upper:expr $(,)*) => {{
+        const IN: &str = $input;
+        const LOWER: &str = $lower;
+        const UPPER: &str = $upper;
+
+        assert_eq!(snake_to_camel_len(IN), LOWER.len());
+        assert_eq!(LOWER.len(), UPPER.len());
+
+        assert_eq!(
+            std::str::from_utf8(&snake_to_camel_bytes::<{ LOWER.len() }>(IN, false)).unwrap(),
+            LOWER,
+        );
+        assert_eq!(
+            std::str::from_utf8(&snake_to_camel_bytes::<{ UPPER.len() }>(IN, true)).unwrap(),
+            UPPER,
+        );
+
+        assert_eq!(snake_to_camel!(IN), LOWER);
+        assert_eq!(snake_to_camel!(IN, upper), UPPER);
+    }};
+}
+
+macro_rules! assert_snake {
+    ($input:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const OUT: &str = $output;
+
+        assert_eq!(camel_to_snake_len(IN), OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&camel_to_snake_bytes::<{ OUT.len() }>(IN)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!(camel_to_snake!(IN), OUT);
+    }};
+}
+
+#[test]
+fn snake_to_camel_words() {
+    assert_camel! {"", "", ""}
+    assert_camel! {"a", "a", "A"}
+    assert_camel! {"user", "user", "User"}
+    assert_camel! {"user_id", "userId", "UserId"}
+    assert_camel! {"max_retry_count", "maxRetryCount", "MaxRetryCount"}
+    assert_camel! {"a_b_c", "aBC", "ABC"}
+    assert_camel! {"field_0_1", "field01", "Field01"}
+}
+
+#[test]
+fn snake_to_camel_underscores() {
+    assert_camel! {"_", "_", "_"}
+    assert_camel! {"___", "___", "___"}
+    assert_camel! {"_user_id", "_userId", "_UserId"}
+    assert_camel! {"user_id_", "userId_", "UserId_"}
+    assert_camel! {"__user___id__", "__userId__", "__UserId__"}
+}
+
+#[test]
+fn snake_to_camel_non_lowercase() {
+    assert_camel! {"User_id", "userId", "UserId"}
+    assert_camel! {"http_URL", "httpURL", "HttpURL"}
+    assert_camel! {"USER_ID", "uSERID", "USERID"}
+    assert_camel! {"ñandú_año", "ñandúAño", "ñandúAño"}
+    assert_camel! {"鉄_錆", "鉄錆", "鉄錆"}
+}
+
+#[test]
+fn camel_to_snake_words() {
+    assert_snake! {"", ""}
+    assert_snake! {"a", "a"}
+    assert_snake! {"A", "a"}
+    assert_snake! {"user", "user"}
+    assert_snake! {"userId", "user_id"}
+    assert_snake! {"UserId", "user_id"}
+    assert_snake! {"maxRetryCount", "max_retry_count"}
+    assert_snake! {"aBC", "a_bc"}
+    assert_snake! {"user2Name", "user2_name"}
+    assert_snake! {"user_id", "user_id"}
+}
+
+#[test]
+fn camel_to_snake_acronyms() {
+    assert_snake! {"HTTP", "http"}
+    assert_snake! {"HTTPServer", "http_server"}
+    assert_snake! {"getHTTPResponse", "get_http_response"}
+    assert_snake! {"userID", "user_id"}
+    assert_snake! {"IOError", "io_error"}
+    assert_snake! {"HTTP2Server", "http2_server"}
+}
+
+#[test]
+fn camel_to_snake_underscores() {
+    assert_snake! {"_", "_"}
+    assert_snake! {"_privateField", "_private_field"}
+    assert_snake! {"_FooBar", "_foo_bar"}
+    assert_snake! {"Foo_Bar", "foo_bar"}
+    assert_snake! {"fooBar_", "foo_bar_"}
+    assert_snake! {"ñandúAño", "ñandú_año"}
+}
+
+#[test]
+fn roundtrip() {
+    assert_eq!(
+        camel_to_snake!(snake_to_camel!("max_retry_count")),
+        "max_retry_count"
+    );
+    assert_eq!(
+        snake_to_camel!(camel_to_snake!("maxRetryCount")),
+        "maxRetryCount"
+    );
+    assert_eq!(
+        snake_to_camel!(camel_to_snake!("HTTPServer"), upper),
+        "HttpServer"
+    );
+}
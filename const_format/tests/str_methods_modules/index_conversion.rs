@@ -0,0 +1,56 @@
+use const_format::{byte_to_char_index, char_to_byte_index, str_index};
+
+macro_rules! assert_case {
+    ($input:expr, $char_index:expr, $byte_index:expr $(,)*) => {{
+        const IN: &str = $input;
+        const BYTE: usize = char_to_byte_index!(IN, $char_index);
+        const CHAR: usize = byte_to_char_index!(IN, $byte_index);
+
+        assert_eq!(BYTE, $byte_index, "input: {:?}", IN);
+        assert_eq!(CHAR, $char_index, "input: {:?}", IN);
+
+        let std_byte = IN
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(IN.len()))
+            .nth($char_index)
+            .unwrap();
+        assert_eq!(BYTE, std_byte, "input: {:?}", IN);
+        assert_eq!(CHAR, IN[..$byte_index].chars().count(), "input: {:?}", IN);
+    }};
+}
+
+#[test]
+fn ascii() {
+    assert_case! {"", 0, 0}
+    assert_case! {"foo", 0, 0}
+    assert_case! {"foo", 1, 1}
+    assert_case! {"foo", 3, 3}
+}
+
+#[test]
+fn multi_byte() {
+    const S: &str = "aé効😀b";
+
+    assert_case! {S, 0, 0}
+    assert_case! {S, 1, 1}
+    assert_case! {S, 2, 3}
+    assert_case! {S, 3, 6}
+    assert_case! {S, 4, 10}
+    // one past the last char is the length of the string
+    assert_case! {S, 5, 11}
+
+    assert_case! {"効率的", 1, 3}
+    assert_case! {"効率的", 3, 9}
+}
+
+#[test]
+fn slicing_with_char_indices() {
+    const S: &str = "ñandú効率";
+    const START: usize = char_to_byte_index!(S, 2);
+    const END: usize = char_to_byte_index!(S, 6);
+
+    assert_eq!(str_index!(S, START..END), "ndú効");
+    assert_eq!(byte_to_char_index!(S, START), 2);
+    assert_eq!(byte_to_char_index!(S, END), 6);
+}
@@ -0,0 +1,49 @@
+use const_format::__str_methods::{json_escape_bytes, json_escaped_len};
+use const_format::json_escape;
+
+macro_rules! assert_case {
+    ($input:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const OUT: &str = $output;
+
+        assert_eq!(json_escaped_len(IN), OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&json_escape_bytes::<{ OUT.len() }>(IN)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!(json_escape!(IN), OUT);
+    }};
+}
+
+#[test]
+fn test_no_escapes() {
+    assert_case! {"", ""}
+    assert_case! {"hello world", "hello world"}
+    assert_case! {"/path/to/thing", "/path/to/thing"}
+    assert_case! {"'single' 鉄 效率的 \x7F", "'single' 鉄 效率的 \x7F"}
+}
+
+#[test]
+fn test_short_escapes() {
+    assert_case! {r#"""#, r#"\""#}
+    assert_case! {r#"\"#, r#"\\"#}
+    assert_case! {"\x08\x0C\n\r\t", r#"\b\f\n\r\t"#}
+    assert_case! {r#"say "hi" \o/"#, r#"say \"hi\" \\o/"#}
+}
+
+#[test]
+fn test_control_escapes() {
+    assert_case! {"\x00", r#"\u0000"#}
+    assert_case! {"\x01\x07\x1B\x1F", r#"\u0001\u0007\u001B\u001F"#}
+    assert_case! {"a\x0Bb\x0Ec", r#"a\u000Bb\u000Ec"#}
+}
+
+#[test]
+fn test_json_vector() {
+    assert_case! {
+        "{\"key\": \"tab\there\", \"bell\": \u{7}, \"path\": \"C:\\dir\",\n\"鉄\": 1}",
+        r#"{\"key\": \"tab\there\", \"bell\": \u0007, \"path\": \"C:\\dir\",\n\"鉄\": 1}"#,
+    }
+}
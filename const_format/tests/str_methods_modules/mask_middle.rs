@@ -0,0 +1,57 @@
+use const_format::__str_methods::{mask_middle_bytes, MaskMiddleArgs};
+use const_format::mask_middle;
+
+macro_rules! assert_case {
+    ($input:expr, $keep_start:expr, $keep_end:expr, $mask:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const OUT: &str = $output;
+        const ARGS: MaskMiddleArgs = MaskMiddleArgs::new(IN, $keep_start, $keep_end, $mask);
+
+        assert_eq!(ARGS.out_len, OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&mask_middle_bytes::<{ ARGS.out_len }>(&ARGS)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!(mask_middle!(IN, $keep_start, $keep_end, $mask), OUT);
+    }};
+}
+
+#[test]
+fn test_mask_middle() {
+    assert_case! {"abcdef", 1, 1, '*', "a****f"}
+    assert_case! {"abcdef", 2, 3, '*', "ab*def"}
+    assert_case! {"abcdef", 0, 0, '*', "******"}
+    assert_case! {"abcdef", 0, 2, '*', "****ef"}
+    assert_case! {"abcdef", 2, 0, '*', "ab****"}
+}
+
+#[test]
+fn test_mask_middle_multibyte() {
+    // multi-byte kept chars
+    assert_case! {"ñandú", 1, 1, '*', "ñ***ú"}
+    assert_case! {"鉄錆ab鉄錆", 2, 2, '-', "鉄錆--鉄錆"}
+
+    // multi-byte masked chars
+    assert_case! {"a鉄錆ñb", 1, 1, '*', "a***b"}
+
+    // multi-byte mask
+    assert_case! {"abcd", 1, 1, '·', "a··d"}
+    assert_case! {"a鉄錆b", 1, 1, '😀', "a😀😀b"}
+    assert_case! {"😀😀😀", 0, 1, 'x', "xx😀"}
+}
+
+#[test]
+fn test_mask_middle_short_strings() {
+    // nothing between the kept chars, returned unchanged
+    assert_case! {"", 0, 0, '*', ""}
+    assert_case! {"", 3, 3, '*', ""}
+    assert_case! {"abc", 2, 1, '*', "abc"}
+    assert_case! {"abc", 1, 5, '*', "abc"}
+    assert_case! {"ñú", 1, 1, '*', "ñú"}
+    assert_case! {"abc", usize::MAX, usize::MAX, '*', "abc"}
+
+    // one char between the kept chars
+    assert_case! {"abc", 1, 1, '*', "a*c"}
+}
@@ -0,0 +1,58 @@
+use const_format::__str_methods::{newlines_bytes, LineEnding, NewlinesArgs};
+use const_format::{normalize_newlines, to_crlf};
+
+macro_rules! assert_case {
+    ($macro:ident, $ending:ident, $input:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const OUT: &str = $output;
+        const ARGS: NewlinesArgs = NewlinesArgs::new(IN, LineEnding::$ending);
+
+        assert_eq!(ARGS.out_len, OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&newlines_bytes::<{ ARGS.out_len }>(&ARGS)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!($macro!(IN), OUT);
+    }};
+}
+
+#[test]
+fn test_normalize_newlines() {
+    assert_case! {normalize_newlines, Lf, "", ""}
+    assert_case! {normalize_newlines, Lf, "foo", "foo"}
+    assert_case! {normalize_newlines, Lf, "\n", "\n"}
+    assert_case! {normalize_newlines, Lf, "\r\n", "\n"}
+    assert_case! {normalize_newlines, Lf, "\r", "\n"}
+    assert_case! {normalize_newlines, Lf, "\n\r", "\n\n"}
+    assert_case! {normalize_newlines, Lf, "\r\r\n", "\n\n"}
+    assert_case! {normalize_newlines, Lf, "a\r\nb\nc\rd", "a\nb\nc\nd"}
+    assert_case! {normalize_newlines, Lf, "a\r\n\r\nb\r", "a\n\nb\n"}
+    assert_case! {normalize_newlines, Lf, "ñ\r鉄\r\n錆", "ñ\n鉄\n錆"}
+}
+
+#[test]
+fn test_to_crlf() {
+    assert_case! {to_crlf, CrLf, "", ""}
+    assert_case! {to_crlf, CrLf, "foo", "foo"}
+    assert_case! {to_crlf, CrLf, "\n", "\r\n"}
+    assert_case! {to_crlf, CrLf, "\r\n", "\r\n"}
+    assert_case! {to_crlf, CrLf, "\r", "\r\n"}
+    assert_case! {to_crlf, CrLf, "\n\r", "\r\n\r\n"}
+    assert_case! {to_crlf, CrLf, "\r\r\n", "\r\n\r\n"}
+    assert_case! {to_crlf, CrLf, "a\r\nb\nc\rd", "a\r\nb\r\nc\r\nd"}
+    assert_case! {to_crlf, CrLf, "a\n\nb\n", "a\r\n\r\nb\r\n"}
+    assert_case! {to_crlf, CrLf, "ñ\r鉄\n錆", "ñ\r\n鉄\r\n錆"}
+}
+
+#[test]
+fn roundtrip() {
+    const MIXED: &str = "one\r\ntwo\nthree\rfour\r\n";
+    const CRLF: &str = to_crlf!(MIXED);
+    const LF: &str = normalize_newlines!(CRLF);
+
+    assert_eq!(CRLF, "one\r\ntwo\r\nthree\r\nfour\r\n");
+    assert_eq!(LF, "one\ntwo\nthree\nfour\n");
+    assert_eq!(to_crlf!(LF), CRLF);
+}
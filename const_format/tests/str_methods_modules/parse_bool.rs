@@ -0,0 +1,54 @@
+use const_format::__str_methods::ParseBoolArgs;
+use const_format::parse_bool;
+
+#[test]
+fn test_strict() {
+    const PARSED: [bool; 2] = [parse_bool!("true"), parse_bool!("false")];
+    assert_eq!(PARSED, [true, false]);
+
+    const IN: &str = "true";
+    assert!(parse_bool!(IN));
+    assert!(parse_bool!(IN,));
+}
+
+#[test]
+fn test_lenient() {
+    assert!(parse_bool!("true", lenient));
+    assert!(parse_bool!("1", lenient));
+    assert!(parse_bool!("yes", lenient));
+    assert!(!parse_bool!("false", lenient));
+    assert!(!parse_bool!("0", lenient));
+    assert!(!parse_bool!("no", lenient));
+}
+
+#[test]
+fn test_validity() {
+    for &(input, lenient, value) in &[
+        ("true", false, Some(true)),
+        ("false", false, Some(false)),
+        ("1", false, None),
+        ("0", false, None),
+        ("yes", false, None),
+        ("no", false, None),
+        ("1", true, Some(true)),
+        ("yes", true, Some(true)),
+        ("0", true, Some(false)),
+        ("no", true, Some(false)),
+        // not case-insensitive, nor trimmed
+        ("", true, None),
+        ("True", true, None),
+        ("FALSE", true, None),
+        ("Yes", true, None),
+        (" true", true, None),
+        ("true ", true, None),
+        ("truefalse", true, None),
+        ("2", true, None),
+        ("t", true, None),
+    ] {
+        let args = ParseBoolArgs::new(input, lenient);
+        assert_eq!(args.is_valid, value.is_some(), "{:?} {}", input, lenient);
+        if let Some(value) = value {
+            assert_eq!(args.value, value, "{:?} {}", input, lenient);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use const_format::__str_methods::{raw_literal_bytes, RawLiteralArgs};
+use const_format::as_raw_literal;
+
+macro_rules! assert_case {
+    ($input:expr, $hashes:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const OUT: &str = $output;
+        const ARGS: RawLiteralArgs = RawLiteralArgs::new(IN);
+
+        assert_eq!(ARGS.hashes, $hashes);
+        assert_eq!(ARGS.out_len, OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&raw_literal_bytes::<{ ARGS.out_len }>(&ARGS)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!(as_raw_literal!(IN), OUT);
+    }};
+}
+
+#[test]
+fn test_as_raw_literal() {
+    assert_case! {"", 0, r#"r"""#}
+    assert_case! {"foo", 0, r#"r"foo""#}
+    assert_case! {"#", 0, r##"r"#""##}
+    assert_case! {"##foo#", 0, r###"r"##foo#""###}
+    assert_case! {r"\n\t", 0, r#"r"\n\t""#}
+    assert_case! {"ñandú\n鉄錆", 0, "r\"ñandú\n鉄錆\""}
+
+    assert_case! {r#"""#, 1, r##"r#"""#"##}
+    assert_case! {r#"a"b"c"#, 1, r##"r#"a"b"c"#"##}
+    assert_case! {r##""#"##, 2, r###"r##""#"##"###}
+    assert_case! {r##"a "# b"##, 2, r###"r##"a "# b"##"###}
+    assert_case! {r###""## and "#"###, 3, r####"r###""## and "#"###"####}
+    assert_case! {r####"#"###"####, 4, r#####"r####"#"###"####"#####}
+
+    // the longest run is used, not the first one
+    assert_case! {r####""# "### ""####, 4, r#####"r####""# "### ""####"#####}
+    // hashes before a quote don't count
+    assert_case! {r####"### ""####, 1, r#####"r#"### ""#"#####}
+}
@@ -0,0 +1,47 @@
+use const_format::repeat_to_width;
+
+fn std_repeat_to_width(pattern: &str, width: usize) -> String {
+    pattern.chars().cycle().take(width).collect()
+}
+
+macro_rules! assert_case {
+    ($pattern:expr, $width:expr, $output:expr $(,)*) => {{
+        const PATTERN: &str = $pattern;
+        const OUT: &str = repeat_to_width!(PATTERN, $width);
+
+        assert_eq!(OUT, $output);
+        assert_eq!(OUT, std_repeat_to_width(PATTERN, $width));
+        assert_eq!(OUT.chars().count(), $width);
+    }};
+}
+
+#[test]
+fn single_char_pattern() {
+    assert_case! {"=", 0, ""}
+    assert_case! {"=", 1, "="}
+    assert_case! {"=", 20, "===================="}
+    assert_case! {"ñ", 3, "ñññ"}
+}
+
+#[test]
+fn multi_char_pattern() {
+    // widths that are a multiple of the pattern's length
+    assert_case! {"-=", 2, "-="}
+    assert_case! {"-=", 6, "-=-=-="}
+
+    // odd widths truncate the last repetition
+    assert_case! {"-=", 1, "-"}
+    assert_case! {"-=", 7, "-=-=-=-"}
+    assert_case! {"abc", 8, "abcabcab"}
+
+    // the width is in chars, not bytes
+    assert_case! {"ñ€", 5, "ñ€ñ€ñ"}
+    assert_case! {"a😀", 3, "a😀a"}
+    assert_case! {"😀a", 3, "😀a😀"}
+}
+
+#[test]
+fn empty_pattern() {
+    assert_case! {"", 0, ""}
+    assert_case! {"abc", 0, ""}
+}
@@ -0,0 +1,67 @@
+use const_format::replace_range;
+
+const IN: &[u8] = b"abcdefghij";
+const RW: &[u8] = b"_.-";
+
+macro_rules! assert_case {
+    ($input:expr, $range:expr, $insert:expr, $output:expr $(,)*) => {{
+        const OUT: &[u8] = replace_range!($input, $range, $insert);
+        assert_eq!(OUT, $output);
+
+        let mut expected = $input.to_vec();
+        expected.splice($range, $insert.iter().copied());
+        assert_eq!(OUT, &expected[..]);
+    }};
+}
+
+#[test]
+fn replace_ranges() {
+    assert_case!(IN, 2..3, RW, b"ab_.-defghij");
+    assert_case!(IN, 4..5, RW, b"abcd_.-fghij");
+
+    assert_case!(IN, 2..4, RW, b"ab_.-efghij");
+    assert_case!(IN, 4..4, RW, b"abcd_.-efghij");
+
+    assert_case!(IN, 2..=4, RW, b"ab_.-fghij");
+    assert_case!(IN, 4..=4, RW, b"abcd_.-fghij");
+
+    assert_case!(IN, ..2, RW, b"_.-cdefghij");
+    assert_case!(IN, ..=3, RW, b"_.-efghij");
+
+    assert_case!(IN, 5.., RW, b"abcde_.-");
+    assert_case!(IN, 10.., RW, b"abcdefghij_.-");
+    assert_case!(IN, 0..0, RW, b"_.-abcdefghij");
+
+    assert_case!(IN, .., RW, b"_.-");
+}
+
+#[test]
+fn single_byte_index() {
+    assert_eq!(replace_range!(IN, 0, RW), b"_.-bcdefghij");
+    assert_eq!(replace_range!(IN, 2, RW), b"ab_.-defghij");
+    assert_eq!(replace_range!(IN, 9, RW), b"abcdefghi_.-");
+}
+
+#[test]
+fn replacements() {
+    // shrinking
+    assert_case!(b"abcde", 1..4, b"", b"ae");
+    assert_case!(b"abcde", 1..4, b"h", b"ahe");
+    assert_case!(b"abcde", 1..4, b"he", b"ahee");
+    // equal length
+    assert_case!(b"abcde", 1..4, b"hel", b"ahele");
+    assert_case!(b"abcde", .., b"hello", b"hello");
+    // growing
+    assert_case!(b"abcde", 1..4, b"hell", b"ahelle");
+    assert_case!(b"abcde", 1..4, b"hello", b"ahelloe");
+    assert_case!(b"", .., b"hello", b"hello");
+
+    // no char boundary requirements
+    assert_case!(
+        "効率的".as_bytes(),
+        1..5,
+        &[0xFF],
+        &[0xE5, 0xFF, 0x87, 0xE7, 0x9A, 0x84]
+    );
+    assert_case!(&[0u8, 1, 2, 3, 4], 2..4, &[0xFF], &[0, 1, 0xFF, 4]);
+}
@@ -0,0 +1,54 @@
+use const_format::__str_methods::{slugify_bytes, slugify_len};
+use const_format::slugify;
+
+macro_rules! assert_case {
+    ($input:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const OUT: &str = $output;
+
+        assert_eq!(slugify_len(IN), OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&slugify_bytes::<{ OUT.len() }>(IN)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!(slugify!(IN), OUT);
+    }};
+}
+
+#[test]
+fn test_words() {
+    assert_case! {"", ""}
+    assert_case! {"a", "a"}
+    assert_case! {"hello", "hello"}
+    assert_case! {"Hello World", "hello-world"}
+    assert_case! {"HTTP Server 2", "http-server-2"}
+    assert_case! {"crate-name", "crate-name"}
+}
+
+#[test]
+fn test_punctuation() {
+    assert_case! {"Hello, World!", "hello-world"}
+    assert_case! {"a.b_c/d\\e", "a-b-c-d-e"}
+    assert_case! {"foo -- bar", "foo-bar"}
+    assert_case! {"what's new?!", "what-s-new"}
+    assert_case! {"1.0.0-beta.1", "1-0-0-beta-1"}
+}
+
+#[test]
+fn test_leading_trailing() {
+    assert_case! {"-", ""}
+    assert_case! {"!?#", ""}
+    assert_case! {"  foo  ", "foo"}
+    assert_case! {"#[derive(Debug)]", "derive-debug"}
+    assert_case! {"--__--bar baz!!", "bar-baz"}
+}
+
+#[test]
+fn test_non_ascii() {
+    assert_case! {"ñandú", "and"}
+    assert_case! {"Año 2000", "a-o-2000"}
+    assert_case! {"鉄錆", ""}
+    assert_case! {"rust 🦀 crab", "rust-crab"}
+}
@@ -0,0 +1,69 @@
+use const_format::__str_methods::StrLineArgs;
+use const_format::{first_line, last_line};
+
+macro_rules! assert_case {
+    ($input:expr, $first:expr, $last:expr $(,)*) => {{
+        const IN: &str = $input;
+        const FIRST: &str = $first;
+        const LAST: &str = $last;
+
+        assert_eq!(first_line!(IN), FIRST);
+        assert_eq!(last_line!(IN), LAST);
+
+        assert_eq!(IN.lines().next().unwrap_or(""), FIRST);
+        assert_eq!(IN.lines().last().unwrap_or(""), LAST);
+
+        // The output is a substring of the input
+        let first = StrLineArgs::first(IN);
+        assert_eq!(&IN[first.start..][..first.len], FIRST);
+        let last = StrLineArgs::last(IN);
+        assert_eq!(&IN[last.start..][..last.len], LAST);
+        assert_eq!(
+            last_line!(IN).as_ptr(),
+            IN.as_ptr().wrapping_add(last.start)
+        );
+    }};
+}
+
+#[test]
+fn test_lines() {
+    assert_case!("", "", "");
+    assert_case!("foo", "foo", "foo");
+    assert_case!("\n", "", "");
+    assert_case!("\r\n", "", "");
+    assert_case!("\n\n", "", "");
+    assert_case!("foo\n", "foo", "foo");
+    assert_case!("foo\nbar", "foo", "bar");
+    assert_case!("foo\nbar\n", "foo", "bar");
+    assert_case!("foo\nbar\n\n", "foo", "");
+    assert_case!("\nfoo\nbar", "", "bar");
+    assert_case!("foo\nbar\nbaz", "foo", "baz");
+    assert_case!("foo\r\nbar\r\n", "foo", "bar");
+    assert_case!("foo\r\nbar", "foo", "bar");
+    assert_case!("\r\nfoo\r\n\r\n", "", "");
+    assert_case!("foo\rbar\nbaz\rqux", "foo\rbar", "baz\rqux");
+    assert_case!("鉄錆\n効率的\n", "鉄錆", "効率的");
+}
+
+#[test]
+fn test_lines_crlf() {
+    assert_case!("\r", "\r", "\r");
+    assert_case!("\r\n\r\n", "", "");
+    assert_case!("\n\r\n", "", "");
+    assert_case!("\r\n\n", "", "");
+    assert_case!("foo\r", "foo\r", "foo\r");
+    assert_case!("foo\r\r\n", "foo\r", "foo\r");
+    assert_case!("\rfoo\r\nbar\r", "\rfoo", "bar\r");
+    assert_case!("foo\r\nbar\n\rbaz\r\n", "foo", "\rbaz");
+    assert_case!("foo\nbar\r\n\r", "foo", "\r");
+    assert_case!("鉄錆\r\n効率的\r\n", "鉄錆", "効率的");
+}
+
+#[test]
+fn test_lines_const() {
+    const TEXT: &str = "Title\n  body\n  more body\n";
+    const FIRST: &str = first_line!(TEXT);
+    const LAST: &str = last_line!(TEXT);
+    assert_eq!(FIRST, "Title");
+    assert_eq!(LAST, "  more body");
+}
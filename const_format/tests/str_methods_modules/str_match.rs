@@ -0,0 +1,55 @@
+use const_format::str_match;
+
+const fn classify(s: &str) -> u32 {
+    str_match!(s,
+        "foo" => 1,
+        "bar" | "baz" => 2,
+        "" => 3,
+        "ñ" | "foo bar" => 4,
+        _ => 0,
+    )
+}
+
+#[test]
+fn matches_patterns() {
+    const FOO: u32 = classify("foo");
+    const BAR: u32 = classify("bar");
+    const BAZ: u32 = classify("baz");
+    const EMPTY: u32 = classify("");
+
+    assert_eq!(FOO, 1);
+    assert_eq!(BAR, 2);
+    assert_eq!(BAZ, 2);
+    assert_eq!(EMPTY, 3);
+    assert_eq!(classify("ñ"), 4);
+    assert_eq!(classify("foo bar"), 4);
+}
+
+#[test]
+fn falls_back_to_default() {
+    assert_eq!(classify("fo"), 0);
+    assert_eq!(classify("fooo"), 0);
+    assert_eq!(classify("Foo"), 0);
+    assert_eq!(classify("n"), 0);
+    assert_eq!(classify(" "), 0);
+}
+
+#[test]
+fn only_default_arm() {
+    assert_eq!(str_match!("foo", _ => 'a'), 'a');
+    assert_eq!(str_match!("foo", _ => 'a',), 'a');
+}
+
+#[test]
+fn evaluates_string_once() {
+    let mut evaluated = 0;
+    let value = str_match!({evaluated += 1; "baz"}, "foo" => "a", "bar" => "b", _ => "c");
+    assert_eq!(value, "c");
+    assert_eq!(evaluated, 1);
+}
+
+#[test]
+fn in_const_item() {
+    const STR: &str = str_match!("bar", "foo" => "a", "bar" => "b", _ => "c");
+    assert_eq!(STR, "b");
+}
@@ -0,0 +1,46 @@
+use const_format::__str_methods::{str_max, str_min};
+use const_format::{str_max, str_min};
+
+macro_rules! assert_case {
+    ($input:expr, $min:expr, $max:expr $(,)*) => {{
+        const IN: &[&str] = &$input;
+
+        assert_eq!(str_min(IN), $min);
+        assert_eq!(str_max(IN), $max);
+        assert_eq!(str_min!($input), $min);
+        assert_eq!(str_max!($input), $max);
+        assert_eq!(str_min!(IN), $min);
+        assert_eq!(str_max!(IN), $max);
+
+        assert_eq!(IN.iter().min().copied(), Some($min));
+        assert_eq!(IN.iter().max().copied(), Some($max));
+    }};
+}
+
+#[test]
+fn test_str_min_max() {
+    // single element
+    assert_case! {[""], "", ""}
+    assert_case! {["foo"], "foo", "foo"}
+
+    assert_case! {["b", "a", "c"], "a", "c"}
+    assert_case! {["c", "b", "a"], "a", "c"}
+    assert_case! {["a", "b", "c"], "a", "c"}
+    assert_case! {["B", "a", "b", "A"], "A", "b"}
+    assert_case! {["ñ", "z", "鉄", "😀", "n"], "n", "😀"}
+}
+
+#[test]
+fn test_str_min_max_ties() {
+    assert_case! {["a", "a"], "a", "a"}
+    assert_case! {["b", "a", "b", "a"], "a", "b"}
+    assert_case! {["x", "y", "y", "x", "x"], "x", "y"}
+}
+
+#[test]
+fn test_str_min_max_prefixes() {
+    assert_case! {["ab", "abc"], "ab", "abc"}
+    assert_case! {["abc", "ab"], "ab", "abc"}
+    assert_case! {["abc", "", "ab", "a"], "", "abc"}
+    assert_case! {["abd", "abc", "ab", "abcd"], "ab", "abd"}
+}
@@ -0,0 +1,158 @@
+use const_format::__str_methods::{str_pad_bytes, PadSide, StrPadArgs};
+use const_format::{center, format_fixed_width_int, pad_end, pad_start};
+
+macro_rules! assert_case {
+    ($macro:ident, $side:ident, $input:expr, $width:expr, $fill:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const OUT: &str = $output;
+        const ARGS: StrPadArgs = StrPadArgs::new(IN, $width, $fill, PadSide::$side);
+
+        assert_eq!(ARGS.out_len, OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&str_pad_bytes::<{ ARGS.out_len }>(&ARGS)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!($macro!(IN, $width, $fill), OUT);
+    }};
+}
+
+#[test]
+fn test_pad_start() {
+    assert_case! {pad_start, Start, "", 0, '-', ""}
+    assert_case! {pad_start, Start, "", 3, '-', "---"}
+    assert_case! {pad_start, Start, "ab", 3, '-', "-ab"}
+    assert_case! {pad_start, Start, "ab", 6, '0', "0000ab"}
+
+    assert_eq!(pad_start!("ab", 6, '0'), format!("{:0>6}", "ab"));
+}
+
+#[test]
+fn test_pad_end() {
+    assert_case! {pad_end, End, "", 0, '-', ""}
+    assert_case! {pad_end, End, "", 3, '-', "---"}
+    assert_case! {pad_end, End, "ab", 3, '-', "ab-"}
+    assert_case! {pad_end, End, "ab", 6, '0', "ab0000"}
+
+    assert_eq!(pad_end!("ab", 6, '0'), format!("{:0<6}", "ab"));
+}
+
+#[test]
+fn test_center() {
+    assert_case! {center, Center, "", 0, '-', ""}
+    assert_case! {center, Center, "", 3, '-', "---"}
+
+    // even padding
+    assert_case! {center, Center, "ab", 4, '-', "-ab-"}
+    assert_case! {center, Center, "TITLE", 21, '*', "********TITLE********"}
+
+    // odd padding, the extra fill char goes at the end
+    assert_case! {center, Center, "ab", 3, '-', "ab-"}
+    assert_case! {center, Center, "ab", 5, '-', "-ab--"}
+    assert_case! {center, Center, "TITLE", 20, '*', "*******TITLE********"}
+
+    assert_case! {center, Center, "鉄錆", 5, '€', "€鉄錆€€"}
+
+    assert_eq!(center!("TITLE", 20, '*'), format!("{:*^20}", "TITLE"));
+    assert_eq!(center!("ab", 7, '0'), format!("{:0^7}", "ab"));
+}
+
+#[test]
+fn test_no_padding_needed() {
+    assert_case! {pad_start, Start, "abc", 0, '-', "abc"}
+    assert_case! {pad_start, Start, "abc", 2, '-', "abc"}
+    assert_case! {pad_start, Start, "abc", 3, '-', "abc"}
+    assert_case! {pad_end, End, "abc", 0, '-', "abc"}
+    assert_case! {pad_end, End, "abc", 2, '-', "abc"}
+    assert_case! {pad_end, End, "abc", 3, '-', "abc"}
+    assert_case! {center, Center, "abc", 0, '-', "abc"}
+    assert_case! {center, Center, "abc", 2, '-', "abc"}
+    assert_case! {center, Center, "abc", 3, '-', "abc"}
+
+    // the width is in chars, not bytes
+    assert_case! {pad_start, Start, "鉄錆", 2, '-', "鉄錆"}
+    assert_case! {pad_end, End, "鉄錆", 2, '-', "鉄錆"}
+    assert_case! {center, Center, "鉄錆", 2, '-', "鉄錆"}
+}
+
+#[test]
+fn test_multibyte() {
+    assert_case! {pad_start, Start, "ab", 4, 'ñ', "ññab"}
+    assert_case! {pad_start, Start, "ab", 4, '€', "€€ab"}
+    assert_case! {pad_start, Start, "ab", 4, '😀', "😀😀ab"}
+    assert_case! {pad_start, Start, "鉄錆", 5, '·', "···鉄錆"}
+    assert_case! {pad_end, End, "ab", 4, 'ñ', "abññ"}
+    assert_case! {pad_end, End, "ab", 4, '€', "ab€€"}
+    assert_case! {pad_end, End, "ab", 4, '😀', "ab😀😀"}
+    assert_case! {pad_end, End, "鉄錆", 5, '·', "鉄錆···"}
+}
+
+macro_rules! assert_int_case {
+    ($value:expr, $width:expr, $fill:expr, $output:expr $(,)*) => {{
+        const OUT: &str = format_fixed_width_int!($value, $width, $fill);
+        assert_eq!(OUT, $output);
+    }};
+}
+
+#[test]
+fn test_format_fixed_width_int() {
+    // padded
+    assert_int_case! {0u8, 3, '0', "000"}
+    assert_int_case! {7u16, 3, '0', "007"}
+    assert_int_case! {42u32, 5, ' ', "   42"}
+    assert_int_case! {42u64, 4, '·', "··42"}
+    assert_int_case! {42usize, 0, '0', "42"}
+
+    // wider than `width`, not truncated
+    assert_int_case! {12345u32, 3, '0', "12345"}
+    assert_int_case! {12345u32, 5, '0', "12345"}
+    assert_int_case! {u128::MAX, 4, ' ', "340282366920938463463374607431768211455"}
+
+    // negative
+    assert_int_case! {-42i8, 5, '0', "-0042"}
+    assert_int_case! {-42i16, 5, ' ', "  -42"}
+    assert_int_case! {-42i32, 5, '_', "__-42"}
+    assert_int_case! {-42i64, 2, '0', "-42"}
+    assert_int_case! {-42i64, 3, '0', "-42"}
+    assert_int_case! {-1isize, 4, '0', "-001"}
+    assert_int_case! {i128::MIN, 3, '0', "-170141183460469231731687303715884105728"}
+
+    // matches std's `{:0width$}` and `{:>width$}` formatting
+    assert_eq!(
+        format_fixed_width_int!(-123i32, 6, '0'),
+        format!("{:06}", -123i32)
+    );
+    assert_eq!(
+        format_fixed_width_int!(-123i32, 6, ' '),
+        format!("{:>6}", -123i32)
+    );
+    assert_eq!(
+        format_fixed_width_int!(123i32, 6, '0'),
+        format!("{:06}", 123i32)
+    );
+}
+
+#[test]
+fn test_too_large_width() {
+    const ARGS: [StrPadArgs; 4] = [
+        StrPadArgs::new("ab", usize::MAX, '-', PadSide::Start),
+        StrPadArgs::new("ab", usize::MAX, 'ñ', PadSide::End),
+        StrPadArgs::new("", usize::MAX / 2 + 1, '效', PadSide::Center),
+        StrPadArgs::new("-42", usize::MAX, '0', PadSide::StartAfterSign),
+    ];
+
+    for args in &ARGS {
+        assert!(args.too_large);
+        assert_eq!(args.padding, 0);
+        assert_eq!(args.out_len, args.str.len());
+    }
+
+    // the largest length that an array can have
+    const LARGEST: StrPadArgs = StrPadArgs::new("", isize::MAX as usize, '-', PadSide::End);
+    assert_eq!(LARGEST.out_len, isize::MAX as usize);
+
+    const TOO_LARGE: StrPadArgs = StrPadArgs::new("", isize::MAX as usize + 1, '-', PadSide::End);
+
+    assert_eq!([LARGEST.too_large, TOO_LARGE.too_large], [false, true]);
+}
@@ -0,0 +1,35 @@
+use const_format::__str_methods::str_reverse_bytes;
+use const_format::str_reverse;
+
+macro_rules! assert_case {
+    ($input:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const OUT: &str = $output;
+
+        assert_eq!(IN.chars().rev().collect::<String>(), OUT);
+
+        assert_eq!(
+            std::str::from_utf8(&str_reverse_bytes::<{ IN.len() }>(IN)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!(str_reverse!(IN), OUT);
+    }};
+}
+
+#[test]
+fn test_ascii() {
+    assert_case! {"", ""}
+    assert_case! {"a", "a"}
+    assert_case! {"ab", "ba"}
+    assert_case! {"hello world", "dlrow olleh"}
+}
+
+#[test]
+fn test_multibyte() {
+    assert_case! {"ñ", "ñ"}
+    assert_case! {"€😀", "😀€"}
+    assert_case! {"añb€c😀", "😀c€bña"}
+    assert_case! {"効率的", "的率効"}
+    assert_case! {"😀😁😂ab", "ba😂😁😀"}
+}
@@ -0,0 +1,68 @@
+use const_format::__str_methods::{SplitNInput, SplitNInputConv};
+use const_format::str_splitn;
+
+macro_rules! assert_case {
+    ($input:expr, $limit:expr, $patt:expr) => {
+        assert_case! {$input, $limit, $patt, $patt}
+    };
+    ($input:expr, $limit:expr, $patt:expr, $std_patt:expr) => {{
+        const IN: &str = $input;
+        const LIMIT: usize = $limit;
+        const ARGS: SplitNInput = SplitNInputConv(IN, LIMIT, $patt).conv();
+        const LEN: usize = ARGS.length();
+
+        let expected = IN.splitn(LIMIT, $std_patt).collect::<Vec<&str>>();
+
+        assert_eq!(LEN, expected.len());
+        assert_eq!(ARGS.split::<LEN>()[..], expected[..]);
+
+        const OUT: [&str; LEN] = str_splitn!(IN, LIMIT, $patt);
+        assert_eq!(OUT[..], expected[..]);
+    }};
+}
+
+#[test]
+fn test_str_pattern() {
+    assert_case! {"", 0, "-"}
+    assert_case! {"", 1, "-"}
+    assert_case! {"", 2, "-"}
+
+    assert_case! {"foo-bar-baz", 0, "-"}
+    assert_case! {"foo-bar-baz", 1, "-"}
+    assert_case! {"foo-bar-baz", 2, "-"}
+    assert_case! {"foo-bar-baz", 3, "-"}
+    assert_case! {"foo-bar-baz", 4, "-"}
+    assert_case! {"foo-bar-baz", 100, "-"}
+
+    assert_case! {"-foo--bar-", 10, "-"}
+    assert_case! {"foo--bar--baz", 2, "--"}
+    assert_case! {"foo--bar--baz", 10, "--"}
+    assert_case! {"lawlawnlawn", 10, "lawn"}
+    assert_case! {"aaaaa", 10, "aa"}
+    assert_case! {"foo", 10, "foobar"}
+    assert_case! {"鉄-效率的-鉄", 10, "效率"}
+}
+
+#[test]
+fn test_byte_pattern() {
+    assert_case! {"", 2, b'-', '-'}
+    assert_case! {"foo-bar-baz", 0, b'-', '-'}
+    assert_case! {"foo-bar-baz", 1, b'-', '-'}
+    assert_case! {"foo-bar-baz", 2, b'-', '-'}
+    assert_case! {"foo-bar-baz", 10, b'-', '-'}
+    assert_case! {"-鉄--效率的-", 10, b'-', '-'}
+}
+
+#[test]
+fn test_empty_pattern() {
+    assert_case! {"", 0, ""}
+    assert_case! {"", 1, ""}
+    assert_case! {"", 2, ""}
+    assert_case! {"", 3, ""}
+    assert_case! {"abc", 1, ""}
+    assert_case! {"abc", 2, ""}
+    assert_case! {"abc", 3, ""}
+    assert_case! {"abc", 100, ""}
+    assert_case! {"鉄效率的", 3, ""}
+    assert_case! {"鉄效率的", 100, ""}
+}
@@ -0,0 +1,62 @@
+use const_format::__str_methods::{strip_ansi_bytes, strip_ansi_len};
+use const_format::strip_ansi;
+
+macro_rules! assert_case {
+    ($input:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const OUT: &str = $output;
+
+        assert_eq!(strip_ansi_len(IN), OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&strip_ansi_bytes::<{ OUT.len() }>(IN)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!(strip_ansi!(IN), OUT);
+    }};
+}
+
+#[test]
+fn test_no_escapes() {
+    assert_case! {"", ""}
+    assert_case! {"hello world", "hello world"}
+    assert_case! {"[31m 鉄錆 efficient", "[31m 鉄錆 efficient"}
+}
+
+#[test]
+fn test_color_codes() {
+    assert_case! {"\x1b[m", ""}
+    assert_case! {"\x1b[31mred\x1b[0m", "red"}
+    assert_case! {"\x1b[1;4;38;5;208mñ\x1b[0m€", "ñ€"}
+    assert_case! {"a\x1b[31m\x1b[1mb\x1b[0mc", "abc"}
+    assert_case! {"\x1b[38;2;255;0;0m鉄\x1b[39m錆", "鉄錆"}
+}
+
+#[test]
+fn test_other_csi_sequences() {
+    // cursor movement and erasing
+    assert_case! {"\x1b[2J\x1b[Hfoo\x1b[10;20H", "foo"}
+    assert_case! {"\x1b[?25lbar\x1b[?25h", "bar"}
+    // intermediate bytes
+    assert_case! {"\x1b[1 qbaz", "baz"}
+}
+
+#[test]
+fn test_bare_escape() {
+    assert_case! {"\x1b", "\x1b"}
+    assert_case! {"foo\x1b", "foo\x1b"}
+    assert_case! {"\x1bfoo", "\x1bfoo"}
+    assert_case! {"\x1b\x1b[0m", "\x1b"}
+    assert_case! {"\x1b]0;title\x07", "\x1b]0;title\x07"}
+}
+
+#[test]
+fn test_incomplete_sequences() {
+    assert_case! {"\x1b[", "\x1b["}
+    assert_case! {"\x1b[31", "\x1b[31"}
+    assert_case! {"foo\x1b[31;", "foo\x1b[31;"}
+    // an invalid byte in the sequence
+    assert_case! {"\x1b[3ñm", "\x1b[3ñm"}
+    assert_case! {"\x1b[3\nm", "\x1b[3\nm"}
+}
@@ -0,0 +1,31 @@
+use const_format::strip_bom;
+
+#[test]
+fn strip_bom_with_bom() {
+    const IN: &str = "\u{FEFF}hello";
+    const OUT: &str = strip_bom!(IN);
+    assert_eq!(OUT, "hello");
+    assert_eq!(OUT.as_ptr(), IN[3..].as_ptr());
+
+    assert_eq!(strip_bom!("\u{FEFF}"), "");
+    assert_eq!(strip_bom!("\u{FEFF}ñ鉄"), "ñ鉄");
+
+    // only the first byte order mark is removed
+    assert_eq!(strip_bom!("\u{FEFF}\u{FEFF}foo"), "\u{FEFF}foo");
+}
+
+#[test]
+fn strip_bom_without_bom() {
+    const IN: &str = "hello\u{FEFF}";
+    const OUT: &str = strip_bom!(IN);
+    assert_eq!(OUT, IN);
+    // the string is returned unchanged, not copied
+    assert_eq!(OUT.as_ptr(), IN.as_ptr());
+    assert_eq!(OUT.len(), IN.len());
+
+    assert_eq!(strip_bom!(""), "");
+    assert_eq!(strip_bom!("h"), "h");
+    assert_eq!(strip_bom!(" \u{FEFF}"), " \u{FEFF}");
+    // other chars that start with the same bytes as the byte order mark
+    assert_eq!(strip_bom!("\u{FEFE}\u{FEFD}"), "\u{FEFE}\u{FEFD}");
+}
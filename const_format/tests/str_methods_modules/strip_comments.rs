@@ -0,0 +1,71 @@
+use const_format::__str_methods::{strip_comments_bytes, strip_comments_len, CommentSyntax};
+use const_format::{strip_c_comments, strip_comments};
+
+macro_rules! assert_case {
+    ($input:expr, $syntax:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const SYNTAX: CommentSyntax = $syntax;
+        const LEN: usize = strip_comments_len(IN, SYNTAX);
+        const OUT_B: [u8; LEN] = strip_comments_bytes::<LEN>(IN, SYNTAX);
+
+        assert_eq!(std::str::from_utf8(&OUT_B).unwrap(), $output);
+    }};
+}
+
+#[test]
+fn line_comments() {
+    assert_case! {"", CommentSyntax::line("#"), ""}
+    assert_case! {"no comments", CommentSyntax::line("#"), "no comments"}
+    assert_case! {"# comment", CommentSyntax::line("#"), ""}
+    assert_case! {"#", CommentSyntax::line("#"), ""}
+    assert_case! {"a\n# comment\nb", CommentSyntax::line("#"), "a\n\nb"}
+    assert_case! {"a\r\n# comment\r\nb", CommentSyntax::line("#"), "a\r\n\r\nb"}
+    assert_case! {"#a\n#b\n#c", CommentSyntax::line("#"), "\n\n"}
+
+    // a `#` that isn't at the start of the line
+    assert_case! {"key = value # comment\nnext", CommentSyntax::line("#"), "key = value \nnext"}
+    assert_case! {"  indented # comment", CommentSyntax::line("#"), "  indented "}
+    assert_case! {"a#b#c\nd", CommentSyntax::line("#"), "a\nd"}
+
+    // multi-byte markers
+    assert_case! {"a -- b\nc - d", CommentSyntax::line("--"), "a \nc - d"}
+    assert_case! {"x ;; y ; z", CommentSyntax::line(";;"), "x "}
+    assert_case! {"ñ → comentario\nsí", CommentSyntax::line("→"), "ñ \nsí"}
+
+    // the marker is found naively, even inside string literals
+    assert_case! {r#"url = "a#b""#, CommentSyntax::line("#"), r#"url = "a"#}
+}
+
+#[test]
+fn c_comments() {
+    assert_case! {"", CommentSyntax::C, ""}
+    assert_case! {"a / b * c", CommentSyntax::C, "a / b * c"}
+    assert_case! {"x; // comment\ny;", CommentSyntax::C, "x; \ny;"}
+    assert_case! {"//\n//\n", CommentSyntax::C, "\n\n"}
+
+    // block comments
+    assert_case! {"a /* b */ c", CommentSyntax::C, "a  c"}
+    assert_case! {"a/**/b/***/c", CommentSyntax::C, "abc"}
+    assert_case! {"a /* b\nc\nd */ e", CommentSyntax::C, "a  e"}
+    assert_case! {"a /* b // c */ d", CommentSyntax::C, "a  d"}
+    assert_case! {"a // b /* c\nd */ e", CommentSyntax::C, "a \nd */ e"}
+    assert_case! {"a /* unclosed\n b", CommentSyntax::C, "a "}
+    assert_case! {"a /*/ b */ c", CommentSyntax::C, "a  c"}
+
+    // block comments don't nest
+    assert_case! {"a /* b /* c */ d */ e", CommentSyntax::C, "a  d */ e"}
+}
+
+#[test]
+fn macro_forms() {
+    const TEXT: &str = "name = foo # the name\n# disabled = true\nsize = 3";
+    const STRIPPED: &str = strip_comments!(TEXT, "#");
+    assert_eq!(STRIPPED, "name = foo \n\nsize = 3");
+
+    assert_eq!(strip_comments!("a // b", "//"), "a ");
+    assert_eq!(strip_comments!("a /* b */", "//"), "a /* b */");
+
+    const SOURCE: &str = "/* header */\nint x; // x\nint y /* y */;\n";
+    const CODE: &str = strip_c_comments!(SOURCE);
+    assert_eq!(CODE, "\nint x; \nint y ;\n");
+}
@@ -0,0 +1,29 @@
+use const_format::swap_case;
+
+#[test]
+fn swap_case_ascii() {
+    assert_eq!(swap_case!("hello"), "HELLO");
+    assert_eq!(swap_case!("HELLO"), "hello");
+    assert_eq!(swap_case!("Hello World"), "hELLO wORLD");
+    assert_eq!(swap_case!("aZ"), "Az");
+}
+
+#[test]
+fn swap_case_unchanged() {
+    assert_eq!(swap_case!(""), "");
+    assert_eq!(swap_case!("42 + 7 = 49!"), "42 + 7 = 49!");
+    assert_eq!(swap_case!("_-[]{}@`"), "_-[]{}@`");
+
+    // only ASCII letters have their case swapped
+    assert_eq!(swap_case!("ñÑéÉ😀"), "ñÑéÉ😀");
+}
+
+#[test]
+fn swap_case_mixed() {
+    const MIXED: &str = swap_case!("HeLLo, wörld! ÀbC_123.");
+    assert_eq!(MIXED, "hEllO, WöRLD! ÀBc_123.");
+    assert_eq!(MIXED.len(), "HeLLo, wörld! ÀbC_123.".len());
+
+    // swapping twice returns the original string
+    assert_eq!(swap_case!(MIXED), "HeLLo, wörld! ÀbC_123.");
+}
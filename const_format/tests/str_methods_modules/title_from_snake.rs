@@ -0,0 +1,47 @@
+use const_format::__str_methods::{title_from_snake_bytes, title_from_snake_len};
+use const_format::title_from_snake;
+
+macro_rules! assert_case {
+    ($input:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const OUT: &str = $output;
+
+        assert_eq!(title_from_snake_len(IN), OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&title_from_snake_bytes::<{ OUT.len() }>(IN)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!(title_from_snake!(IN), OUT);
+    }};
+}
+
+#[test]
+fn test_words() {
+    assert_case! {"", ""}
+    assert_case! {"a", "A"}
+    assert_case! {"user", "User"}
+    assert_case! {"user_id", "User Id"}
+    assert_case! {"max_retry_count", "Max Retry Count"}
+    assert_case! {"a_b_c", "A B C"}
+}
+
+#[test]
+fn test_underscores() {
+    assert_case! {"_", ""}
+    assert_case! {"___", ""}
+    assert_case! {"_user_id", "User Id"}
+    assert_case! {"user_id_", "User Id"}
+    assert_case! {"__user___id__", "User Id"}
+}
+
+#[test]
+fn test_non_lowercase() {
+    assert_case! {"USER_ID", "USER ID"}
+    assert_case! {"user_Id", "User Id"}
+    assert_case! {"field_0_1", "Field 0 1"}
+    assert_case! {"ñandú_año", "ñandú Año"}
+    assert_case! {"鉄_錆", "鉄 錆"}
+    assert_case! {"foo bar_baz", "Foo bar Baz"}
+}
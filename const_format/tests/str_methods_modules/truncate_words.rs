@@ -0,0 +1,59 @@
+use const_format::truncate_words;
+
+fn std_truncate_words(s: &str, words: usize, ellipsis: &str) -> String {
+    if s.split_whitespace().count() <= words {
+        return s.to_string();
+    }
+    let end = s
+        .split_whitespace()
+        .take(words)
+        .last()
+        .map_or(0, |w| w.as_ptr() as usize - s.as_ptr() as usize + w.len());
+    format!("{}{}", &s[..end], ellipsis)
+}
+
+macro_rules! assert_case {
+    ($input:expr, $words:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const OUT: &str = truncate_words!(IN, $words, "…");
+
+        assert_eq!(OUT, $output);
+        assert_eq!(OUT, std_truncate_words(IN, $words, "…"), "input: {:?}", IN);
+    }};
+}
+
+#[test]
+fn truncated() {
+    assert_case! {"one two three four five six seven", 5, "one two three four five…"}
+    assert_case! {"one two three", 1, "one…"}
+    assert_case! {"one two three", 2, "one two…"}
+    assert_case! {"one two", 0, "…"}
+
+    // whitespace after the last kept word is removed
+    assert_case! {"  one\t\ttwo \n three  ", 2, "  one\t\ttwo…"}
+    assert_case! {"one\u{3000}two\u{2028}three", 2, "one\u{3000}two…"}
+
+    // non-ascii words
+    assert_case! {"ñandú über Öl", 2, "ñandú über…"}
+}
+
+#[test]
+fn not_truncated() {
+    assert_case! {"", 0, ""}
+    assert_case! {"", 5, ""}
+    assert_case! {"   ", 0, "   "}
+    assert_case! {"one two", 2, "one two"}
+    assert_case! {"one two", 5, "one two"}
+
+    // trailing whitespace is kept when nothing is truncated
+    assert_case! {" one two \n", 2, " one two \n"}
+}
+
+#[test]
+fn custom_ellipsis() {
+    const TEXT: &str = "Lorem ipsum dolor sit amet";
+
+    assert_eq!(truncate_words!(TEXT, 3, "..."), "Lorem ipsum dolor...");
+    assert_eq!(truncate_words!(TEXT, 3, ""), "Lorem ipsum dolor");
+    assert_eq!(truncate_words!(TEXT, 5, "..."), TEXT);
+}
@@ -0,0 +1,50 @@
+use const_format::__str_methods::word_count;
+use const_format::word_count;
+
+macro_rules! assert_case {
+    ($input:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const OUT: usize = word_count(IN);
+
+        assert_eq!(OUT, $output);
+        assert_eq!(OUT, IN.split_whitespace().count(), "input: {:?}", IN);
+        assert_eq!(word_count!(IN), $output);
+    }};
+}
+
+#[test]
+fn test_word_count() {
+    assert_case! {"", 0}
+    assert_case! {"foo", 1}
+    assert_case! {"foo bar", 2}
+
+    // multiple spaces
+    assert_case! {"foo  bar    baz", 3}
+
+    // tabs and newlines
+    assert_case! {"foo\tbar\t\tbaz", 3}
+    assert_case! {"foo\nbar\r\nbaz\x0Bqux\x0Cend", 5}
+
+    // leading and trailing whitespace
+    assert_case! {"   ", 0}
+    assert_case! {" \t\n ", 0}
+    assert_case! {"  foo", 1}
+    assert_case! {"foo  ", 1}
+    assert_case! {"\t foo bar \n", 2}
+
+    // non-ascii words and whitespace
+    assert_case! {"ñandú 鉄錆", 2}
+    assert_case! {"a\u{A0}b\u{85}c", 3}
+    assert_case! {"a\u{1680}b\u{2000}c\u{200A}d\u{2028}e\u{2029}f\u{202F}g\u{205F}h\u{3000}i", 9}
+    // not whitespace
+    assert_case! {"a\u{200B}b\u{2060}c\u{180E}d", 1}
+}
+
+#[test]
+fn whitespace_chars_match_std() {
+    for c in (0..=0x10FFFFu32).filter_map(core::char::from_u32) {
+        let s = format!("a{}b", c);
+        let expected = if c.is_whitespace() { 2 } else { 1 };
+        assert_eq!(word_count(&s), expected, "char: {:?}", c);
+    }
+}
@@ -0,0 +1,83 @@
+use const_format::__str_methods::{wrap_text_bytes, wrap_text_len};
+use const_format::wrap_text;
+
+macro_rules! assert_case {
+    ($input:expr, $width:expr, $output:expr $(,)*) => {{
+        const IN: &str = $input;
+        const OUT: &str = $output;
+
+        assert_eq!(wrap_text_len(IN, $width), OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&wrap_text_bytes::<{ OUT.len() }>(IN, $width)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!(wrap_text!(IN, $width), OUT);
+
+        for line in OUT.lines() {
+            assert!(
+                line.chars().count() <= $width || !line.contains(' '),
+                "line too long: {:?}",
+                line,
+            );
+        }
+    }};
+}
+
+#[test]
+fn test_short() {
+    assert_case! {"", 10, ""}
+    assert_case! {"foo", 10, "foo"}
+    assert_case! {"foo bar", 7, "foo bar"}
+    assert_case! {"foo bar", 6, "foo\nbar"}
+    assert_case! {"foo bar", 0, "foo\nbar"}
+}
+
+#[test]
+fn test_wrapping() {
+    assert_case! {
+        "the quick brown fox jumps over the lazy dog",
+        10,
+        "the quick\nbrown fox\njumps over\nthe lazy\ndog",
+    }
+    assert_case! {
+        "the quick brown fox jumps over the lazy dog",
+        16,
+        "the quick brown\nfox jumps over\nthe lazy dog",
+    }
+    assert_case! {"a b c d e f", 3, "a b\nc d\ne f"}
+}
+
+#[test]
+fn test_multibyte() {
+    // the width is in chars, not bytes
+    assert_case! {"ñañaña €€ 鉄錆 😀", 9, "ñañaña €€\n鉄錆 😀"}
+    assert_case! {"鉄 錆 鉄", 3, "鉄 錆\n鉄"}
+}
+
+#[test]
+fn test_long_words() {
+    assert_case! {"abcdefghij", 4, "abcdefghij"}
+    assert_case! {"ab abcdefghij cd", 4, "ab\nabcdefghij\ncd"}
+    assert_case! {"ab abcdefghij cd ef", 5, "ab\nabcdefghij\ncd ef"}
+    assert_case! {"abcdefghij klmnopqrst", 4, "abcdefghij\nklmnopqrst"}
+}
+
+#[test]
+fn test_spaces() {
+    assert_case! {"   ", 4, ""}
+    assert_case! {"foo   ", 4, "foo"}
+    assert_case! {"   foo", 4, "foo"}
+    assert_case! {"foo    bar", 10, "foo bar"}
+    assert_case! {"  foo  bar  baz  ", 7, "foo bar\nbaz"}
+}
+
+#[test]
+fn test_newlines() {
+    assert_case! {"\n", 4, "\n"}
+    assert_case! {"foo\nbar", 10, "foo\nbar"}
+    assert_case! {"foo bar\nbaz qux", 10, "foo bar\nbaz qux"}
+    assert_case! {"foo bar baz\n\nqux", 7, "foo bar\nbaz\n\nqux"}
+    assert_case! {"foo  \n  bar", 10, "foo\nbar"}
+}
@@ -0,0 +1,52 @@
+use const_format::__str_methods::{zip_join_bytes, ZipJoinArgs};
+use const_format::zip_join;
+
+macro_rules! assert_case {
+    ([$($left:expr),* $(,)?], [$($right:expr),* $(,)?], $pair_sep:expr, $item_sep:expr, $output:expr $(,)*) => {{
+        const LEFT: &[&str] = &[$($left),*];
+        const RIGHT: &[&str] = &[$($right),*];
+        const OUT: &str = $output;
+        const ARGS: ZipJoinArgs = ZipJoinArgs::new(LEFT, RIGHT, $pair_sep, $item_sep);
+
+        assert_eq!(ARGS.out_len, OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&zip_join_bytes::<{ ARGS.out_len }>(&ARGS)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!(zip_join!([$($left),*], [$($right),*], $pair_sep, $item_sep), OUT);
+
+        let expected = LEFT
+            .iter()
+            .zip(RIGHT)
+            .map(|(l, r)| format!("{}{}{}", l, $pair_sep, r))
+            .collect::<Vec<_>>()
+            .join($item_sep);
+        assert_eq!(OUT, expected);
+    }};
+}
+
+#[test]
+fn test_zip_join() {
+    assert_case! {[], [], "=", "\n", ""}
+    assert_case! {[""], [""], "", "", ""}
+    assert_case! {[""], [""], "=", "\n", "="}
+    assert_case! {["a"], ["1"], "=", "\n", "a=1"}
+    assert_case! {["a", "b"], ["1", "2"], "=", "\n", "a=1\nb=2"}
+    assert_case! {["a", "b", "c"], ["1", "2", "3"], "", "", "a1b2c3"}
+    assert_case! {["", "b", ""], ["1", "", ""], "=", ";", "=1;b=;="}
+    assert_case! {["key", "name"], ["value", "foo"], " = ", "\r\n", "key = value\r\nname = foo"}
+    assert_case! {["鉄", "ñ"], ["錆", "ü"], "→", "·", "鉄→錆·ñ→ü"}
+}
+
+#[test]
+fn test_zip_join_consts() {
+    const KEYS: [&str; 2] = ["HOST", "PORT"];
+    const VALUES: [&str; 2] = ["localhost", "8080"];
+    const PAIR_SEP: &str = "=";
+    const ITEM_SEP: &str = "\n";
+
+    const ENV: &str = zip_join!(KEYS, VALUES, PAIR_SEP, ITEM_SEP);
+    assert_eq!(ENV, "HOST=localhost\nPORT=8080");
+}
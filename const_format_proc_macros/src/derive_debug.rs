@@ -10,7 +10,7 @@ mod attribute_parsing;
 mod syntax;
 mod type_detection;
 
-use self::attribute_parsing::HowToFmt;
+use self::attribute_parsing::{ConstDebugConfig, HowToFmt};
 
 pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> Result<TokenStream2, crate::Error> {
     let ds = &DataStructure::new(&input);
@@ -56,10 +56,22 @@ pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> Result<TokenStream2,
         DataVariant::Union => panic!("Cannot derive ConstDebug on unions"),
     };
 
-    let variant_branches = ds.variants.iter().map(|variant| {
+    let variant_branches = ds.variants.iter().enumerate().map(|(vindex, variant)| {
         let vname = variant.name;
 
-        let debug_method = match variant.kind {
+        let vname_str = match &config.variants[vindex].rename {
+            Some(rename) => rename.clone(),
+            None => vname.to_string(),
+        };
+
+        // `#[cdeb(named_fields)]` prints tuple fields like the fields of braced structs.
+        let kind = if config.named_fields {
+            StructKind::Braced
+        } else {
+            variant.kind
+        };
+
+        let debug_method = match kind {
             StructKind::Braced => Ident::new("debug_struct", Span::call_site()),
             StructKind::Tupled => Ident::new("debug_tuple", Span::call_site()),
         };
@@ -89,8 +101,11 @@ pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> Result<TokenStream2,
 
                 let fspan = f.pattern_ident().span();
 
-                let field_name_str = match variant.kind {
-                    StructKind::Braced => Some(f.ident.to_string()),
+                let field_name_str = match kind {
+                    StructKind::Braced => Some(match &config.field_map[f].rename {
+                        Some(rename) => rename.clone(),
+                        None => f.ident.to_string(),
+                    }),
                     StructKind::Tupled => None,
                 }
                 .into_iter();
@@ -99,29 +114,28 @@ pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> Result<TokenStream2,
                     let mut field_formatter = formatter.field(#(#field_name_str)*);
                 );
 
-                field_ts.append_all(match &how_to_fmt {
-                    HowToFmt::Regular => coerce_and_fmt(&cratep, f),
-                    HowToFmt::Ignore => unreachable!(),
-                    HowToFmt::Slice => fmt_slice(&cratep, f),
-                    HowToFmt::Option_ => fmt_option(&cratep, f),
-                    HowToFmt::Newtype(newtype) => fmt_newtype(&cratep, newtype, f),
-                    HowToFmt::With(with) => call_with_function(&cratep, f, with),
-                    HowToFmt::WithMacro(with) => call_with_macro(&cratep, f, with),
-                    HowToFmt::WithWrapper(with) => call_with_wrapper(&cratep, f, with),
-                });
+                field_ts.append_all(fmt_field(&cratep, how_to_fmt, f));
 
                 Some(field_ts)
             });
 
         quote!(
             #enum_prefix #vname { #(#patt)* .. } => {
-                let mut formatter = formatter.#debug_method(stringify!(#vname));
+                let mut formatter = formatter.#debug_method(#vname_str);
                 #(#fmt_call)*
                 formatter.finish()
             }
         )
     });
 
+    let variant_branches: Vec<TokenStream2> = if config.as_int {
+        fmt_enum_as_int(ds, &cratep)?
+    } else if config.transparent {
+        vec![fmt_transparent(ds, &config, &cratep)?]
+    } else {
+        variant_branches.collect()
+    };
+
     let ret = quote!(
         #cratep::impl_fmt!{
             #impl_headers
@@ -145,6 +159,123 @@ pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> Result<TokenStream2,
     Ok(ret)
 }
 
+/// Generates the match arms for a `#[cdeb(as_int)]` enum,
+/// which print the discriminant of each variant as an integer.
+fn fmt_enum_as_int(
+    ds: &DataStructure<'_>,
+    cratep: &TokenStream2,
+) -> Result<Vec<TokenStream2>, crate::Error> {
+    if ds.data_variant != DataVariant::Enum {
+        return_spanned_err!(
+            ds.name,
+            "The `#[cdeb(as_int)]` attribute can only be used on enums."
+        );
+    }
+
+    let int_ty = enum_int_type(ds.attrs);
+
+    ds.variants
+        .iter()
+        .map(|variant| {
+            let vname = variant.name;
+            if !variant.fields.is_empty() {
+                return_spanned_err!(
+                    vname,
+                    "The `#[cdeb(as_int)]` attribute can only be used on enums \
+                     whose variants have no fields."
+                );
+            }
+
+            // Braced struct expressions can construct any kind of fieldless variant.
+            Ok(quote!(
+                Self::#vname { .. } => #cratep::pmr::PWrapper(
+                    (Self::#vname {}) as #int_ty
+                ).const_debug_fmt(formatter),
+            ))
+        })
+        .collect()
+}
+
+/// Generates the match arm for a `#[cdeb(transparent)]` struct,
+/// which prints its only non-ignored field as though it was the struct.
+fn fmt_transparent(
+    ds: &DataStructure<'_>,
+    config: &ConstDebugConfig<'_>,
+    cratep: &TokenStream2,
+) -> Result<TokenStream2, crate::Error> {
+    if ds.data_variant != DataVariant::Struct {
+        return_spanned_err!(
+            ds.name,
+            "The `#[cdeb(transparent)]` attribute can only be used on structs."
+        );
+    }
+
+    let variant = &ds.variants[0];
+    let mut fields = variant
+        .fields
+        .iter()
+        .filter(|f| !matches!(config.field_map[*f].how_to_fmt, HowToFmt::Ignore));
+
+    let field = match (fields.next(), fields.next()) {
+        (Some(field), None) => field,
+        _ => return_spanned_err!(
+            ds.name,
+            "The `#[cdeb(transparent)]` attribute can only be used on structs \
+             with exactly one non-ignored field."
+        ),
+    };
+
+    let pat = &field.ident;
+    let variable = field.pattern_ident();
+    let fmt_call = fmt_field(cratep, &config.field_map[field].how_to_fmt, field);
+
+    Ok(quote!(
+        Self { #pat: #variable, .. } => {
+            let field_formatter = formatter;
+            #fmt_call
+            #cratep::pmr::Ok(())
+        }
+    ))
+}
+
+/// Gets the integer type from the `#[repr(...)]` attributes of an enum,
+/// defaulting to `isize` if there isn't one.
+fn enum_int_type(attrs: &[syn::Attribute]) -> TokenStream2 {
+    const INT_TYPES: &[&str] = &[
+        "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64", "u128", "i128", "usize", "isize",
+    ];
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        if let Ok(syn::Meta::List(list)) = attr.parse_meta() {
+            for nested in list.nested {
+                if let syn::NestedMeta::Meta(syn::Meta::Path(path)) = nested {
+                    if let Some(ident) = path.get_ident() {
+                        if INT_TYPES.iter().any(|ty| ident == ty) {
+                            return ident.to_token_stream();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    quote!(isize)
+}
+
+/// Formats the field into the `field_formatter` variable.
+fn fmt_field(cratep: &TokenStream2, how_to_fmt: &HowToFmt<'_>, f: &Field<'_>) -> TokenStream2 {
+    match how_to_fmt {
+        HowToFmt::Regular => coerce_and_fmt(cratep, f),
+        HowToFmt::Ignore => unreachable!(),
+        HowToFmt::Slice => fmt_slice(cratep, f),
+        HowToFmt::Option_ => fmt_option(cratep, f),
+        HowToFmt::Newtype(newtype) => fmt_newtype(cratep, newtype, f),
+        HowToFmt::With(with) => call_with_function(cratep, f, with),
+        HowToFmt::WithMacro(with) => call_with_macro(cratep, f, with),
+        HowToFmt::WithWrapper(with) => call_with_wrapper(cratep, f, with),
+    }
+}
+
 // Copying the definitino of the `const_format::coerce_to_fn` macro here
 // because the compiler points inside the coerce_to_fn macro otherwise
 fn coerce_and_fmt(cratep: &TokenStream2, field: &Field<'_>) -> TokenStream2 {
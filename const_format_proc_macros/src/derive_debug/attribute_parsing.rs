@@ -1,5 +1,5 @@
 use crate::{
-    datastructure::{DataStructure, Field, FieldMap},
+    datastructure::{DataStructure, Field, FieldIdent, FieldMap},
     utils::LinearResult,
 };
 
@@ -13,9 +13,17 @@ use std::marker::PhantomData;
 
 pub(crate) struct ConstDebugConfig<'a> {
     pub(crate) debug_print: bool,
+    /// Whether the enum is printed as its discriminant value, with `#[cdeb(as_int)]`.
+    pub(crate) as_int: bool,
+    /// Whether the struct is printed as its only field, with `#[cdeb(transparent)]`.
+    pub(crate) transparent: bool,
+    /// Whether tuple fields are printed with their index as their name,
+    /// with `#[cdeb(named_fields)]`.
+    pub(crate) named_fields: bool,
     pub(crate) crate_path: Option<syn::Path>,
     pub(crate) impls: Vec<ImplHeader>,
     pub(crate) field_map: FieldMap<FieldConfig<'a>>,
+    pub(crate) variants: Vec<VariantConfig>,
     _marker: PhantomData<&'a ()>,
 }
 
@@ -23,18 +31,26 @@ impl<'a> ConstDebugConfig<'a> {
     fn new(roa: ConstDebugAttrs<'a>) -> Result<Self, crate::Error> {
         let ConstDebugAttrs {
             debug_print,
+            as_int,
+            transparent,
+            named_fields,
             crate_path,
             impls,
             field_map,
+            variants,
             errors: _,
             _marker: PhantomData,
         } = roa;
 
         Ok(Self {
             debug_print,
+            as_int,
+            transparent,
+            named_fields,
             crate_path,
             impls,
             field_map,
+            variants,
             _marker: PhantomData,
         })
     }
@@ -42,9 +58,13 @@ impl<'a> ConstDebugConfig<'a> {
 
 struct ConstDebugAttrs<'a> {
     debug_print: bool,
+    as_int: bool,
+    transparent: bool,
+    named_fields: bool,
     crate_path: Option<syn::Path>,
     impls: Vec<ImplHeader>,
     field_map: FieldMap<FieldConfig<'a>>,
+    variants: Vec<VariantConfig>,
     errors: LinearResult,
     _marker: PhantomData<&'a ()>,
 }
@@ -53,6 +73,13 @@ struct ConstDebugAttrs<'a> {
 
 pub(crate) struct FieldConfig<'a> {
     pub(crate) how_to_fmt: HowToFmt<'a>,
+    /// The name printed for the field, if it's not the name of the field itself.
+    pub(crate) rename: Option<String>,
+}
+
+pub(crate) struct VariantConfig {
+    /// The name printed for the variant, if it's not the name of the variant itself.
+    pub(crate) rename: Option<String>,
 }
 
 pub(crate) enum HowToFmt<'a> {
@@ -82,6 +109,7 @@ pub(crate) enum HowToFmt<'a> {
 #[derive(Copy, Clone)]
 enum ParseContext<'a> {
     TypeAttr,
+    Variant { index: usize },
     Field { field: &'a Field<'a> },
 }
 
@@ -90,11 +118,20 @@ pub(crate) fn parse_attrs_for_derive<'a>(
 ) -> Result<ConstDebugConfig<'a>, crate::Error> {
     let mut this = ConstDebugAttrs {
         debug_print: false,
+        as_int: false,
+        transparent: false,
+        named_fields: false,
         crate_path: None,
         impls: Vec::new(),
         field_map: FieldMap::with(ds, |f| FieldConfig {
             how_to_fmt: type_detection::detect_type_formatting(f.ty),
+            rename: None,
         }),
+        variants: ds
+            .variants
+            .iter()
+            .map(|_| VariantConfig { rename: None })
+            .collect(),
         errors: LinearResult::ok(),
         _marker: PhantomData,
     };
@@ -102,7 +139,9 @@ pub(crate) fn parse_attrs_for_derive<'a>(
     let ty_ctx = ParseContext::TypeAttr;
     parse_inner(&mut this, ds.attrs, ty_ctx)?;
 
-    for variant in &ds.variants {
+    for (index, variant) in ds.variants.iter().enumerate() {
+        parse_inner(&mut this, variant.attrs, ParseContext::Variant { index })?;
+
         for field in variant.fields.iter() {
             parse_inner(&mut this, field.attrs, ParseContext::Field { field })?;
         }
@@ -183,6 +222,16 @@ fn parse_sabi_attr<'a>(
                 f_config.how_to_fmt = HowToFmt::WithMacro(parse_lit(&nv.lit)?);
             } else if nv.path.is_ident("with_wrapper") {
                 f_config.how_to_fmt = HowToFmt::WithWrapper(parse_lit(&nv.lit)?);
+            } else if nv.path.is_ident("rename") {
+                if let (FieldIdent::Index(_), false) = (&field.ident, this.named_fields) {
+                    return_spanned_err!(
+                        nv,
+                        "The `#[cdeb(rename = \"...\")]` attribute can't be used on tuple fields, \
+                         because they are printed without their name \
+                         (unless the type has the `#[cdeb(named_fields)]` attribute)."
+                    );
+                }
+                f_config.rename = Some(parse_str_lit(&nv.lit)?);
             } else {
                 return Err(make_err(&nv));
             }
@@ -207,9 +256,22 @@ fn parse_sabi_attr<'a>(
                 return Err(make_err(&list));
             }
         }
+        (ParseContext::Variant { index, .. }, Meta::NameValue(nv)) => {
+            if nv.path.is_ident("rename") {
+                this.variants[index].rename = Some(parse_str_lit(&nv.lit)?);
+            } else {
+                return Err(make_err(&nv));
+            }
+        }
         (ParseContext::TypeAttr { .. }, Meta::Path(path)) => {
             if path.is_ident("debug_print") {
                 this.debug_print = true;
+            } else if path.is_ident("as_int") {
+                this.as_int = true;
+            } else if path.is_ident("transparent") {
+                this.transparent = true;
+            } else if path.is_ident("named_fields") {
+                this.named_fields = true;
             } else {
                 return Err(make_err(&path));
             }
@@ -280,6 +342,13 @@ where
     }
 }
 
+fn parse_str_lit(lit: &syn::Lit) -> Result<String, crate::Error> {
+    match lit {
+        syn::Lit::Str(x) => Ok(x.value()),
+        _ => Err(spanned_err!(lit, "Expected string literal")),
+    }
+}
+
 #[allow(dead_code)]
 fn parse_expr(lit: syn::Lit) -> Result<syn::Expr, crate::Error> {
     match lit {
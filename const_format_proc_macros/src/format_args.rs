@@ -39,6 +39,11 @@ pub(crate) struct FormatIfArgs {
     pub(crate) inner: FormatArgs,
 }
 
+/// The arguments of `format_arg_count`
+pub(crate) struct FormatArgCountArgs {
+    pub(crate) literal: FormatStr,
+}
+
 /// The arguments of `writec`
 pub(crate) struct WriteArgs {
     pub(crate) writer_expr: TokenStream2,
@@ -95,8 +100,30 @@ impl ExpandInto {
 
                 quote_spanned!(rawness.span()=> #formatter.write_str(#str_tokens) )
             }
+            ExpandInto::Formatted(fmted) if fmted.format.bool_labels.is_some() => {
+                let (on, off) = fmted.format.bool_labels.as_ref().unwrap();
+                let local_variable = &fmted.local_variable;
+                let span = local_variable.span();
+
+                let cond = quote::quote!(__cf_osRcTFl4A::coerce_to_fmt!(&#local_variable).0)
+                    .set_span_recursive(span);
+
+                quote::quote!( #formatter.write_str(if #cond { #on } else { #off }) )
+            }
+            ExpandInto::Formatted(fmted) if fmted.format.digit_grouping.is_some() => {
+                crate::Error::new(
+                    fmted.local_variable.span(),
+                    "digit grouping is only supported in the `formatcp` family of macros",
+                )
+                .to_compile_error()
+            }
+            ExpandInto::Formatted(fmted) if fmted.format.scientific.is_some() => crate::Error::new(
+                fmted.local_variable.span(),
+                "scientific notation is only supported in the `formatcp` family of macros",
+            )
+            .to_compile_error(),
             ExpandInto::Formatted(fmted) => {
-                let flags = fmted.format;
+                let flags = &fmted.format;
                 let fmt_method = fmted.format.fmt_method_name();
                 let local_variable = &fmted.local_variable;
                 let span = local_variable.span();
@@ -1,6 +1,6 @@
 use super::{
-    ExpandFormatted, ExpandInto, ExpandWithFormatter, FormatArg, FormatArgs, FormatIfArgs,
-    LocalVariable, UncheckedFormatArg, UncheckedFormatArgs, WriteArgs,
+    ExpandFormatted, ExpandInto, ExpandWithFormatter, FormatArg, FormatArgCountArgs, FormatArgs,
+    FormatIfArgs, LocalVariable, UncheckedFormatArg, UncheckedFormatArgs, WriteArgs,
 };
 
 use crate::{
@@ -8,7 +8,7 @@ use crate::{
     parse_utils::{LitStr, MyParse, ParseBuffer, ParseStream, TokenTreeExt},
     shared_arg_parsing::ExprArg,
     spanned::Spans,
-    utils::{dummy_ident, LinearResult},
+    utils::{dummy_ident, ident_from_unraw_name, unraw_ident_name, LinearResult},
 };
 
 use proc_macro2::{Ident, Span, TokenTree};
@@ -137,7 +137,7 @@ impl FormatArgs {
 
         let mut first_named_arg = unchecked_fargs.args.len();
 
-        let mut named_arg_names = Vec::<Ident>::new();
+        let mut named_arg_names = Vec::<String>::new();
         let mut args = Vec::<FormatArg>::with_capacity(unchecked_fargs.args.len());
         let mut local_variables = Vec::<LocalVariable>::with_capacity(unchecked_fargs.args.len());
 
@@ -161,8 +161,10 @@ impl FormatArgs {
                         first_named_arg = i;
                     }
 
-                    let name = make_ident(format!("{}{}", prefix, ident));
-                    named_arg_names.push(ident);
+                    // `r#type = ...` arguments are referred to as `{type}` in the format string
+                    let unraw_name = unraw_ident_name(&ident);
+                    let name = make_ident(format!("{}{}", prefix, unraw_name));
+                    named_arg_names.push(unraw_name);
                     name
                 } else {
                     if prev_is_named_arg {
@@ -224,7 +226,7 @@ impl FormatArgs {
                         } else {
                             // `formatcp!("{FOO}")` assumes that FOO is a constant in scope
                             return ExpandInto::Formatted(ExpandFormatted {
-                                local_variable: Ident::new(&ident, rawness.span()),
+                                local_variable: ident_from_unraw_name(&ident, rawness.span()),
                                 format: formatting,
                             });
                         }
@@ -260,6 +262,24 @@ impl FormatArgs {
 
                 match arg {
                     FormatArg::WithFormatter { fmt_ident, expr } => {
+                        if formatting.bool_labels.is_some() {
+                            res.push_err(crate::Error::new(
+                                rawness.span(),
+                                "can't use `bool(..)` formatting with custom formatting",
+                            ));
+                        }
+                        if formatting.digit_grouping.is_some() {
+                            res.push_err(crate::Error::new(
+                                rawness.span(),
+                                "can't use digit grouping with custom formatting",
+                            ));
+                        }
+                        if formatting.scientific.is_some() {
+                            res.push_err(crate::Error::new(
+                                rawness.span(),
+                                "can't use scientific notation with custom formatting",
+                            ));
+                        }
                         ExpandInto::WithFormatter(ExpandWithFormatter {
                             format: formatting,
                             fmt_ident: fmt_ident.clone(),
@@ -321,6 +341,20 @@ impl MyParse for FormatIfArgs {
 
 ////////////////////////////////////////////////
 
+impl MyParse for FormatArgCountArgs {
+    fn parse(input: ParseStream) -> Result<Self, crate::Error> {
+        let mut literal = FormatStr { list: Vec::new() };
+
+        let paren = input.parse_paren()?;
+        let mut content = ParseBuffer::new(paren.contents);
+        parse_fmt_lit(&mut literal, &mut content)?;
+
+        Ok(Self { literal })
+    }
+}
+
+////////////////////////////////////////////////
+
 impl MyParse for WriteArgs {
     fn parse(input: ParseStream) -> Result<Self, crate::Error> {
         let prefix = Ident::new("const_fmt_local_", Span::call_site());
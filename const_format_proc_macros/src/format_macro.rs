@@ -1,7 +1,9 @@
 use crate::{
-    format_args::{ExpandInto, FormatArgs, FormatIfArgs, LocalVariable, WriteArgs},
+    format_args::{
+        ExpandInto, FormatArgCountArgs, FormatArgs, FormatIfArgs, LocalVariable, WriteArgs,
+    },
     parse_utils::TokenStream2Ext,
-    shared_arg_parsing::{ExprArg, ExprArgs},
+    shared_arg_parsing::{ExprArg, ExprArgs, FormattingSpec},
     Error,
 };
 
@@ -17,11 +19,32 @@ mod tests;
 pub(crate) fn concatcp_impl(value: ExprArgs) -> Result<TokenStream2, crate::Error> {
     let fmt_var = Ident::new("fmt", Span::mixed_site());
 
-    let concat_args = value.args.iter().map(|ExprArg { expr, span }| {
-        quote_spanned!(span.start=>
-            __cf_osRcTFl4A::pmr::PConvWrapper(#expr).to_pargument_display(#fmt_var)
-        )
-    });
+    let concat_args = value.args.iter().map(
+        |ExprArg {
+             expr,
+             span,
+             formatting,
+         }| {
+            match formatting {
+                None => quote_spanned!(span.start=>
+                    __cf_osRcTFl4A::pmr::PConvWrapper(#expr).to_pargument_display(#fmt_var)
+                ),
+                Some(formatting) if formatting.bool_labels.is_some() => {
+                    let (on, off) = formatting.bool_labels.as_ref().unwrap();
+                    quote_spanned!(span.start=>
+                        __cf_osRcTFl4A::pmr::PConvWrapper(if #expr { #on } else { #off })
+                            .to_pargument_display(#fmt_var)
+                    )
+                }
+                Some(formatting) => {
+                    let to_pargument_m = formatting.to_pargument_method_name();
+                    quote_spanned!(span.start=>
+                        __cf_osRcTFl4A::pmr::PConvWrapper(#expr).#to_pargument_m(#formatting)
+                    )
+                }
+            }
+        },
+    );
 
     Ok(quote!(({
         // The suffix is to avoid name collisions with identifiers in the passed-in expression.
@@ -70,9 +93,19 @@ pub(crate) fn formatcp_impl(fmt_args: FormatArgs) -> Result<TokenStream2, crate:
                     .to_pargument_display(__cf_osRcTFl4A::pmr::FormattingFlags::NEW)
             )
         }
+        ExpandInto::Formatted(fmted) if fmted.format.bool_labels.is_some() => {
+            let (on, off) = fmted.format.bool_labels.as_ref().unwrap();
+            let local_variable = &fmted.local_variable;
+            let span = local_variable.span();
+            quote!(
+                __cf_osRcTFl4A::pmr::PConvWrapper(if #local_variable { #on } else { #off })
+                    .to_pargument_display(__cf_osRcTFl4A::pmr::FormattingFlags::NEW)
+            )
+            .set_span_recursive(span)
+        }
         ExpandInto::Formatted(fmted) => {
             let to_pargument_m = fmted.format.to_pargument_method_name();
-            let formatting = fmted.format;
+            let formatting = &fmted.format;
             let local_variable = &fmted.local_variable;
             let span = local_variable.span();
             // I had to use `set_span_recursive` to set the span to that of the argument,
@@ -163,6 +196,18 @@ pub(crate) fn formatc_macro_impl(fmt_args: FormatArgs) -> Result<TokenStream2, c
     })))
 }
 
+pub(crate) fn format_arg_count_impl(value: FormatArgCountArgs) -> Result<TokenStream2, Error> {
+    let count = proc_macro2::Literal::usize_suffixed(value.literal.distinct_arg_count());
+    Ok(quote!(#count))
+}
+
+pub(crate) fn formatting_flags_impl(value: FormattingSpec) -> Result<TokenStream2, Error> {
+    let formatting = value.formatting;
+    Ok(quote!(#formatting))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 pub(crate) fn writec_macro_impl(value: WriteArgs) -> Result<TokenStream2, Error> {
     let writer_expr = value.writer_expr;
     let writer_span = value.writer_span;
@@ -176,36 +221,63 @@ pub(crate) fn writec_macro_impl(value: WriteArgs) -> Result<TokenStream2, Error>
     let expr = local_variables.iter().map(|arg| &arg.expr);
 
     let strwriter = Ident::new("strwriter", Span::mixed_site());
+    let writer = Ident::new("writer", Span::mixed_site());
+    let computer = Ident::new("computer", Span::mixed_site());
+    let measuring = Ident::new("measuring", Span::mixed_site());
+    let marker = Ident::new("marker", Span::mixed_site());
 
     let writing_formatted = expanded_into.iter().map(|ei| ei.fmt_call(&strwriter));
 
     let borrow_mutably = quote_spanned!(writer_span=> ((#writer_expr).borrow_mutably()));
 
-    let make_formatter = quote_spanned!(writer_span =>
-        let mut marker = __cf_osRcTFl4A::pmr::IsAWriteMarker::NEW;
+    let coerce_writer = quote_spanned!(writer_span =>
+        let mut #marker = __cf_osRcTFl4A::pmr::IsAWriteMarker::NEW;
         if false {
-            marker = marker.infer_type(&#strwriter);
+            #marker = #marker.infer_type(&#strwriter);
         }
-        let mut #strwriter = marker.coerce(#strwriter);
-        let mut #strwriter =
-            #strwriter.make_formatter(__cf_osRcTFl4A::FormattingFlags::NEW);
+        let mut #writer = #marker.coerce(#strwriter);
+    );
+
+    let make_formatter = quote_spanned!(writer_span =>
+        let mut #strwriter = if #measuring {
+            #computer.make_formatter(__cf_osRcTFl4A::FormattingFlags::NEW)
+        } else {
+            #writer.make_formatter(__cf_osRcTFl4A::FormattingFlags::NEW)
+        };
     );
 
     Ok(quote! {({
+        // For the `borrow_mutably` method of types that can't have it as an inherent method.
+        #[allow(unused_imports)]
+        use __cf_osRcTFl4A::pmr::BorrowMutably as _;
 
         #[allow(non_snake_case)]
         match (#borrow_mutably, #(&(#expr),)*) {
             (#strwriter, #(#locals,)*) => {
-                #make_formatter
+                #coerce_writer
+
+                // Writers that grow (eg: `String`s) have capacity reserved for
+                // the formatted string before it's written,
+                // computing its length by formatting into a `ComputeStrLength` first.
+                let mut #computer = __cf_osRcTFl4A::pmr::ComputeStrLength::new();
+                let mut #measuring = #marker.needs_reserve();
 
                 loop {
+                    #make_formatter
+
                     #(
                         __cf_osRcTFl4A::unwrap_or_else!(
                             #writing_formatted,
                             |e| break __cf_osRcTFl4A::pmr::Err(e)
                         );
                     )*
-                    break __cf_osRcTFl4A::pmr::Ok(());
+
+                    if #measuring {
+                        #measuring = false;
+                        #marker.reserve(&mut #writer, #computer.len());
+                    } else {
+                        break __cf_osRcTFl4A::pmr::Ok(());
+                    }
                 }
             }
         }
@@ -82,3 +82,26 @@ fn unused_arguments() {
         }
     );
 }
+
+fn process_concatcp(s: &str) -> Result<String, String> {
+    MyParse::parse_token_stream_2(s.parse().unwrap())
+        .and_then(crate::format_macro::concatcp_impl)
+        .map(|x| x.to_string())
+        .map_err(|e| e.to_compile_error().to_string())
+}
+
+#[test]
+fn concatcp_formatting_spec() {
+    assert_ret!(process_concatcp(r##"(("foo")), ((100u8, "#x"))"##), |s| {
+        s.unwrap()
+            .consecutive_in_self(&["\"foo\"", "to_pargument_display", "100u8", "__A_HEX"])
+    });
+
+    assert_ret!(process_concatcp(r#"((100u8, "q"))"#), |s| {
+        s.unwrap_err()
+            .consecutive_in_self(&["unknown formatting", "q"])
+    });
+    assert_ret!(process_concatcp(r#"((100u8, "p"))"#), |s| {
+        s.unwrap_err().consecutive_in_self(&["pointer formatting"])
+    });
+}
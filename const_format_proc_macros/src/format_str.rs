@@ -7,7 +7,9 @@ mod parsing;
 #[cfg(test)]
 mod tests;
 
-pub(crate) use self::errors::{ParseError, ParseErrorKind};
+pub(crate) use self::errors::{ParseError, ParseErrorKind, Unsupported};
+
+pub(crate) use self::parsing::{is_ident, parse_formatting};
 
 #[derive(Debug, PartialEq)]
 pub(crate) struct FormatStr {
@@ -33,3 +35,38 @@ pub(crate) enum WhichArg {
     Ident(String),
     Positional(Option<usize>),
 }
+
+impl FormatStr {
+    /// The amount of distinct arguments that are used in the format string.
+    ///
+    /// `{}` arguments use the next positional argument, like in the formatting macros,
+    /// and arguments that are used multiple times are counted once.
+    pub(crate) fn distinct_arg_count(&self) -> usize {
+        let mut positional = Vec::<usize>::new();
+        let mut named = Vec::<&str>::new();
+        let mut next_pos = 0;
+
+        for component in &self.list {
+            if let FmtStrComponent::Arg(arg) = component {
+                match &arg.which_arg {
+                    WhichArg::Positional(opt_pos) => {
+                        let pos = opt_pos.unwrap_or_else(|| {
+                            next_pos += 1;
+                            next_pos - 1
+                        });
+                        if !positional.contains(&pos) {
+                            positional.push(pos);
+                        }
+                    }
+                    WhichArg::Ident(ident) => {
+                        if !named.contains(&&**ident) {
+                            named.push(ident);
+                        }
+                    }
+                }
+            }
+        }
+
+        positional.len() + named.len()
+    }
+}
@@ -28,6 +28,29 @@ pub(crate) enum ParseErrorKind {
     UnknownFormatting {
         what: String,
     },
+    /// Formatting that the standard library supports, but the formatting macros don't.
+    UnsupportedFormatting {
+        what: String,
+        reason: Unsupported,
+    },
+    /// A `{:bool(on,off)}` argument whose parentheses aren't balanced.
+    UnbalancedParens {
+        what: String,
+    },
+    /// Digit grouping (`{:,}` and `{:group(_)}`) combined with other formatting,
+    /// like `{:,x}` and `{:,group(_)}`.
+    ConflictingGrouping {
+        what: String,
+    },
+}
+
+/// The reason why an `UnsupportedFormatting` error isn't supported.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum Unsupported {
+    /// Pointer formatting (`{:p}`), which can't be done at compile-time.
+    Pointer,
+    /// Width or precision arguments, like `{:width$}` and `{:.1$}`.
+    Width,
 }
 
 #[allow(dead_code)]
@@ -47,6 +70,22 @@ impl ParseErrorKind {
             what: what.to_string(),
         }
     }
+    pub fn unsupported_formatting(what: &str, reason: Unsupported) -> Self {
+        Self::UnsupportedFormatting {
+            what: what.to_string(),
+            reason,
+        }
+    }
+    pub fn unbalanced_parens(what: &str) -> Self {
+        Self::UnbalancedParens {
+            what: what.to_string(),
+        }
+    }
+    pub fn conflicting_grouping(what: &str) -> Self {
+        Self::ConflictingGrouping {
+            what: what.to_string(),
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -65,6 +104,9 @@ impl ParseError {
             ParseErrorKind::NotANumber { what } => what.len(),
             ParseErrorKind::NotAnIdent { what } => what.len(),
             ParseErrorKind::UnknownFormatting { what } => what.len(),
+            ParseErrorKind::UnsupportedFormatting { what, .. } => what.len(),
+            ParseErrorKind::UnbalancedParens { what } => what.len(),
+            ParseErrorKind::ConflictingGrouping { what } => what.len(),
         };
 
         self.pos..self.pos + len
@@ -93,10 +135,37 @@ impl Display for ParseErrorKind {
             ParseErrorKind::UnknownFormatting { what } => {
                 writeln!(f, "unknown formatting: \"{}\"", what)
             }
+            ParseErrorKind::UnsupportedFormatting { what, reason } => {
+                writeln!(f, "unsupported formatting: \"{}\", {}", what, reason)
+            }
+            ParseErrorKind::UnbalancedParens { what } => {
+                writeln!(f, "unbalanced parentheses: \"{}\"", what)
+            }
+            ParseErrorKind::ConflictingGrouping { what } => writeln!(
+                f,
+                "conflicting formatting: \"{}\", \
+                 digit grouping (`,` and `group(_)`) can't be combined with other formatting",
+                what
+            ),
         }
     }
 }
 
+impl Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Unsupported::Pointer => {
+                "pointer formatting isn't available in const contexts, \
+                 because the addresses of pointers aren't known at compile-time"
+            }
+            Unsupported::Width => {
+                "width and precision arguments aren't supported, \
+                 use the `pad_start` or `pad_end` macros to pad strings instead"
+            }
+        })
+    }
+}
+
 impl Display for DisplayParseError<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("failed to parse the format string ")?;
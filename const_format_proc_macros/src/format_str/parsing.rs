@@ -1,10 +1,14 @@
-use super::{FmtArg, FmtStrComponent, FormatStr, ParseError, ParseErrorKind, WhichArg};
+use super::{
+    FmtArg, FmtStrComponent, FormatStr, ParseError, ParseErrorKind, Unsupported, WhichArg,
+};
 
 use crate::{
-    formatting::{FormattingFlags, IsAlternate, NumberFormatting},
+    formatting::{Formatting, FormattingFlags, IsAlternate, NumberFormatting, Scientific},
     parse_utils::StrRawness,
 };
 
+use proc_macro2::Ident;
+
 #[cfg(test)]
 impl FmtStrComponent {
     pub(super) fn str(s: &str) -> Self {
@@ -127,7 +131,7 @@ fn parse_fmt_arg(input: &str, starts_at: usize, rawness: StrRawness) -> Result<F
 
     Ok(FmtArg::new(
         parse_which_arg(which_arg_str, starts_at)?,
-        parse_formatting(formatting_str, formatting_starts_at)?,
+        parse_formatting(formatting_str, formatting_starts_at, rawness)?,
         rawness,
     ))
 }
@@ -156,10 +160,30 @@ fn parse_which_arg(input: &str, starts_at: usize) -> Result<WhichArg, ParseError
 /// Parses the `?` and other formatters inside formatting arguments (`{}`).
 ///
 /// `starts_at` is the offset of `input` in the formatting string.
-fn parse_formatting(input: &str, starts_at: usize) -> Result<FormattingFlags, ParseError> {
+pub(crate) fn parse_formatting(
+    input: &str,
+    starts_at: usize,
+    rawness: StrRawness,
+) -> Result<FormattingFlags, ParseError> {
     match input {
         "#" => return Ok(FormattingFlags::display(IsAlternate::Yes)),
         "" => return Ok(FormattingFlags::display(IsAlternate::No)),
+        _ if input.starts_with('@') => {
+            return parse_flags_const(&input[1..], starts_at + 1, rawness);
+        }
+        _ if input.starts_with("bool(") => {
+            return parse_bool_labels(input, starts_at);
+        }
+        "," => return Ok(FormattingFlags::digit_grouping(',')),
+        _ if input.starts_with("group(") => {
+            return parse_digit_grouping(input, starts_at);
+        }
+        _ if input.contains(',') || input.contains("group(") => {
+            return Err(ParseError {
+                pos: starts_at,
+                kind: ParseErrorKind::conflicting_grouping(input),
+            });
+        }
         _ => {}
     }
 
@@ -172,6 +196,29 @@ fn parse_formatting(input: &str, starts_at: usize) -> Result<FormattingFlags, Pa
         },
     };
 
+    if let b"p" | b"#p" = bytes {
+        return Err(ParseError {
+            pos: starts_at,
+            kind: ParseErrorKind::unsupported_formatting(input, Unsupported::Pointer),
+        });
+    }
+
+    if input.contains('$') {
+        return Err(ParseError {
+            pos: starts_at,
+            kind: ParseErrorKind::unsupported_formatting(input, Unsupported::Width),
+        });
+    }
+
+    let is_uppercase = match bytes.last() {
+        Some(b'e') => Some(false),
+        Some(b'E') => Some(true),
+        _ => None,
+    };
+    if let Some(is_uppercase) = is_uppercase {
+        return parse_scientific(&input[..input.len() - 1], is_uppercase).ok_or_else(make_error);
+    }
+
     if let [before @ .., b'?'] = bytes {
         bytes = before;
     }
@@ -190,6 +237,124 @@ fn parse_formatting(input: &str, starts_at: usize) -> Result<FormattingFlags, Pa
     Ok(FormattingFlags::debug(num_fmt, is_alternate))
 }
 
+/// Parses the `FLAGS` in `{:@FLAGS}` and `{:@FLAGS?}` formatting arguments,
+/// which is the name of a `FormattingFlags` constant.
+///
+/// `starts_at` is the offset of `input` in the formatting string.
+fn parse_flags_const(
+    input: &str,
+    starts_at: usize,
+    rawness: StrRawness,
+) -> Result<FormattingFlags, ParseError> {
+    let (ident_str, formatting) = match input.strip_suffix('?') {
+        Some(ident_str) => (ident_str, Formatting::Debug(NumberFormatting::Decimal)),
+        None => (input, Formatting::Display),
+    };
+
+    if is_ident(ident_str) {
+        let ident = Ident::new(ident_str, rawness.span());
+        Ok(FormattingFlags::from_const(formatting, ident))
+    } else {
+        Err(ParseError {
+            pos: starts_at,
+            kind: ParseErrorKind::NotAnIdent {
+                what: ident_str.to_string(),
+            },
+        })
+    }
+}
+
+/// Parses the `bool(on,off)` in `{:bool(on,off)}` formatting arguments,
+/// the labels are used verbatim, including any whitespace.
+///
+/// `starts_at` is the offset of `input` in the formatting string.
+fn parse_bool_labels(input: &str, starts_at: usize) -> Result<FormattingFlags, ParseError> {
+    let inside = &input["bool(".len()..];
+
+    let labels = match inside.strip_suffix(')') {
+        Some(labels) if !labels.contains(&['(', ')'][..]) => labels,
+        _ => {
+            return Err(ParseError {
+                pos: starts_at,
+                kind: ParseErrorKind::unbalanced_parens(input),
+            })
+        }
+    };
+
+    let mut labels_iter = labels.split(',');
+    match (labels_iter.next(), labels_iter.next(), labels_iter.next()) {
+        (Some(on), Some(off), None) => Ok(FormattingFlags::bool_labels(
+            on.to_string(),
+            off.to_string(),
+        )),
+        _ => Err(ParseError {
+            pos: starts_at,
+            kind: ParseErrorKind::unknown_formatting(input),
+        }),
+    }
+}
+
+/// Parses the `group(_)` in `{:group(_)}` formatting arguments,
+/// where the separator between groups of digits is a single character.
+///
+/// `starts_at` is the offset of `input` in the formatting string.
+fn parse_digit_grouping(input: &str, starts_at: usize) -> Result<FormattingFlags, ParseError> {
+    let inside = &input["group(".len()..];
+
+    let unbalanced_parens = || ParseError {
+        pos: starts_at,
+        kind: ParseErrorKind::unbalanced_parens(input),
+    };
+
+    let close = inside.find(')').ok_or_else(unbalanced_parens)?;
+    let (separator, after) = (&inside[..close], &inside[close + 1..]);
+
+    if separator.contains('(') || after.contains(&['(', ')'][..]) {
+        return Err(unbalanced_parens());
+    }
+
+    if !after.is_empty() {
+        return Err(ParseError {
+            pos: starts_at,
+            kind: ParseErrorKind::conflicting_grouping(input),
+        });
+    }
+
+    let mut chars = separator.chars();
+    match (chars.next(), chars.next()) {
+        (Some(separator), None) => Ok(FormattingFlags::digit_grouping(separator)),
+        _ => Err(ParseError {
+            pos: starts_at,
+            kind: ParseErrorKind::unknown_formatting(input),
+        }),
+    }
+}
+
+/// Parses the flags before the `e` or `E` in `{:e}`, `{:#E}`, and `{:.2e}` formatting arguments,
+/// returning `None` if they're invalid.
+fn parse_scientific(input: &str, is_uppercase: bool) -> Option<FormattingFlags> {
+    let (is_alternate, input) = match input.strip_prefix('#') {
+        Some(input) => (IsAlternate::Yes, input),
+        None => (IsAlternate::No, input),
+    };
+
+    let precision = match input.strip_prefix('.') {
+        Some(digits) if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) => {
+            Some(digits.parse::<usize>().ok()?)
+        }
+        None if input.is_empty() => None,
+        _ => return None,
+    };
+
+    Some(FormattingFlags::scientific(
+        is_alternate,
+        Scientific {
+            is_uppercase,
+            precision,
+        },
+    ))
+}
+
 /// Parses an identifier in a formatting argument.
 ///
 /// `starts_at` is the offset of `input` in the formatting string.
@@ -208,7 +373,7 @@ fn parse_ident(ident_str: &str, starts_at: usize) -> Result<WhichArg, ParseError
 
 ////////////////////////////////////////////////////////////////////////////////
 
-fn is_ident(s: &str) -> bool {
+pub(crate) fn is_ident(s: &str) -> bool {
     use unicode_xid::UnicodeXID;
 
     if s.is_empty() || s == "_" {
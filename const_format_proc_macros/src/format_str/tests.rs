@@ -2,7 +2,7 @@ use super::*;
 
 use super::{ParseError as PE, ParseErrorKind as PEK};
 
-use crate::formatting::{FormattingFlags as FF, IsAlternate, NumberFormatting};
+use crate::formatting::{FormattingFlags as FF, IsAlternate, NumberFormatting, Scientific};
 
 use fastrand::Rng;
 
@@ -124,6 +124,14 @@ fn not_an_ident() {
             kind: PEK::not_an_ident("_?")
         }
     );
+    // like in std, raw identifiers aren't supported in format strings
+    assert_eq!(
+        err("  {r#type} "),
+        PE {
+            pos: 3,
+            kind: PEK::not_an_ident("r#type")
+        }
+    );
 }
 
 #[test]
@@ -144,6 +152,380 @@ fn unknown_formatting() {
     );
 }
 
+#[test]
+fn unsupported_formatting() {
+    for (input, what) in [
+        (" {:p}", "p"),
+        (" {:#p}", "#p"),
+        (" {0:p}", "p"),
+        (" {x:p}", "p"),
+    ] {
+        let error = err(input);
+        assert_eq!(
+            error,
+            PE {
+                pos: input.find(':').unwrap() + 1,
+                kind: PEK::unsupported_formatting(what, Unsupported::Pointer)
+            }
+        );
+    }
+
+    let message = PEK::unsupported_formatting("p", Unsupported::Pointer).to_string();
+    assert!(
+        message.starts_with("unsupported formatting: \"p\""),
+        "{}",
+        message
+    );
+    assert!(message.contains("pointer formatting"), "{}", message);
+    assert!(message.contains("const contexts"), "{}", message);
+
+    // `p` is not special cased when combined with other formatting
+    assert_eq!(
+        err("{:p?}"),
+        PE {
+            pos: 2,
+            kind: PEK::unknown_formatting("p?")
+        }
+    );
+}
+
+#[test]
+fn unsupported_width() {
+    for (input, what) in [
+        ("{:width$}", "width$"),
+        ("{val:width$}", "width$"),
+        ("{0:1$}", "1$"),
+        ("{:.prec$}", ".prec$"),
+        ("{:>width$?}", ">width$?"),
+    ] {
+        let error = err(input);
+        assert_eq!(
+            error,
+            PE {
+                pos: input.find(':').unwrap() + 1,
+                kind: PEK::unsupported_formatting(what, Unsupported::Width)
+            }
+        );
+    }
+
+    let message = PEK::unsupported_formatting("width$", Unsupported::Width).to_string();
+    assert!(
+        message.starts_with("unsupported formatting: \"width$\""),
+        "{}",
+        message
+    );
+    assert!(message.contains("pad_start"), "{}", message);
+}
+
+#[test]
+fn scientific() {
+    let sci = |is_alternate, is_uppercase, precision| {
+        FF::scientific(
+            is_alternate,
+            Scientific {
+                is_uppercase,
+                precision,
+            },
+        )
+    };
+
+    assert_eq!(
+        ok("{:e}{:E}{0:#e}{x:.2e}{:#.10E}{:.0e}").list,
+        vec![
+            FmtStrComponent::arg(WhichArg::Positional(None), sci(NOALT, false, None)),
+            FmtStrComponent::arg(WhichArg::Positional(None), sci(NOALT, true, None)),
+            FmtStrComponent::arg(
+                WhichArg::Positional(Some(0)),
+                sci(IsAlternate::Yes, false, None)
+            ),
+            FmtStrComponent::arg(WhichArg::ident("x"), sci(NOALT, false, Some(2))),
+            FmtStrComponent::arg(
+                WhichArg::Positional(None),
+                sci(IsAlternate::Yes, true, Some(10))
+            ),
+            FmtStrComponent::arg(WhichArg::Positional(None), sci(NOALT, false, Some(0))),
+        ]
+    );
+
+    for (input, what) in [
+        ("{:.e}", ".e"),
+        ("{:.+1e}", ".+1e"),
+        ("{:.1E?}", ".1E?"),
+        ("{:e?}", "e?"),
+        ("{:?e}", "?e"),
+        ("{:xe}", "xe"),
+        ("{:.2#e}", ".2#e"),
+        ("{:.99999999999999999999999e}", ".99999999999999999999999e"),
+    ] {
+        assert_eq!(
+            err(input),
+            PE {
+                pos: 2,
+                kind: PEK::unknown_formatting(what)
+            }
+        );
+    }
+
+    // digit grouping can't be combined with scientific notation
+    assert_eq!(
+        err("{:,e}"),
+        PE {
+            pos: 2,
+            kind: PEK::conflicting_grouping(",e")
+        }
+    );
+
+    // precision arguments are reported as unsupported width
+    assert_eq!(
+        err("{:.prec$e}"),
+        PE {
+            pos: 2,
+            kind: PEK::unsupported_formatting(".prec$e", Unsupported::Width)
+        }
+    );
+}
+
+// Fill and alignment (eg: `{:-<5}`) aren't supported by the formatting macros,
+// these tests make sure that fill characters which are also part of the
+// format string grammar (`{`, `}`, `:`) are reported as errors,
+// instead of being parsed as something else.
+#[test]
+fn grammar_chars_as_fill() {
+    assert_eq!(
+        err("{:{<5}"),
+        PE {
+            pos: 2,
+            kind: PEK::unknown_formatting("{<5")
+        }
+    );
+    assert_eq!(
+        err("  {:{}"),
+        PE {
+            pos: 4,
+            kind: PEK::unknown_formatting("{")
+        }
+    );
+    assert_eq!(
+        err("{::<5}"),
+        PE {
+            pos: 2,
+            kind: PEK::unknown_formatting(":<5")
+        }
+    );
+    assert_eq!(
+        err("{0::^5?}"),
+        PE {
+            pos: 3,
+            kind: PEK::unknown_formatting(":^5?")
+        }
+    );
+    // The `}` closes the argument, so the `}` after the alignment is unmatched.
+    assert_eq!(
+        err("{:}<5}"),
+        PE {
+            pos: 5,
+            kind: PEK::InvalidClosedArg
+        }
+    );
+    // The first `}` closes the argument, so the second one isn't part of an escape.
+    assert_eq!(
+        err("{:}}>5?}"),
+        PE {
+            pos: 3,
+            kind: PEK::InvalidClosedArg
+        }
+    );
+}
+
+#[test]
+fn flags_const() {
+    use crate::formatting::Formatting;
+
+    use proc_macro2::{Ident, Span};
+
+    let flags_const =
+        |formatting, name| FF::from_const(formatting, Ident::new(name, Span::mixed_site()));
+    let debug = Formatting::Debug(NFDEC);
+
+    assert_eq!(
+        ok("{:@FOO}{0:@BAR?}{baz:@_Qux?}").list,
+        vec![
+            FmtStrComponent::arg(
+                WhichArg::Positional(None),
+                flags_const(Formatting::Display, "FOO")
+            ),
+            FmtStrComponent::arg(WhichArg::Positional(Some(0)), flags_const(debug, "BAR")),
+            FmtStrComponent::arg(WhichArg::ident("baz"), flags_const(debug, "_Qux")),
+        ]
+    );
+
+    assert_eq!(
+        err("  {:@} "),
+        PE {
+            pos: 5,
+            kind: PEK::not_an_ident("")
+        }
+    );
+    assert_eq!(
+        err("  {:@?} "),
+        PE {
+            pos: 5,
+            kind: PEK::not_an_ident("")
+        }
+    );
+    assert_eq!(
+        err("   {:@A B} "),
+        PE {
+            pos: 6,
+            kind: PEK::not_an_ident("A B")
+        }
+    );
+    assert_eq!(
+        err("   {:@#x?} "),
+        PE {
+            pos: 6,
+            kind: PEK::not_an_ident("#x")
+        }
+    );
+}
+
+#[test]
+fn bool_labels() {
+    let labels = |on: &str, off: &str| FF::bool_labels(on.to_string(), off.to_string());
+
+    assert_eq!(
+        ok("{:bool(on,off)}{0:bool(,)}{foo:bool( yes , no:)}").list,
+        vec![
+            FmtStrComponent::arg(WhichArg::Positional(None), labels("on", "off")),
+            FmtStrComponent::arg(WhichArg::Positional(Some(0)), labels("", "")),
+            FmtStrComponent::arg(WhichArg::ident("foo"), labels(" yes ", " no:")),
+        ]
+    );
+
+    assert_eq!(
+        err("  {:bool(on,off} "),
+        PE {
+            pos: 4,
+            kind: PEK::unbalanced_parens("bool(on,off")
+        }
+    );
+    assert_eq!(
+        err("  {:bool((on),off)} "),
+        PE {
+            pos: 4,
+            kind: PEK::unbalanced_parens("bool((on),off)")
+        }
+    );
+    assert_eq!(
+        err("  {:bool(on,off))} "),
+        PE {
+            pos: 4,
+            kind: PEK::unbalanced_parens("bool(on,off))")
+        }
+    );
+    assert_eq!(
+        err("  {:bool(on)} "),
+        PE {
+            pos: 4,
+            kind: PEK::unknown_formatting("bool(on)")
+        }
+    );
+    assert_eq!(
+        err("  {:bool(a,b,c)} "),
+        PE {
+            pos: 4,
+            kind: PEK::unknown_formatting("bool(a,b,c)")
+        }
+    );
+}
+
+#[test]
+fn digit_grouping() {
+    let grouping = FF::digit_grouping;
+
+    assert_eq!(
+        ok("{:,}{0:group(_)}{foo:group( )}{:group(.)}{:group(,)}").list,
+        vec![
+            FmtStrComponent::arg(WhichArg::Positional(None), grouping(',')),
+            FmtStrComponent::arg(WhichArg::Positional(Some(0)), grouping('_')),
+            FmtStrComponent::arg(WhichArg::ident("foo"), grouping(' ')),
+            FmtStrComponent::arg(WhichArg::Positional(None), grouping('.')),
+            FmtStrComponent::arg(WhichArg::Positional(None), grouping(',')),
+        ]
+    );
+
+    let conflicting = [
+        ("  {:,?} ", ",?"),
+        ("  {:,x} ", ",x"),
+        ("  {:#,} ", "#,"),
+        ("  {:,,} ", ",,"),
+        ("  {:,group(_)} ", ",group(_)"),
+        ("  {:group(_),} ", "group(_),"),
+        ("  {:?group(_)} ", "?group(_)"),
+    ];
+    for (input, what) in conflicting {
+        assert_eq!(
+            err(input),
+            PE {
+                pos: 4,
+                kind: PEK::conflicting_grouping(what)
+            }
+        );
+    }
+
+    assert_eq!(
+        err("  {:group(_} "),
+        PE {
+            pos: 4,
+            kind: PEK::unbalanced_parens("group(_")
+        }
+    );
+    assert_eq!(
+        err("  {:group(()} "),
+        PE {
+            pos: 4,
+            kind: PEK::unbalanced_parens("group(()")
+        }
+    );
+    assert_eq!(
+        err("  {:group()} "),
+        PE {
+            pos: 4,
+            kind: PEK::unknown_formatting("group()")
+        }
+    );
+    assert_eq!(
+        err("  {:group(__)} "),
+        PE {
+            pos: 4,
+            kind: PEK::unknown_formatting("group(__)")
+        }
+    );
+}
+
+#[test]
+fn distinct_arg_count() {
+    assert_eq!(ok("").distinct_arg_count(), 0);
+    assert_eq!(ok("foo {{}} bar").distinct_arg_count(), 0);
+    assert_eq!(ok("{:@FLAGS}").distinct_arg_count(), 1);
+
+    // positional arguments
+    assert_eq!(ok("{}").distinct_arg_count(), 1);
+    assert_eq!(ok("{} {:?} {:x}").distinct_arg_count(), 3);
+    assert_eq!(ok("{0} {0:?} {1}").distinct_arg_count(), 2);
+    assert_eq!(ok("{} {0}").distinct_arg_count(), 1);
+    assert_eq!(ok("{1} {} {}").distinct_arg_count(), 2);
+    assert_eq!(ok("{3}").distinct_arg_count(), 1);
+
+    // named arguments
+    assert_eq!(ok("{x}").distinct_arg_count(), 1);
+    assert_eq!(ok("{x} {y:?} {x:b}").distinct_arg_count(), 2);
+
+    // mixed arguments
+    assert_eq!(ok("{} {x} {}").distinct_arg_count(), 3);
+    assert_eq!(ok("{x} {0} {} {x} {y}").distinct_arg_count(), 3);
+}
+
 #[test]
 fn ok_cases() {
     assert_eq!(
@@ -41,10 +41,31 @@ pub(crate) enum IsAlternate {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// The scientific notation of `{:e}`, `{:E}`, and `{:.2e}` arguments.
 #[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Scientific {
+    pub(crate) is_uppercase: bool,
+    /// The digits after the decimal point of the mantissa, `2` in `{:.2e}`.
+    pub(crate) precision: Option<usize>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct FormattingFlags {
     pub(crate) formatting: Formatting,
     pub(crate) is_alternate: IsAlternate,
+    /// The constant that the flags are taken from, in `{:@FLAGS}` arguments.
+    ///
+    /// When this is `Some`, the `is_alternate` field and
+    /// the number formatting are ignored.
+    pub(crate) flags_const: Option<Ident>,
+    /// The `(true, false)` labels of a `{:bool(on,off)}` argument.
+    pub(crate) bool_labels: Option<(String, String)>,
+    /// The separator between groups of digits, in `{:,}` and `{:group(_)}` arguments.
+    pub(crate) digit_grouping: Option<char>,
+    /// The scientific notation of integers, in `{:e}` and `{:E}` arguments.
+    pub(crate) scientific: Option<Scientific>,
 }
 
 impl FormattingFlags {
@@ -53,6 +74,10 @@ impl FormattingFlags {
         Self {
             formatting: Formatting::Display,
             is_alternate,
+            flags_const: None,
+            bool_labels: None,
+            digit_grouping: None,
+            scientific: None,
         }
     }
 
@@ -61,12 +86,72 @@ impl FormattingFlags {
         Self {
             formatting: Formatting::Debug(num_fmt),
             is_alternate,
+            flags_const: None,
+            bool_labels: None,
+            digit_grouping: None,
+            scientific: None,
+        }
+    }
+
+    /// Constructs the flags of a `{:@FLAGS}`/`{:@FLAGS?}` argument,
+    /// where `FLAGS` is a `FormattingFlags` constant.
+    #[inline]
+    pub(crate) fn from_const(formatting: Formatting, flags_const: Ident) -> Self {
+        Self {
+            formatting,
+            is_alternate: IsAlternate::No,
+            flags_const: Some(flags_const),
+            bool_labels: None,
+            digit_grouping: None,
+            scientific: None,
+        }
+    }
+
+    /// Constructs the flags of a `{:bool(on,off)}` argument,
+    /// which displays a `bool` as one of the two labels.
+    #[inline]
+    pub(crate) fn bool_labels(on: String, off: String) -> Self {
+        Self {
+            formatting: Formatting::Display,
+            is_alternate: IsAlternate::No,
+            flags_const: None,
+            bool_labels: Some((on, off)),
+            digit_grouping: None,
+            scientific: None,
+        }
+    }
+
+    /// Constructs the flags of a `{:,}`/`{:group(_)}` argument,
+    /// which display formats integers with `separator` between groups of digits.
+    #[inline]
+    pub(crate) const fn digit_grouping(separator: char) -> Self {
+        Self {
+            formatting: Formatting::Display,
+            is_alternate: IsAlternate::No,
+            flags_const: None,
+            bool_labels: None,
+            digit_grouping: Some(separator),
+            scientific: None,
+        }
+    }
+
+    /// Constructs the flags of a `{:e}`/`{:E}`/`{:.2e}` argument,
+    /// which display formats integers in scientific notation.
+    #[inline]
+    pub(crate) const fn scientific(is_alternate: IsAlternate, scientific: Scientific) -> Self {
+        Self {
+            formatting: Formatting::Display,
+            is_alternate,
+            flags_const: None,
+            bool_labels: None,
+            digit_grouping: None,
+            scientific: Some(scientific),
         }
     }
 }
 
 impl FormattingFlags {
-    pub(crate) fn to_pargument_method_name(self) -> Ident {
+    pub(crate) fn to_pargument_method_name(&self) -> Ident {
         let name = match self.formatting {
             Formatting::Display => "to_pargument_display",
             Formatting::Debug { .. } => "to_pargument_debug",
@@ -76,7 +161,7 @@ impl FormattingFlags {
     }
 
     #[allow(dead_code)]
-    pub(crate) fn fmt_method_name(self) -> Ident {
+    pub(crate) fn fmt_method_name(&self) -> Ident {
         let name = match self.formatting {
             Formatting::Display => "const_display_fmt",
             Formatting::Debug { .. } => "const_debug_fmt",
@@ -86,7 +171,7 @@ impl FormattingFlags {
     }
 
     #[allow(dead_code)]
-    pub(crate) fn len_method_name(self) -> Ident {
+    pub(crate) fn len_method_name(&self) -> Ident {
         let name = match self.formatting {
             Formatting::Display => "const_display_fmt",
             Formatting::Debug { .. } => "const_debug_fmt",
@@ -100,6 +185,11 @@ impl ToTokens for FormattingFlags {
     fn to_tokens(&self, ts: &mut TokenStream2) {
         use self::{IsAlternate as IA, NumberFormatting as FM};
 
+        if let Some(flags_const) = &self.flags_const {
+            flags_const.to_tokens(ts);
+            return;
+        }
+
         let formatting = match self.formatting {
             Formatting::Display => NumberFormatting::Decimal,
             Formatting::Debug(num_fmt) => num_fmt,
@@ -113,5 +203,30 @@ impl ToTokens for FormattingFlags {
             (IA::Yes, FM::Hexadecimal) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__A_HEX),
             (IA::Yes, FM::Binary) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__A_BIN),
         });
+
+        if let Some(separator) = self.digit_grouping {
+            ts.append_all(quote!(.set_digit_grouping(Some(#separator))));
+        }
+
+        if let Some(Scientific {
+            is_uppercase,
+            precision,
+        }) = self.scientific
+        {
+            let notation = if is_uppercase {
+                quote!(UPPER)
+            } else {
+                quote!(LOWER)
+            };
+            let precision = match precision {
+                Some(precision) => quote!(Some(#precision)),
+                None => quote!(None),
+            };
+            ts.append_all(quote!(
+                .set_scientific(Some(
+                    __cf_osRcTFl4A::pmr::ScientificNotation::#notation.set_precision(#precision)
+                ))
+            ));
+        }
     }
 }
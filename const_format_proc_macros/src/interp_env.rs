@@ -0,0 +1,177 @@
+//! The implementation of the `interp_env` macro,
+//! which expands `"host=${HOST}"` to `concat!("host=", env!("HOST"))`.
+
+use crate::{
+    parse_utils::{MyParse, ParseBuffer, ParseStream},
+    Error,
+};
+
+use proc_macro2::{Literal, Span, TokenStream as TokenStream2, TokenTree};
+
+use quote::quote_spanned;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) struct InterpEnvArgs {
+    pieces: Vec<Piece>,
+    /// The quotes of the input literal, eg: `"` or `r#"`.
+    open: String,
+    /// The quotes of the input literal, eg: `"` or `"#`.
+    close: String,
+    span: Span,
+}
+
+#[derive(Debug, PartialEq)]
+enum Piece {
+    /// Literal text, as it was written in the source code (with escapes).
+    ///
+    /// The `$$` escapes are already replaced with `$`.
+    Text(String),
+    /// The name of an environment variable.
+    Var(String),
+}
+
+impl MyParse for InterpEnvArgs {
+    fn parse(input: ParseStream<'_>) -> Result<Self, crate::Error> {
+        let paren = input.parse_paren()?;
+        let paren_span = paren.paren_span;
+        let mut content = ParseBuffer::new(paren.contents);
+
+        content.parse_unwrap_tt(|content| match content.next() {
+            Some(TokenTree::Literal(lit)) => {
+                let span = lit.span();
+                let (open, template, close) = split_str_literal(&lit)?;
+                let pieces = parse_template(&template).map_err(|msg| Error::new(span, msg))?;
+                Ok(Self {
+                    pieces,
+                    open,
+                    close,
+                    span,
+                })
+            }
+            Some(tt) => Err(Error::new(tt.span(), "Expected a string literal")),
+            None => Err(Error::new(paren_span, "Expected a string literal")),
+        })
+    }
+}
+
+/// Splits the source code of a string literal into its
+/// opening quote(s), contents, and closing quote(s).
+///
+/// Returns the strings instead of borrowing them,
+/// because `Literal` can only be converted to a `String`.
+fn split_str_literal(lit: &Literal) -> Result<(String, String, String), crate::Error> {
+    let source = lit.to_string();
+
+    let hashes = source
+        .strip_prefix('r')
+        .map(|rem| rem.bytes().take_while(|&b| b == b'#').count());
+    let open_len = hashes.map_or(1, |h| h + 2);
+    let close_len = hashes.map_or(1, |h| h + 1);
+
+    let is_str = source.len() >= open_len + close_len
+        && source.as_bytes()[open_len - 1] == b'"'
+        && source.ends_with(&"#".repeat(close_len - 1));
+
+    if !is_str {
+        return Err(Error::new(
+            lit.span(),
+            format!("Expected a string literal, found: {}", source),
+        ));
+    }
+
+    let close_start = source.len() - close_len;
+    Ok((
+        source[..open_len].to_string(),
+        source[open_len..close_start].to_string(),
+        source[close_start..].to_string(),
+    ))
+}
+
+fn parse_template(template: &str) -> Result<Vec<Piece>, String> {
+    let mut pieces = Vec::new();
+    let mut text = String::new();
+    let mut rem = template;
+
+    while let Some(dollar) = rem.find('$') {
+        text.push_str(&rem[..dollar]);
+        rem = &rem[dollar + 1..];
+
+        if let Some(after) = rem.strip_prefix('$') {
+            text.push('$');
+            rem = after;
+        } else if let Some(after) = rem.strip_prefix('{') {
+            let close = after
+                .find('}')
+                .ok_or_else(|| "unclosed `${` in the template".to_string())?;
+            let name = &after[..close];
+
+            if !is_env_var_name(name) {
+                return Err(format!(
+                    "`{}` is not a valid environment variable name, \
+                     expected an ASCII identifier",
+                    name,
+                ));
+            }
+
+            if !text.is_empty() {
+                pieces.push(Piece::Text(std::mem::take(&mut text)));
+            }
+            pieces.push(Piece::Var(name.to_string()));
+            rem = &after[close + 1..];
+        } else {
+            return Err(
+                "`$` must be followed by `{NAME}`, or by another `$` to write a literal `$`"
+                    .to_string(),
+            );
+        }
+    }
+
+    text.push_str(rem);
+    if !text.is_empty() || pieces.is_empty() {
+        pieces.push(Piece::Text(text));
+    }
+
+    Ok(pieces)
+}
+
+fn is_env_var_name(s: &str) -> bool {
+    let mut bytes = s.bytes();
+    match bytes.next() {
+        Some(b) if b.is_ascii_alphabetic() || b == b'_' => {}
+        _ => return false,
+    }
+    bytes.all(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
+pub(crate) fn interp_env_impl(value: InterpEnvArgs) -> Result<TokenStream2, crate::Error> {
+    let span = value.span;
+
+    // Using the quotes of the input, so that the escapes in the text mean the same.
+    let lit_str = |text: &str| -> Result<Literal, crate::Error> {
+        let source = format!("{}{}{}", value.open, text, value.close);
+        let mut lit: Literal = source
+            .parse()
+            .map_err(|_| Error::new(span, "Expected a string literal"))?;
+        lit.set_span(span);
+        Ok(lit)
+    };
+
+    let args = value
+        .pieces
+        .iter()
+        .map(|piece| match piece {
+            Piece::Text(text) => lit_str(text).map(|lit| quote_spanned!(span=> #lit)),
+            Piece::Var(name) => {
+                let mut name = Literal::string(name);
+                name.set_span(span);
+                Ok(quote_spanned!(span=> __cf_osRcTFl4A::pmr::env!(#name)))
+            }
+        })
+        .collect::<Result<Vec<TokenStream2>, crate::Error>>()?;
+
+    Ok(quote_spanned!(span=>
+        __cf_osRcTFl4A::pmr::concat!(#(#args),*)
+    ))
+}
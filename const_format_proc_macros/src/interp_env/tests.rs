@@ -0,0 +1,105 @@
+use super::{parse_template, Piece};
+
+use crate::{parse_utils::MyParse, test_utils::StrExt};
+
+fn process_str(s: &str) -> Result<String, String> {
+    MyParse::parse_token_stream_2(s.parse().unwrap())
+        .and_then(super::interp_env_impl)
+        .map(|x| x.to_string())
+        .map_err(|e| e.to_compile_error().to_string())
+}
+
+fn text(s: &str) -> Piece {
+    Piece::Text(s.to_string())
+}
+
+fn var(s: &str) -> Piece {
+    Piece::Var(s.to_string())
+}
+
+#[test]
+fn parse_substitutions() {
+    assert_eq!(parse_template("").unwrap(), vec![text("")]);
+    assert_eq!(parse_template("foo").unwrap(), vec![text("foo")]);
+    assert_eq!(parse_template("${A}").unwrap(), vec![var("A")]);
+    assert_eq!(
+        parse_template("host=${HOST}:${PORT}").unwrap(),
+        vec![text("host="), var("HOST"), text(":"), var("PORT")],
+    );
+    assert_eq!(
+        parse_template("${_a1}${B_2}!").unwrap(),
+        vec![var("_a1"), var("B_2"), text("!")],
+    );
+}
+
+#[test]
+fn parse_escapes() {
+    assert_eq!(parse_template("$$").unwrap(), vec![text("$")]);
+    assert_eq!(parse_template("$$$$").unwrap(), vec![text("$$")]);
+    assert_eq!(parse_template("$${A}").unwrap(), vec![text("${A}")],);
+    assert_eq!(
+        parse_template("cost: $$${PRICE}").unwrap(),
+        vec![text("cost: $"), var("PRICE")],
+    );
+}
+
+#[test]
+fn parse_errors() {
+    for case in ["$", "a$b", "$ {A}", "$A"] {
+        assert!(
+            parse_template(case)
+                .unwrap_err()
+                .consecutive_in_self(&["`$` must be followed by", "`$`"]),
+            "{:?}",
+            case
+        );
+    }
+    for case in ["${", "${A", "a${AB"] {
+        assert!(
+            parse_template(case)
+                .unwrap_err()
+                .consecutive_in_self(&["unclosed"]),
+            "{:?}",
+            case
+        );
+    }
+    for case in ["${}", "${1A}", "${A-B}", "${ A}", "${ñ}"] {
+        assert!(
+            parse_template(case)
+                .unwrap_err()
+                .consecutive_in_self(&["not a valid environment variable name"]),
+            "{:?}",
+            case
+        );
+    }
+}
+
+#[test]
+fn expansion() {
+    let out = process_str(r#"("a${B}\n$$")"#).unwrap();
+    assert!(
+        out.consecutive_in_self(&["concat", "\"a\"", "env", "\"B\"", r#""\n$""#]),
+        "{}",
+        out
+    );
+
+    let out = process_str(r###"(r#"a"${B}"#)"###).unwrap();
+    assert!(
+        out.consecutive_in_self(&[r##"r#"a""#"##, "env", "\"B\""]),
+        "{}",
+        out
+    );
+}
+
+#[test]
+fn expansion_errors() {
+    assert!(process_str("(100)")
+        .unwrap_err()
+        .consecutive_in_self(&["string literal"]));
+    assert!(process_str(r#"(b"foo")"#)
+        .unwrap_err()
+        .consecutive_in_self(&["string literal"]));
+    assert!(process_str(r#"("${")"#)
+        .unwrap_err()
+        .consecutive_in_self(&["unclosed"]));
+}
@@ -0,0 +1,85 @@
+//! The implementation of the `is_valid_ident` macro.
+
+use crate::{
+    format_str::is_ident,
+    parse_utils::{LitStr, MyParse, ParseBuffer, ParseStream},
+    shared_arg_parsing::is_str_literal,
+    utils::{KEYWORDS, NON_RAW_KEYWORDS},
+    Error,
+};
+
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+
+use quote::quote;
+
+pub(crate) struct IsValidIdentArgs {
+    name: String,
+}
+
+impl MyParse for IsValidIdentArgs {
+    fn parse(input: ParseStream<'_>) -> Result<Self, crate::Error> {
+        let paren = input.parse_paren()?;
+        let paren_span = paren.paren_span;
+        let mut content = ParseBuffer::new(paren.contents);
+
+        content.parse_unwrap_tt(|content| match content.next() {
+            Some(TokenTree::Literal(lit)) if is_str_literal(&lit) => {
+                let lit = LitStr::parse_from_literal(&lit)?;
+                Ok(Self {
+                    name: lit.value().to_string(),
+                })
+            }
+            Some(tt) => Err(Error::new(tt.span(), "Expected a string literal")),
+            None => Err(Error::new(paren_span, "Expected a string literal")),
+        })
+    }
+}
+
+pub(crate) fn is_valid_ident_impl(args: IsValidIdentArgs) -> Result<TokenStream2, Error> {
+    let is_valid = is_valid_ident(&args.name);
+    Ok(quote!(#is_valid))
+}
+
+/// Whether `name` can be used as an identifier,
+/// keywords are only valid as raw identifiers (eg: `r#type`).
+fn is_valid_ident(name: &str) -> bool {
+    match name.strip_prefix("r#") {
+        Some(raw) => is_ident(raw) && !NON_RAW_KEYWORDS.contains(&raw),
+        None => is_ident(name) && !KEYWORDS.contains(&name) && !NON_RAW_KEYWORDS.contains(&name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_valid_ident;
+
+    #[test]
+    fn valid_idents() {
+        for name in [
+            "foo", "foo_bar", "_foo", "__", "FooBar2", "ñandú", "r#foo", "r#type",
+        ] {
+            assert!(is_valid_ident(name), "{}", name);
+        }
+        // weak keywords are valid identifiers
+        for name in ["union", "macro_rules", "raw", "default"] {
+            assert!(is_valid_ident(name), "{}", name);
+        }
+    }
+
+    #[test]
+    fn invalid_idents() {
+        for name in [
+            "", "_", "r#", "r#_", "2foo", "foo-bar", "foo bar", "r#r#foo", "'a",
+        ] {
+            assert!(!is_valid_ident(name), "{}", name);
+        }
+        for name in [
+            "type", "fn", "async", "gen", "self", "Self", "crate", "super",
+        ] {
+            assert!(!is_valid_ident(name), "{}", name);
+        }
+        for name in ["r#self", "r#Self", "r#crate", "r#super"] {
+            assert!(!is_valid_ident(name), "{}", name);
+        }
+    }
+}
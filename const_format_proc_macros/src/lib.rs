@@ -24,6 +24,10 @@ mod format_macro;
 
 mod formatting;
 
+mod interp_env;
+
+mod is_valid_ident;
+
 mod parse_utils;
 
 mod respan_to_macro;
@@ -100,6 +104,45 @@ pub fn __formatcp_if_impl(input: TokenStream1) -> TokenStream1 {
         .into()
 }
 
+#[doc(hidden)]
+#[proc_macro]
+pub fn __format_arg_count_impl(input: TokenStream1) -> TokenStream1 {
+    MyParse::parse_token_stream_1(input)
+        .and_then(format_macro::format_arg_count_impl)
+        .unwrap_or_else(|e| {
+            let e = e.to_compile_error();
+            quote::quote!({
+                #e;
+                0usize
+            })
+        })
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn __interp_env_impl(input: TokenStream1) -> TokenStream1 {
+    MyParse::parse_token_stream_1(input)
+        .and_then(interp_env::interp_env_impl)
+        .unwrap_or_else(compile_err_empty_str)
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn __is_valid_ident_impl(input: TokenStream1) -> TokenStream1 {
+    MyParse::parse_token_stream_1(input)
+        .and_then(is_valid_ident::is_valid_ident_impl)
+        .unwrap_or_else(|e| {
+            let e = e.to_compile_error();
+            quote::quote!({
+                #e;
+                false
+            })
+        })
+        .into()
+}
+
 #[doc(hidden)]
 #[proc_macro]
 pub fn __writec_impl(input: TokenStream1) -> TokenStream1 {
@@ -115,13 +158,27 @@ pub fn __writec_impl(input: TokenStream1) -> TokenStream1 {
         .into()
 }
 
+#[doc(hidden)]
+#[proc_macro]
+pub fn __formatting_flags_impl(input: TokenStream1) -> TokenStream1 {
+    MyParse::parse_token_stream_1(input)
+        .and_then(format_macro::formatting_flags_impl)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
 #[cfg(feature = "derive")]
 #[proc_macro_derive(ConstDebug, attributes(cdeb))]
 pub fn derive_const_debug(input: TokenStream1) -> TokenStream1 {
     syn::parse(input)
         .map_err(crate::Error::from)
         .and_then(derive_debug::derive_constdebug_impl)
-        .unwrap_or_else(|e| e.to_compile_error())
+        .unwrap_or_else(|e| {
+            // The errors are `compile_error!()`s in expression position,
+            // which are ignored if they're output where items are expected.
+            let e = e.to_compile_error();
+            quote::quote!(const _: () = { #e };)
+        })
         .into()
 }
 
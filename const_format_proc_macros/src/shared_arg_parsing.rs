@@ -1,11 +1,13 @@
 //! Types for parsing arguments, shared by many of the macros
 
 use crate::{
-    parse_utils::{MyParse, ParseBuffer, ParseStream},
+    format_str::parse_formatting,
+    formatting::FormattingFlags,
+    parse_utils::{LitStr, MyParse, ParseBuffer, ParseStream, TokenTreeExt},
     spanned::Spans,
 };
 
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Delimiter, Literal, TokenStream as TokenStream2, TokenTree};
 
 use quote::ToTokens;
 
@@ -17,6 +19,9 @@ pub(crate) struct ExprArg {
     /// Using a TokenStream2 because it is validated to be a valid expression in
     /// the macro_rules! macros that call these proc macros.
     pub(crate) expr: TokenStream2,
+    /// The formatting of `(expr, "#x")` arguments,
+    /// which are formatted like `{:#x}` would be.
+    pub(crate) formatting: Option<FormattingFlags>,
 }
 
 impl ToTokens for ExprArg {
@@ -41,11 +46,108 @@ impl MyParse for ExprArg {
         content.parse_unwrap_tt(|content| {
             let (expr, span) = content.parse_token_stream_and_span();
 
-            Ok(Self { span, expr })
+            let (expr, formatting) = match split_formatting(&expr)? {
+                Some((expr, formatting)) => (expr, Some(formatting)),
+                None => (expr, None),
+            };
+
+            Ok(Self {
+                span,
+                expr,
+                formatting,
+            })
         })
     }
 }
 
+/// Splits `(expr, "spec")` into the expression and the parsed `spec`,
+/// returning `None` for any other expression.
+fn split_formatting(
+    expr: &TokenStream2,
+) -> Result<Option<(TokenStream2, FormattingFlags)>, crate::Error> {
+    let mut iter = expr.clone().into_iter();
+    let group = match (iter.next(), iter.next()) {
+        (Some(TokenTree::Group(group)), None) if group.delimiter() == Delimiter::Parenthesis => {
+            group
+        }
+        _ => return Ok(None),
+    };
+
+    let mut tokens = group.stream().into_iter().collect::<Vec<TokenTree>>();
+    if matches!(tokens.last(), Some(tt) if tt.is_punct(',')) {
+        tokens.pop();
+    }
+
+    let spec = match tokens.pop().map(unwrap_none_group) {
+        Some(TokenTree::Literal(lit)) => lit,
+        _ => return Ok(None),
+    };
+    match tokens.pop() {
+        Some(comma) if comma.is_punct(',') && !tokens.is_empty() => {}
+        _ => return Ok(None),
+    }
+
+    let spec = match LitStr::parse_from_literal(&spec) {
+        Ok(spec) => spec,
+        Err(_) => return Ok(None),
+    };
+
+    let formatting = parse_formatting(spec.value(), 0, spec.rawness)
+        .map_err(|e| e.into_crate_err(spec.span, spec.value()))?;
+
+    Ok(Some((tokens.into_iter().collect(), formatting)))
+}
+
+/// A formatting spec string literal wrapped in parentheses,
+/// `("#x")` is parsed like the `{:#x}` formatting in format strings.
+pub(crate) struct FormattingSpec {
+    pub(crate) formatting: FormattingFlags,
+}
+
+impl MyParse for FormattingSpec {
+    fn parse(input: ParseStream<'_>) -> Result<Self, crate::Error> {
+        let paren = input.parse_paren()?;
+
+        let mut content = ParseBuffer::new(paren.contents);
+
+        let spec = match content.next().map(unwrap_none_group) {
+            Some(TokenTree::Literal(lit)) if content.is_empty() && is_str_literal(&lit) => {
+                LitStr::parse_from_literal(&lit)?
+            }
+            tt => {
+                let span = tt.map_or(paren.paren_span, |tt| tt.span());
+                return Err(crate::Error::new(
+                    span,
+                    "expected a formatting spec string literal",
+                ));
+            }
+        };
+
+        let formatting = parse_formatting(spec.value(), 0, spec.rawness)
+            .map_err(|e| e.into_crate_err(spec.span, spec.value()))?;
+
+        Ok(Self { formatting })
+    }
+}
+
+pub(crate) fn is_str_literal(lit: &Literal) -> bool {
+    let lit = lit.to_string();
+    lit.starts_with('"') || lit.starts_with("r\"") || lit.starts_with("r#")
+}
+
+fn unwrap_none_group(tt: TokenTree) -> TokenTree {
+    match tt {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+            let mut iter = group.stream().into_iter();
+            match (iter.next(), iter.next()) {
+                (Some(inner), None) => unwrap_none_group(inner),
+                _ => TokenTree::Group(group),
+            }
+        }
+        tt => tt,
+    }
+}
+
 ////////////////////////////////////////////////
 
 impl MyParse for ExprArgs {
@@ -1,4 +1,4 @@
-use proc_macro2::Span;
+use proc_macro2::{Ident, Span};
 
 #[cfg(feature = "derive")]
 use quote::ToTokens;
@@ -14,6 +14,40 @@ pub(crate) fn dummy_ident() -> proc_macro2::Ident {
     proc_macro2::Ident::new("__dummy__", Span::mixed_site())
 }
 
+/// Gets the name of `ident`, without the `r#` prefix of raw identifiers.
+pub(crate) fn unraw_ident_name(ident: &Ident) -> String {
+    let mut name = ident.to_string();
+    if name.starts_with("r#") {
+        name.drain(..2);
+    }
+    name
+}
+
+/// The strict and reserved keywords of all editions that can be used as raw identifiers.
+pub(crate) const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// The keywords that can't be used as raw identifiers.
+pub(crate) const NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+/// Constructs an identifier from its name (without the `r#` prefix),
+/// as a raw identifier if the name is a keyword.
+///
+/// This is used for identifiers that come from a format string,
+/// where `{type}` refers to `r#type`, like in the standard library.
+pub(crate) fn ident_from_unraw_name(name: &str, span: Span) -> Ident {
+    if KEYWORDS.contains(&name) {
+        Ident::new_raw(name, span)
+    } else {
+        Ident::new(name, span)
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(feature = "derive")]
@@ -13,6 +13,8 @@ const _: &str = formatcp!("{}", 0u8, 0u8 + 1);
 
 const _: &str = formatcp!("{}", |fmt| 0 + 0);
 
+const _: &str = formatcp!("{}", core::time::Duration::from_secs(5));
+
 #[cfg(feature = "nightly")]
 const _: () = {
     const _: &str = formatc!("{}");
@@ -13,3 +13,6 @@ mod using_assertc_macros;
 
 #[cfg(feature = "nightly")]
 mod using_writec_macro;
+
+#[cfg(feature = "assertcp")]
+mod using_assertcp_macros;
@@ -1,4 +1,4 @@
-use cfmt::{assertc, assertc_eq, assertc_ne};
+use cfmt::{assertc, assertc_eq, assertc_ne, FormattingFlags};
 
 // uninferred argument
 assertc!(false, "{}", 0);
@@ -20,3 +20,11 @@ assertc_eq!(0u8, 1u8, "{}", 0);
 assertc_eq!(0u8, 1u8, "{}", 0u8);
 
 assertc!(2 + 2 == 5, "{}", 0u8);
+
+assertc_eq!(
+    FormattingFlags::NEW.set_hexadecimal(),
+    FormattingFlags::NEW.set_binary().set_alternate(true),
+    "the flags must be equal",
+);
+
+assertc_ne!(FormattingFlags::NEW, FormattingFlags::NEW.set_decimal());
@@ -0,0 +1,13 @@
+use cfmt::{assertcp_eq, panic_fmt};
+
+const LEN: usize = 3;
+const INDEX: usize = 5;
+
+const _: () = if INDEX < LEN {
+} else {
+    panic_fmt!("index out of bounds: the len is {} but the index is {}", LEN, INDEX)
+};
+
+const FLAGS: u32 = 0b1011_0000;
+
+assertcp_eq!(FLAGS & 0xF0, 0xA0u32, fmt = "#x", "unexpected flags");